@@ -20,5 +20,8 @@ fn main() {
         x11_platform: { all(feature = "x11", free_unix, not(wasm), not(redox)) },
         wayland_platform: { all(feature = "wayland", free_unix, not(wasm), not(redox)) },
         orbital_platform: { redox },
+
+        // Input devices read directly from the kernel.
+        gamepad_platform: { all(feature = "gamepad", target_os = "linux", any(x11_platform, wayland_platform)) },
     }
 }
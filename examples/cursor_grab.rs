@@ -63,7 +63,10 @@ fn main() {
                 _ => (),
             },
             Event::DeviceEvent { event, .. } => match event {
-                DeviceEvent::MouseMotion { delta } => println!("mouse moved: {delta:?}"),
+                DeviceEvent::MouseMotion {
+                    delta,
+                    unaccelerated_delta,
+                } => println!("mouse moved: {delta:?}, unaccelerated: {unaccelerated_delta:?}"),
                 DeviceEvent::Button { button, state } => match state {
                     ElementState::Pressed => println!("mouse button {button} pressed"),
                     ElementState::Released => println!("mouse button {button} released"),
@@ -70,7 +70,7 @@ fn main() {
                 }
             }
             Event::WindowEvent {
-                event: WindowEvent::Ime(event),
+                event: WindowEvent::Ime { event, .. },
                 ..
             } => {
                 println!("{event:?}");
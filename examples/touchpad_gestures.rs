@@ -17,7 +17,7 @@ fn main() {
         .build(&event_loop)
         .unwrap();
 
-    println!("Only supported on macOS at the moment.");
+    println!("Only supported on macOS, Wayland and X11 at the moment.");
 
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;
@@ -42,6 +42,17 @@ fn main() {
                         println!("Rotated clockwise {delta}");
                     }
                 }
+                WindowEvent::SwipeGesture {
+                    fingers,
+                    delta,
+                    phase,
+                    ..
+                } => {
+                    println!("Swiped with {fingers} fingers {phase:?} {delta:?}");
+                }
+                WindowEvent::HoldGesture { fingers, phase, .. } => {
+                    println!("Held {fingers} fingers {phase:?}");
+                }
                 _ => (),
             }
         } else if let Event::RedrawRequested(_) = event {
@@ -74,12 +74,14 @@
 //!   of pre-defined settings. All "retina displays" have a scaling factor above 1.0 by default but
 //!   the specific value varies across devices.
 //! - **X11:** Many man-hours have been spent trying to figure out how to handle DPI in X11. Winit
-//!   currently uses a three-pronged approach:
+//!   currently uses a four-pronged approach:
 //!   + Use the value in the `WINIT_X11_SCALE_FACTOR` environment variable, if present.
+//!   + If not present, use the `Xft/DPI` or `Gdk/WindowScalingFactor` settings of the XSETTINGS
+//!     manager of the desktop, which are followed as they change.
 //!   + If not present, use the value set in `Xft.dpi` in Xresources.
 //!   + Otherwise, calculate the scale factor based on the millimeter monitor dimensions provided by XRandR.
 //!
-//!   If `WINIT_X11_SCALE_FACTOR` is set to `randr`, it'll ignore the `Xft.dpi` field and use the
+//!   If `WINIT_X11_SCALE_FACTOR` is set to `randr`, it'll ignore the DPI settings and use the
 //!   XRandR scaling method. Generally speaking, you should try to configure the standard system
 //!   variables to do what you want before resorting to `WINIT_X11_SCALE_FACTOR`.
 //! - **Wayland:** On Wayland, scale factors are set per-screen by the server, and are always
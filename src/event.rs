@@ -47,8 +47,9 @@ use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
     event_loop::AsyncRequestSerial,
     keyboard::{self, ModifiersKeyState, ModifiersKeys, ModifiersState},
+    monitor::MonitorHandle,
     platform_impl,
-    window::{ActivationToken, Theme, WindowId},
+    window::{ActivationToken, Theme, TiledEdges, WindowButtons, WindowId},
 };
 
 /// Describes a generic event.
@@ -79,6 +80,34 @@ pub enum Event<'a, T: 'static> {
     /// Emitted when an event is sent from [`EventLoopProxy::send_event`](crate::event_loop::EventLoopProxy::send_event)
     UserEvent(T),
 
+    /// Emitted when a monitor has been connected.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Emitted in response to RandR screen change notifications.
+    /// - **Wayland:** Emitted when a new `wl_output` is advertised by the compositor.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    MonitorAdded(MonitorHandle),
+
+    /// Emitted when a monitor has been disconnected.
+    ///
+    /// The monitor is already gone when this event is delivered, so querying the handle
+    /// may return stale or default values; it's mainly useful to compare against previously
+    /// stored handles.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    MonitorRemoved(MonitorHandle),
+
+    /// Emitted when the video mode, position, scale factor or transform of a monitor changed.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Emitted on `wl_output` and `xdg_output` updates.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    MonitorChanged(MonitorHandle),
+
     /// Emitted when the application has been suspended.
     ///
     /// # Portability
@@ -270,6 +299,9 @@ impl<T: Clone> Clone for Event<'static, T> {
                 event: event.clone(),
             },
             UserEvent(event) => UserEvent(event.clone()),
+            MonitorAdded(monitor) => MonitorAdded(monitor.clone()),
+            MonitorRemoved(monitor) => MonitorRemoved(monitor.clone()),
+            MonitorChanged(monitor) => MonitorChanged(monitor.clone()),
             DeviceEvent { device_id, event } => DeviceEvent {
                 device_id: *device_id,
                 event: event.clone(),
@@ -293,6 +325,9 @@ impl<'a, T> Event<'a, T> {
             UserEvent(_) => Err(self),
             WindowEvent { window_id, event } => Ok(WindowEvent { window_id, event }),
             DeviceEvent { device_id, event } => Ok(DeviceEvent { device_id, event }),
+            MonitorAdded(monitor) => Ok(MonitorAdded(monitor)),
+            MonitorRemoved(monitor) => Ok(MonitorRemoved(monitor)),
+            MonitorChanged(monitor) => Ok(MonitorChanged(monitor)),
             NewEvents(cause) => Ok(NewEvents(cause)),
             MainEventsCleared => Ok(MainEventsCleared),
             RedrawRequested(wid) => Ok(RedrawRequested(wid)),
@@ -313,6 +348,9 @@ impl<'a, T> Event<'a, T> {
                 .map(|event| WindowEvent { window_id, event }),
             UserEvent(event) => Some(UserEvent(event)),
             DeviceEvent { device_id, event } => Some(DeviceEvent { device_id, event }),
+            MonitorAdded(monitor) => Some(MonitorAdded(monitor)),
+            MonitorRemoved(monitor) => Some(MonitorRemoved(monitor)),
+            MonitorChanged(monitor) => Some(MonitorChanged(monitor)),
             NewEvents(cause) => Some(NewEvents(cause)),
             MainEventsCleared => Some(MainEventsCleared),
             RedrawRequested(wid) => Some(RedrawRequested(wid)),
@@ -408,8 +446,21 @@ pub enum WindowEvent<'a> {
     /// The window gained or lost focus.
     ///
     /// The parameter is true if the window has gained focus, and false if it has lost focus.
+    ///
+    /// With several seats, the window has focus as long as any of the seats focuses it, see
+    /// [`WindowEvent::SeatFocused`] for the focus of each seat.
     Focused(bool),
 
+    /// The window gained or lost the keyboard focus of a seat.
+    ///
+    /// This is sent for every seat along with [`WindowEvent::Focused`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Sent for the master keyboards of the MPX seats.
+    /// - **Windows / macOS / Web / iOS / Android / Orbital:** Unsupported.
+    SeatFocused { seat_id: SeatId, focused: bool },
+
     /// An event from the keyboard has been received.
     ///
     /// ## Platform-specific
@@ -435,14 +486,34 @@ pub enum WindowEvent<'a> {
     /// The keyboard modifiers have changed.
     ModifiersChanged(Modifiers),
 
+    /// The active keyboard layout or the list of layouts has changed.
+    ///
+    /// `device_id` is the keyboard whose layouts changed, and `layout` is the index of its active
+    /// layout in [`EventLoopWindowTarget::keyboard_layouts`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / X11:** Only sent to the window with the keyboard focus.
+    /// - **X11:** `device_id` is always the `Virtual core keyboard`, which follows the layouts of
+    ///   the core keyboard.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    ///
+    /// [`EventLoopWindowTarget::keyboard_layouts`]: crate::event_loop::EventLoopWindowTarget::keyboard_layouts
+    KeyboardLayoutChanged { device_id: DeviceId, layout: usize },
+
     /// An event from an input method.
     ///
     /// **Note:** You have to explicitly enable this event using [`Window::set_ime_allowed`].
     ///
+    /// `seat_id` is the seat whose input method sent the event, or `None` when the event isn't
+    /// specific to a seat, e.g. when the IME is enabled by [`Window::set_ime_allowed`].
+    ///
     /// ## Platform-specific
     ///
+    /// - **X11:** The input method only works with the `Virtual core` seat.
+    /// - **Windows / macOS:** `seat_id` is always `None`.
     /// - **iOS / Android / Web / Orbital:** Unsupported.
-    Ime(Ime),
+    Ime { seat_id: Option<SeatId>, event: Ime },
 
     /// The cursor has moved on the window.
     ///
@@ -485,10 +556,33 @@ pub enum WindowEvent<'a> {
     CursorLeft { device_id: DeviceId },
 
     /// A mouse wheel movement or touchpad scroll occurred.
+    ///
+    /// Scrolling with the fingers is [`Started`](TouchPhase::Started) when the fingers start
+    /// moving and [`Ended`](TouchPhase::Ended) when they're lifted, which is when kinetic
+    /// scrolling should start. The wheels only report [`Moved`](TouchPhase::Moved).
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The `value120` is derived from the discrete steps of the wheel, so it
+    ///   doesn't have a higher resolution than the steps.
+    /// - **X11:** The source is guessed from the properties of the device, and the scrolling
+    ///   with the fingers is always [`Moved`](TouchPhase::Moved).
+    /// - **Windows:** The source is always [`Unknown`](ScrollSource::Unknown).
+    /// - **macOS:** The source is [`Finger`](ScrollSource::Finger) for the devices with precise
+    ///   deltas, and [`Wheel`](ScrollSource::Wheel) otherwise.
+    /// - **iOS / Android / Web / Orbital:** The source is always
+    ///   [`Unknown`](ScrollSource::Unknown) and there's no `value120`.
     MouseWheel {
         device_id: DeviceId,
         delta: MouseScrollDelta,
         phase: TouchPhase,
+        /// The kind of input the scroll comes from.
+        source: ScrollSource,
+        /// The horizontal and vertical motion of the wheel, in 120ths of a step.
+        ///
+        /// High resolution wheels report fractions of a step, which can be accumulated to scroll
+        /// smoothly. It has the same sign as the `delta`.
+        value120: Option<(i32, i32)>,
     },
 
     /// An mouse button press has been received.
@@ -505,7 +599,9 @@ pub enum WindowEvent<'a> {
     ///
     /// ## Platform-specific
     ///
-    /// - Only available on **macOS**.
+    /// - **Wayland:** Requires the `zwp_pointer_gestures_v1` protocol.
+    /// - **X11:** Requires XInput 2.4.
+    /// - **iOS / Android / Web / Windows / Orbital:** Unsupported.
     TouchpadMagnify {
         device_id: DeviceId,
         delta: f64,
@@ -538,13 +634,48 @@ pub enum WindowEvent<'a> {
     ///
     /// ## Platform-specific
     ///
-    /// - Only available on **macOS**.
+    /// - **Wayland:** Requires the `zwp_pointer_gestures_v1` protocol.
+    /// - **X11:** Requires XInput 2.4.
+    /// - **iOS / Android / Web / Windows / Orbital:** Unsupported.
     TouchpadRotate {
         device_id: DeviceId,
         delta: f32,
         phase: TouchPhase,
     },
 
+    /// Touchpad swipe event with three or more fingers.
+    ///
+    /// The delta is the motion of the center of the fingers since the previous event, and is
+    /// accelerated like the motion of the cursor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Requires the `zwp_pointer_gestures_v1` protocol.
+    /// - **X11:** Requires XInput 2.4.
+    /// - **iOS / Android / macOS / Web / Windows / Orbital:** Unsupported.
+    SwipeGesture {
+        device_id: DeviceId,
+        fingers: u32,
+        delta: PhysicalPosition<f64>,
+        phase: TouchPhase,
+    },
+
+    /// Touchpad hold event, when fingers rest on the touchpad without moving.
+    ///
+    /// The gesture is [`Ended`](TouchPhase::Ended) when the fingers are lifted, and
+    /// [`Cancelled`](TouchPhase::Cancelled) when they start moving or another gesture begins,
+    /// which can be used to stop kinetic scrolling when the fingers are put down.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Requires version 3 of the `zwp_pointer_gestures_v1` protocol.
+    /// - **iOS / Android / macOS / Web / Windows / X11 / Orbital:** Unsupported.
+    HoldGesture {
+        device_id: DeviceId,
+        fingers: u32,
+        phase: TouchPhase,
+    },
+
     /// Touchpad pressure event.
     ///
     /// At the moment, only supported on Apple forcetouch-capable macbooks.
@@ -575,6 +706,24 @@ pub enum WindowEvent<'a> {
     /// [`transform`]: https://developer.mozilla.org/en-US/docs/Web/CSS/transform
     Touch(Touch),
 
+    /// A pen, or another tool of a drawing tablet, was used over the window, or a control of
+    /// the tablet pad was used while the window had the focus.
+    ///
+    /// The tools keep moving the cursor, so [`CursorMoved`](Self::CursorMoved) and
+    /// [`MouseInput`](Self::MouseInput) are still emitted for them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Requires the `zwp_tablet_manager_v2` protocol.
+    /// - **X11:** The tools are recognized from the labels of the XInput2 valuators and the
+    ///   names of the devices. The proximity is emulated with the pointer entering and leaving
+    ///   the window, and the serial numbers and the pad rings and strips aren't available.
+    /// - **iOS / Android / macOS / Web / Windows / Orbital:** Unsupported.
+    PenInput {
+        device_id: DeviceId,
+        event: PenEvent,
+    },
+
     /// The window's scale factor has changed.
     ///
     /// The following user actions can cause DPI changes:
@@ -600,7 +749,10 @@ pub enum WindowEvent<'a> {
     ///
     /// ## Platform-specific
     ///
-    /// - **iOS / Android / X11 / Wayland / Orbital:** Unsupported.
+    /// - **X11 / Wayland:** Only sent to the windows without a theme set, when the color scheme
+    ///   of the XDG desktop portal changes. X11 also follows the GTK theme from XSETTINGS. When
+    ///   the desktop has no preference, X11 reports [`Theme::Dark`] and Wayland [`Theme::Light`].
+    /// - **iOS / Android / Orbital:** Unsupported.
     ThemeChanged(Theme),
 
     /// The window has been occluded (completely hidden from view).
@@ -617,6 +769,38 @@ pub enum WindowEvent<'a> {
     /// [`padding`]: https://developer.mozilla.org/en-US/docs/Web/CSS/padding
     /// [`transform`]: https://developer.mozilla.org/en-US/docs/Web/CSS/transform
     Occluded(bool),
+
+    /// The buttons returned by [`Window::enabled_buttons`] changed, because the window manager
+    /// changed the set of supported actions.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Windows / macOS / X11 / Orbital:** Unsupported.
+    EnabledButtonsChanged(WindowButtons),
+
+    /// The state of the window was changed by the window manager.
+    ///
+    /// This event is sent when any of the fields change, and the fields reflect the
+    /// current state of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** `suspended` is set when the window is hidden with `_NET_WM_STATE_HIDDEN`
+    ///   and `activated` follows `_NET_WM_STATE_FOCUSED`.
+    /// - **Wayland:** `suspended` is always `false`.
+    /// - **iOS / Android / Web / Windows / macOS / Orbital:** Unsupported.
+    WindowStateChanged {
+        /// Whether the window is maximized.
+        maximized: bool,
+        /// Whether the window is fullscreen.
+        fullscreen: bool,
+        /// The edges of the window which are tiled, see [`Window::tiled_edges`].
+        tiled_edges: TiledEdges,
+        /// Whether the window is not visible to the user and shouldn't render.
+        suspended: bool,
+        /// Whether the window should be drawn as active.
+        activated: bool,
+    },
 }
 
 impl Clone for WindowEvent<'static> {
@@ -635,6 +819,10 @@ impl Clone for WindowEvent<'static> {
             HoveredFile(file) => HoveredFile(file.clone()),
             HoveredFileCancelled => HoveredFileCancelled,
             Focused(f) => Focused(*f),
+            SeatFocused { seat_id, focused } => SeatFocused {
+                seat_id: *seat_id,
+                focused: *focused,
+            },
             KeyboardInput {
                 device_id,
                 event,
@@ -644,8 +832,15 @@ impl Clone for WindowEvent<'static> {
                 event: event.clone(),
                 is_synthetic: *is_synthetic,
             },
-            Ime(preedit_state) => Ime(preedit_state.clone()),
+            Ime { seat_id, event } => Ime {
+                seat_id: *seat_id,
+                event: event.clone(),
+            },
             ModifiersChanged(modifiers) => ModifiersChanged(*modifiers),
+            KeyboardLayoutChanged { device_id, layout } => KeyboardLayoutChanged {
+                device_id: *device_id,
+                layout: *layout,
+            },
             CursorMoved {
                 device_id,
                 position,
@@ -663,10 +858,14 @@ impl Clone for WindowEvent<'static> {
                 device_id,
                 delta,
                 phase,
+                source,
+                value120,
             } => MouseWheel {
                 device_id: *device_id,
                 delta: *delta,
                 phase: *phase,
+                source: *source,
+                value120: *value120,
             },
             MouseInput {
                 device_id,
@@ -698,6 +897,26 @@ impl Clone for WindowEvent<'static> {
                 delta: *delta,
                 phase: *phase,
             },
+            SwipeGesture {
+                device_id,
+                fingers,
+                delta,
+                phase,
+            } => SwipeGesture {
+                device_id: *device_id,
+                fingers: *fingers,
+                delta: *delta,
+                phase: *phase,
+            },
+            HoldGesture {
+                device_id,
+                fingers,
+                phase,
+            } => HoldGesture {
+                device_id: *device_id,
+                fingers: *fingers,
+                phase: *phase,
+            },
             TouchpadPressure {
                 device_id,
                 pressure,
@@ -717,11 +936,29 @@ impl Clone for WindowEvent<'static> {
                 value: *value,
             },
             Touch(touch) => Touch(*touch),
+            PenInput { device_id, event } => PenInput {
+                device_id: *device_id,
+                event: *event,
+            },
             ThemeChanged(theme) => ThemeChanged(*theme),
             ScaleFactorChanged { .. } => {
                 unreachable!("Static event can't be about scale factor changing")
             }
             Occluded(occluded) => Occluded(*occluded),
+            EnabledButtonsChanged(buttons) => EnabledButtonsChanged(*buttons),
+            WindowStateChanged {
+                maximized,
+                fullscreen,
+                tiled_edges,
+                suspended,
+                activated,
+            } => WindowStateChanged {
+                maximized: *maximized,
+                fullscreen: *fullscreen,
+                tiled_edges: *tiled_edges,
+                suspended: *suspended,
+                activated: *activated,
+            },
         };
     }
 }
@@ -739,6 +976,7 @@ impl<'a> WindowEvent<'a> {
             HoveredFile(file) => Some(HoveredFile(file)),
             HoveredFileCancelled => Some(HoveredFileCancelled),
             Focused(focused) => Some(Focused(focused)),
+            SeatFocused { seat_id, focused } => Some(SeatFocused { seat_id, focused }),
             KeyboardInput {
                 device_id,
                 event,
@@ -749,7 +987,10 @@ impl<'a> WindowEvent<'a> {
                 is_synthetic,
             }),
             ModifiersChanged(modifers) => Some(ModifiersChanged(modifers)),
-            Ime(event) => Some(Ime(event)),
+            KeyboardLayoutChanged { device_id, layout } => {
+                Some(KeyboardLayoutChanged { device_id, layout })
+            }
+            Ime { seat_id, event } => Some(Ime { seat_id, event }),
             CursorMoved {
                 device_id,
                 position,
@@ -763,10 +1004,14 @@ impl<'a> WindowEvent<'a> {
                 device_id,
                 delta,
                 phase,
+                source,
+                value120,
             } => Some(MouseWheel {
                 device_id,
                 delta,
                 phase,
+                source,
+                value120,
             }),
             MouseInput {
                 device_id,
@@ -796,6 +1041,26 @@ impl<'a> WindowEvent<'a> {
                 delta,
                 phase,
             }),
+            SwipeGesture {
+                device_id,
+                fingers,
+                delta,
+                phase,
+            } => Some(SwipeGesture {
+                device_id,
+                fingers,
+                delta,
+                phase,
+            }),
+            HoldGesture {
+                device_id,
+                fingers,
+                phase,
+            } => Some(HoldGesture {
+                device_id,
+                fingers,
+                phase,
+            }),
             TouchpadPressure {
                 device_id,
                 pressure,
@@ -815,9 +1080,24 @@ impl<'a> WindowEvent<'a> {
                 value,
             }),
             Touch(touch) => Some(Touch(touch)),
+            PenInput { device_id, event } => Some(PenInput { device_id, event }),
             ThemeChanged(theme) => Some(ThemeChanged(theme)),
             ScaleFactorChanged { .. } => None,
             Occluded(occluded) => Some(Occluded(occluded)),
+            EnabledButtonsChanged(buttons) => Some(EnabledButtonsChanged(buttons)),
+            WindowStateChanged {
+                maximized,
+                fullscreen,
+                tiled_edges,
+                suspended,
+                activated,
+            } => Some(WindowStateChanged {
+                maximized,
+                fullscreen,
+                tiled_edges,
+                suspended,
+                activated,
+            }),
         }
     }
 }
@@ -843,6 +1123,147 @@ impl DeviceId {
     pub const unsafe fn dummy() -> Self {
         DeviceId(platform_impl::DeviceId::dummy())
     }
+
+    /// Returns the seat the device is part of.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The floating devices aren't part of a seat. The keyboard events come from the
+    ///   core keyboard, part of the `Virtual core` seat.
+    /// - **Windows / macOS / Web / iOS / Android / Orbital:** Always returns `None`.
+    pub fn seat_id(&self) -> Option<SeatId> {
+        #[cfg(any(x11_platform, wayland_platform))]
+        return self.0.seat_id();
+        #[cfg(not(any(x11_platform, wayland_platform)))]
+        None
+    }
+}
+
+/// Identifier of a seat.
+///
+/// A seat is a group of input devices used by a single person, with its own pointer, keyboard
+/// focus and touch, so several people can use the windows at once.
+///
+/// The seat of the devices sending the events is given by [`DeviceId::seat_id`].
+///
+/// ## Platform-specific
+///
+/// - **Wayland:** A `wl_seat`.
+/// - **X11:** A master pointer and keyboard pair of XInput2, as set up with MPX.
+/// - **Windows / macOS / Web / iOS / Android / Orbital:** Unsupported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SeatId(pub(crate) u32);
+
+impl SeatId {
+    /// Returns a dummy id, useful for unit testing.
+    ///
+    /// # Safety
+    ///
+    /// The only guarantee made about the return value of this function is that
+    /// it will always be equal to itself and to future values returned by this function.
+    /// No other guarantees are made. This may be equal to a real `SeatId`.
+    ///
+    /// **Passing this into a winit function will result in undefined behavior.**
+    pub const unsafe fn dummy() -> Self {
+        SeatId(0)
+    }
+}
+
+/// Describes an input device.
+///
+/// The devices are listed by [`EventLoopWindowTarget::devices`], and the one behind an event can
+/// be looked up with [`EventLoopWindowTarget::device_info`].
+///
+/// [`EventLoopWindowTarget::devices`]: crate::event_loop::EventLoopWindowTarget::devices
+/// [`EventLoopWindowTarget::device_info`]: crate::event_loop::EventLoopWindowTarget::device_info
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceInfo {
+    pub(crate) id: DeviceId,
+    pub(crate) name: String,
+    pub(crate) kind: DeviceKind,
+    pub(crate) vendor_id: Option<u16>,
+    pub(crate) product_id: Option<u16>,
+    pub(crate) seat: Option<String>,
+}
+
+impl DeviceInfo {
+    /// Returns the id of the device, as found in the events it emits.
+    #[inline]
+    pub fn id(&self) -> DeviceId {
+        self.id
+    }
+
+    /// Returns a human-readable name of the device.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The compositor only names the tablets, the other devices are named after
+    ///   their seat and capability, e.g. `seat0 pointer`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of the device.
+    #[inline]
+    pub fn kind(&self) -> DeviceKind {
+        self.kind
+    }
+
+    /// Returns the USB vendor id of the device, if known.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Only reported for tablets.
+    #[inline]
+    pub fn vendor_id(&self) -> Option<u16> {
+        self.vendor_id
+    }
+
+    /// Returns the USB product id of the device, if known.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Only reported for tablets.
+    #[inline]
+    pub fn product_id(&self) -> Option<u16> {
+        self.product_id
+    }
+
+    /// Returns the name of the seat the device belongs to.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The name of the master device the device is attached to, without its
+    ///   ` pointer` or ` keyboard` suffix, e.g. `Virtual core`. Floating devices have no seat.
+    #[inline]
+    pub fn seat(&self) -> Option<&str> {
+        self.seat.as_deref()
+    }
+}
+
+/// The kind of an input device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeviceKind {
+    Keyboard,
+
+    /// A mouse, trackball or pointing stick.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** All the pointing devices of a seat are reported as a single pointer.
+    Pointer,
+
+    Touchpad,
+
+    Touchscreen,
+
+    /// A drawing tablet, with its tools and pad.
+    Tablet,
+
+    /// A gamepad or a joystick.
+    Gamepad,
 }
 
 /// Represents raw hardware events that are not associated with any particular window.
@@ -855,17 +1276,42 @@ impl DeviceId {
 /// Note that these events are delivered regardless of input focus.
 #[derive(Clone, Debug, PartialEq)]
 pub enum DeviceEvent {
+    /// A device has been connected.
+    ///
+    /// Use [`EventLoopWindowTarget::device_info`] to get information about it.
+    ///
+    /// [`EventLoopWindowTarget::device_info`]: crate::event_loop::EventLoopWindowTarget::device_info
     Added,
+
+    /// A device has been disconnected.
     Removed,
 
     /// Change in physical position of a pointing device.
     ///
-    /// This represents raw, unfiltered physical motion. Not to be confused with [`WindowEvent::CursorMoved`].
+    /// This represents the relative motion of the device, even when the cursor can't move anymore,
+    /// like at the edge of the screen. Not to be confused with [`WindowEvent::CursorMoved`].
+    ///
+    /// Depending on the platform, `delta` may include the pointer acceleration, use
+    /// `unaccelerated_delta` for the raw physical motion.
     MouseMotion {
         /// (x, y) change in position in unspecified units.
         ///
-        /// Different devices may use different units.
+        /// Different devices may use different units. The motion includes the pointer acceleration
+        /// when the platform applies it to the raw input, so it matches the movement of the cursor.
         delta: (f64, f64),
+
+        /// (x, y) change in position before any pointer acceleration, as reported by the device.
+        ///
+        /// This is the motion to use for a camera controlled by the mouse, since it stays proportional
+        /// to the physical movement of the device.
+        ///
+        /// ## Platform-specific
+        ///
+        /// - **X11:** From the raw values of the `XI_RawMotion` events.
+        /// - **Wayland:** From the unaccelerated motion of `zwp_relative_pointer_v1`.
+        /// - **Windows:** Same as `delta`, the relative motion of `WM_INPUT` being unaccelerated.
+        /// - **macOS / Web / iOS / Android / Orbital:** Always `None`.
+        unaccelerated_delta: Option<(f64, f64)>,
     },
 
     /// Physical scroll event
@@ -891,6 +1337,102 @@ pub enum DeviceEvent {
     Text {
         codepoint: char,
     },
+
+    /// A button of a gamepad was pressed or released.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / X11:** Requires the `gamepad` feature.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    GamepadButton {
+        button: GamepadButton,
+        state: ElementState,
+    },
+
+    /// An axis of a gamepad moved.
+    ///
+    /// The value of the triggers goes from `0.0` to `1.0`, the other axes go from `-1.0` to `1.0`
+    /// and are `0.0` at rest. The sticks are positive to the right and downwards.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / X11:** Requires the `gamepad` feature.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    GamepadAxis {
+        axis: GamepadAxis,
+        value: f64,
+    },
+}
+
+/// A button of a gamepad, named after its position on the controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GamepadButton {
+    /// The bottom action button, e.g. A on Xbox controllers or Cross on PlayStation controllers.
+    South,
+
+    /// The right action button, e.g. B on Xbox controllers or Circle on PlayStation controllers.
+    East,
+
+    /// The top action button, e.g. Y on Xbox controllers or Triangle on PlayStation controllers.
+    North,
+
+    /// The left action button, e.g. X on Xbox controllers or Square on PlayStation controllers.
+    West,
+
+    LeftBumper,
+    RightBumper,
+
+    /// The left trigger, when pressed past a threshold. The analog value is reported as
+    /// [`GamepadAxis::LeftTrigger`].
+    LeftTrigger,
+
+    /// The right trigger, when pressed past a threshold. The analog value is reported as
+    /// [`GamepadAxis::RightTrigger`].
+    RightTrigger,
+
+    Select,
+    Start,
+
+    /// The button in the middle of the controller, e.g. the Xbox or PS button.
+    Mode,
+
+    /// The click of the left stick.
+    LeftStick,
+
+    /// The click of the right stick.
+    RightStick,
+
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+
+    /// A button without a standard position, e.g. a button of a joystick.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / X11:** The evdev code of the button.
+    Other(u16),
+}
+
+/// An axis of a gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+
+    /// An axis without a standard position, e.g. the throttle of a joystick.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / X11:** The evdev code of the axis.
+    Other(u16),
 }
 
 /// Describes a keyboard input as a raw device event.
@@ -1208,12 +1750,38 @@ pub struct Touch {
     ///
     /// ## Platform-specific
     ///
-    /// - Only available on **iOS** 9.0+, **Windows** 8+, and **Web**.
+    /// - Only available on **iOS** 9.0+, **Windows** 8+, **Web** and **X11**.
     pub force: Option<Force>,
+    /// The area in contact with the screen. May be `None` if the platform or the device
+    /// doesn't report it.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The axes are scaled to the size of the screen, which is only right for the
+    ///   touchscreens covering the whole screen.
+    /// - Only available on **Wayland** and **X11**.
+    pub contact: Option<TouchContact>,
     /// Unique identifier of a finger.
     pub id: u64,
 }
 
+/// The area of a touch, approximated by an ellipse centered on its location.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TouchContact {
+    /// The length of the major axis of the ellipse, in physical pixels.
+    pub major: f64,
+    /// The length of the minor axis of the ellipse, in physical pixels.
+    ///
+    /// It's the same as the major axis when the device only reports the size of the contact.
+    pub minor: f64,
+    /// The clockwise angle between the major axis and the vertical axis, in degrees between
+    /// -180 and 180.
+    ///
+    /// It's 0 when the device doesn't report the orientation.
+    pub orientation: f64,
+}
+
 /// Describes the force of a touch event
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Force {
@@ -1271,6 +1839,105 @@ impl Force {
     }
 }
 
+/// Describes the input from a pen, or another tool, of a drawing tablet.
+///
+/// The tools and the pad send separate events, the pad events don't have any tool.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PenEvent {
+    /// The tool came into the detection range of the tablet over the window.
+    ProximityIn { tool: PenTool },
+
+    /// The tool left the detection range of the tablet, or moved out of the window.
+    ProximityOut { tool: PenTool },
+
+    /// The tip of the tool touched the tablet.
+    Down { tool: PenTool },
+
+    /// The tip of the tool stopped touching the tablet.
+    Up { tool: PenTool },
+
+    /// The tool moved, or the state of its axes changed.
+    Motion {
+        tool: PenTool,
+        location: PhysicalPosition<f64>,
+        axes: PenAxes,
+    },
+
+    /// A button on the barrel of the tool was pressed or released.
+    ///
+    /// The buttons are numbered from 1, the tip isn't a button.
+    Button {
+        tool: PenTool,
+        button: ButtonId,
+        state: ElementState,
+    },
+
+    /// A button of the tablet pad was pressed or released, the buttons are numbered from 0.
+    PadButton {
+        button: ButtonId,
+        state: ElementState,
+    },
+
+    /// A finger moved on a ring of the tablet pad.
+    ///
+    /// The angle is in degrees, clockwise from the top of the ring. It is `None` when the finger
+    /// left the ring.
+    PadRing { ring: u32, angle: Option<f64> },
+
+    /// A finger moved on a strip of the tablet pad.
+    ///
+    /// The position goes from 0 at the top or left to 1 at the bottom or right. It is `None`
+    /// when the finger left the strip.
+    PadStrip { strip: u32, position: Option<f64> },
+}
+
+/// Describes a tool of a drawing tablet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PenTool {
+    pub kind: PenToolKind,
+    /// The serial number of the tool, which identifies a physical tool across tablets.
+    ///
+    /// `None` when the tablet doesn't report serial numbers.
+    pub serial: Option<u64>,
+}
+
+/// Describes the kind of a tool of a drawing tablet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PenToolKind {
+    Pen,
+    Eraser,
+    Brush,
+    Pencil,
+    Airbrush,
+    Finger,
+    /// A puck, which is used like a mouse on the tablet.
+    Mouse,
+    /// A puck with a lens, used to trace drawings.
+    Lens,
+}
+
+/// Describes the state of the axes of a tool of a drawing tablet.
+///
+/// The axes are `None` when the tool doesn't have them.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PenAxes {
+    /// The pressure on the tip, from 0 to 1.
+    pub pressure: Option<f64>,
+    /// The tilt towards the right and the bottom, in degrees from the perpendicular to the
+    /// tablet.
+    pub tilt: Option<(f64, f64)>,
+    /// The distance from the tablet, from 0 to 1 in device specific units.
+    pub distance: Option<f64>,
+    /// The rotation around the axis of the tool, in degrees clockwise.
+    pub rotation: Option<f64>,
+    /// The position of the slider of the tool, from -1 to 1.
+    pub slider: Option<f64>,
+}
+
 /// Identifier for a specific analog axis on some device.
 pub type AxisId = u32;
 
@@ -1328,3 +1995,23 @@ pub enum MouseScrollDelta {
     /// and move the content right and down (to reveal more things left and up).
     PixelDelta(PhysicalPosition<f64>),
 }
+
+/// The kind of input a scroll comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScrollSource {
+    /// A mouse wheel, scrolling in steps.
+    Wheel,
+
+    /// Fingers on a touchpad, which report when the scroll ends.
+    Finger,
+
+    /// A continuous source without an end, like the scroll button of a trackpoint.
+    Continuous,
+
+    /// The sideways tilt of a mouse wheel.
+    WheelTilt,
+
+    /// The source isn't known.
+    Unknown,
+}
@@ -18,7 +18,13 @@ use std::time::{Duration, Instant};
 #[cfg(wasm_platform)]
 use web_time::{Duration, Instant};
 
-use crate::{event::Event, monitor::MonitorHandle, platform_impl};
+use crate::{
+    error::ExternalError,
+    event::{DeviceId, DeviceInfo, Event},
+    keyboard::LayoutInfo,
+    monitor::{MonitorHandle, MonitorId},
+    platform_impl,
+};
 
 /// Provides a way to retrieve events from the system and from the windows that were registered to
 /// the events loop.
@@ -341,6 +347,21 @@ impl<T> EventLoopWindowTarget<T> {
             .map(|inner| MonitorHandle { inner })
     }
 
+    /// Returns the monitor identified by `id`, as returned by [`MonitorHandle::stable_id`].
+    ///
+    /// When no monitor matches exactly, a display with the same serial number plugged into
+    /// another connector is returned instead.
+    ///
+    /// Returns `None` if the display isn't connected.
+    pub fn monitor_by_id(&self, id: &MonitorId) -> Option<MonitorHandle> {
+        self.available_monitors()
+            .find(|monitor| monitor.stable_id() == *id)
+            .or_else(|| {
+                self.available_monitors()
+                    .find(|monitor| monitor.stable_id().is_same_display(id))
+            })
+    }
+
     /// Returns the primary monitor of the system.
     ///
     /// Returns `None` if it can't identify any monitor as a primary one.
@@ -363,13 +384,111 @@ impl<T> EventLoopWindowTarget<T> {
     ///
     /// ## Platform-specific
     ///
-    /// - **Wayland / macOS / iOS / Android / Orbital:** Unsupported.
+    /// - **Linux:** The input of the gamepads is filtered as well, but they are always reported as
+    ///   [added] and [removed].
+    /// - **Wayland:** Only the input of the gamepads is filtered.
+    /// - **macOS / iOS / Android / Orbital:** Unsupported.
     ///
     /// [`DeviceEvent`]: crate::event::DeviceEvent
+    /// [added]: crate::event::DeviceEvent::Added
+    /// [removed]: crate::event::DeviceEvent::Removed
     pub fn listen_device_events(&self, _allowed: DeviceEvents) {
         #[cfg(any(x11_platform, wasm_platform, wayland_platform, windows))]
         self.p.listen_device_events(_allowed);
     }
+
+    /// Returns the list of the input devices.
+    ///
+    /// Watch [`DeviceEvent::Added`] and [`DeviceEvent::Removed`] to know when it changes.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The master devices, e.g. `Virtual core pointer`, are listed alongside the
+    ///   physical devices, since the window events are reported with their id.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported, always empty.
+    ///
+    /// [`DeviceEvent::Added`]: crate::event::DeviceEvent::Added
+    /// [`DeviceEvent::Removed`]: crate::event::DeviceEvent::Removed
+    pub fn devices(&self) -> impl Iterator<Item = DeviceInfo> {
+        #[cfg(any(x11_platform, wayland_platform))]
+        let devices = self.p.devices();
+        #[cfg(not(any(x11_platform, wayland_platform)))]
+        let devices = Vec::new();
+        devices.into_iter()
+    }
+
+    /// Returns the layouts of the keyboard, in the order of the keymap.
+    ///
+    /// Watch [`WindowEvent::KeyboardLayoutChanged`] to know when it changes.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The layouts of the keyboard with the focus, or of the first seat when no
+    ///   window has the keyboard focus.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported, always empty.
+    ///
+    /// [`WindowEvent::KeyboardLayoutChanged`]: crate::event::WindowEvent::KeyboardLayoutChanged
+    pub fn keyboard_layouts(&self) -> Vec<LayoutInfo> {
+        #[cfg(any(x11_platform, wayland_platform))]
+        return self.p.keyboard_layouts();
+        #[cfg(not(any(x11_platform, wayland_platform)))]
+        Vec::new()
+    }
+
+    /// Returns the index of the active layout in [`keyboard_layouts`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported, always `None`.
+    ///
+    /// [`keyboard_layouts`]: Self::keyboard_layouts
+    pub fn active_keyboard_layout(&self) -> Option<usize> {
+        #[cfg(any(x11_platform, wayland_platform))]
+        return self.p.active_keyboard_layout();
+        #[cfg(not(any(x11_platform, wayland_platform)))]
+        None
+    }
+
+    /// Makes a gamepad rumble for `duration`, with the strength of its strong and weak motors
+    /// going from `0.0` to `1.0`.
+    ///
+    /// The rumble replaces the current one of the gamepad.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / X11:** Requires the `gamepad` feature, and the write access to the device.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    pub fn set_gamepad_rumble(
+        &self,
+        _device_id: DeviceId,
+        _strong: f32,
+        _weak: f32,
+        _duration: Duration,
+    ) -> Result<(), ExternalError> {
+        #[cfg(gamepad_platform)]
+        return self
+            .p
+            .set_gamepad_rumble(_device_id.0, _strong, _weak, _duration);
+        #[cfg(not(gamepad_platform))]
+        Err(ExternalError::NotSupported(
+            crate::error::NotSupportedError::new(),
+        ))
+    }
+
+    /// Returns the information about the device identified by `device_id`.
+    ///
+    /// Returns `None` if the device is gone, or on the platforms where [`devices`] is
+    /// unsupported.
+    ///
+    /// Unlike [`devices`], only the given device is queried.
+    ///
+    /// [`devices`]: Self::devices
+    pub fn device_info(&self, _device_id: DeviceId) -> Option<DeviceInfo> {
+        #[cfg(any(x11_platform, wayland_platform))]
+        return self.p.device_info(_device_id.0);
+        #[cfg(not(any(x11_platform, wayland_platform)))]
+        None
+    }
 }
 
 unsafe impl<T> HasRawDisplayHandle for EventLoopWindowTarget<T> {
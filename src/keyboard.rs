@@ -1614,6 +1614,35 @@ pub enum ModifiersKeyState {
     Unknown,
 }
 
+/// A keyboard layout, as listed by [`EventLoopWindowTarget::keyboard_layouts`].
+///
+/// [`EventLoopWindowTarget::keyboard_layouts`]: crate::event_loop::EventLoopWindowTarget::keyboard_layouts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LayoutInfo {
+    pub(crate) name: String,
+    pub(crate) short_name: Option<String>,
+}
+
+impl LayoutInfo {
+    /// Returns the human-readable name of the layout, e.g. `English (US)`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the short name of the layout, e.g. `us`, if known.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / X11:** Read from the symbols of the keymap, which only name the layouts
+    ///   when the keymap was built from the XKB rules.
+    #[inline]
+    pub fn short_name(&self) -> Option<&str> {
+        self.short_name.as_deref()
+    }
+}
+
 // NOTE: the exact modifier key is not used to represent modifiers state in the
 // first place due to a fact that modifiers state could be changed without any
 // key being pressed and on some platforms like Wayland/X11 which key resulted
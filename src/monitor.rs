@@ -138,6 +138,21 @@ impl MonitorHandle {
         self.inner.position()
     }
 
+    /// Returns the area of the monitor that isn't covered by panels, docks or the taskbar, as
+    /// the top-left corner position and the size.
+    ///
+    /// This is the area that should be used when placing new windows or dialogs.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Computed from `_NET_WORKAREA` and the struts of the dock windows.
+    /// - **Wayland / macOS / iOS / Android / Web / Orbital:** Returns the full bounds of the
+    ///   monitor.
+    #[inline]
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        self.inner.work_area()
+    }
+
     /// The monitor refresh rate used by the system.
     ///
     /// Return `Some` if succeed, or `None` if failed, which usually happens when the monitor
@@ -164,6 +179,95 @@ impl MonitorHandle {
         self.inner.scale_factor()
     }
 
+    /// Returns the physical width and height of the monitor in millimetres.
+    ///
+    /// Returns `None` when the size is unknown or doesn't make sense, e.g. for projectors.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        self.inner.physical_size_mm()
+    }
+
+    /// Returns the manufacturer of the monitor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The three letter PNP id from the EDID, e.g. `DEL`.
+    /// - **Wayland:** The make advertised by the compositor.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    #[inline]
+    pub fn manufacturer(&self) -> Option<String> {
+        self.inner.manufacturer()
+    }
+
+    /// Returns the model name of the monitor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The display product name from the EDID, falling back to the product code.
+    /// - **Wayland:** The model advertised by the compositor.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        self.inner.model()
+    }
+
+    /// Returns the serial number of the monitor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The serial number from the EDID.
+    /// - **Wayland / Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    #[inline]
+    pub fn serial_number(&self) -> Option<String> {
+        self.inner.serial_number()
+    }
+
+    /// Returns an identifier of the monitor that persists across restarts and replugs.
+    ///
+    /// It's derived from the connector name and the identity of the connected display, and
+    /// can be stored to find the same physical display later on with
+    /// [`EventLoopWindowTarget::monitor_by_id`].
+    ///
+    /// Note that two identical displays without serial numbers can only be told apart by the
+    /// connector they're plugged into.
+    ///
+    /// [`EventLoopWindowTarget::monitor_by_id`]: crate::event_loop::EventLoopWindowTarget::monitor_by_id
+    #[inline]
+    pub fn stable_id(&self) -> MonitorId {
+        MonitorId {
+            connector: self.name(),
+            manufacturer: self.manufacturer(),
+            model: self.model(),
+            serial_number: self.serial_number(),
+        }
+    }
+
+    /// Returns the transformation applied to the contents of the monitor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Always returns
+    ///   [`MonitorTransform::Normal`].
+    #[inline]
+    pub fn transform(&self) -> MonitorTransform {
+        self.inner.transform()
+    }
+
+    /// Returns the layout of the subpixels of the monitor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Always returns
+    ///   [`SubpixelOrder::Unknown`].
+    #[inline]
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        self.inner.subpixel_order()
+    }
+
     /// Returns all fullscreen video modes supported by this monitor.
     ///
     /// ## Platform-specific
@@ -176,3 +280,84 @@ impl MonitorHandle {
             .map(|video_mode| VideoMode { video_mode })
     }
 }
+
+/// The transformation applied to the contents of a monitor.
+///
+/// Rotations are counter-clockwise, and flipping happens around the vertical axis before
+/// rotating.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MonitorTransform {
+    #[default]
+    Normal,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    Flipped,
+    Flipped90,
+    Flipped180,
+    Flipped270,
+}
+
+/// The layout of the subpixels of a monitor.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SubpixelOrder {
+    #[default]
+    Unknown,
+    /// The monitor doesn't have subpixels, e.g. projectors.
+    None,
+    HorizontalRgb,
+    HorizontalBgr,
+    VerticalRgb,
+    VerticalBgr,
+}
+
+/// Persistent identifier of a monitor.
+///
+/// Can be acquired with [`MonitorHandle::stable_id`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MonitorId {
+    connector: Option<String>,
+    manufacturer: Option<String>,
+    model: Option<String>,
+    serial_number: Option<String>,
+}
+
+impl MonitorId {
+    /// Whether both identifiers refer to the same display, regardless of the connector it's
+    /// plugged into.
+    pub(crate) fn is_same_display(&self, other: &Self) -> bool {
+        // Without a serial number, only the connector tells identical displays apart.
+        self.serial_number.is_some()
+            && self.manufacturer == other.manufacturer
+            && self.model == other.model
+            && self.serial_number == other.serial_number
+    }
+}
+
+/// The gamma lookup tables of a monitor.
+///
+/// Each table maps the intensity of a channel, from black to full intensity, to the value sent
+/// to the display. The three tables have the same length, which is given by the hardware.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GammaRamp {
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+
+impl GammaRamp {
+    /// The identity ramp with `size` entries per channel.
+    pub fn linear(size: usize) -> Self {
+        let table: Vec<u16> = (0..size)
+            .map(|index| (index * u16::MAX as usize / size.saturating_sub(1).max(1)) as u16)
+            .collect();
+        GammaRamp {
+            red: table.clone(),
+            green: table.clone(),
+            blue: table,
+        }
+    }
+}
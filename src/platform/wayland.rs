@@ -1,11 +1,15 @@
+use std::num::NonZeroU32;
 use std::os::raw;
+use std::sync::Arc;
 
 use sctk::reexports::client::Proxy;
 
 use crate::{
+    dpi::Size,
+    error::ExternalError,
     event_loop::{EventLoopBuilder, EventLoopWindowTarget},
-    monitor::MonitorHandle,
-    window::{Window, WindowBuilder},
+    monitor::{GammaRamp, MonitorHandle},
+    window::{CursorIcon, ResizeDirection, Window, WindowBuilder, WindowButtons},
 };
 
 use crate::platform_impl::{
@@ -90,6 +94,21 @@ pub trait WindowExtWayland {
     ///
     /// The pointer will become invalid when the [`Window`] is destroyed.
     fn wayland_display(&self) -> Option<*mut raw::c_void>;
+
+    /// Set the colors used by the bundled client side decorations frame.
+    ///
+    /// Passing `None` restores the colors picked from the window [`Theme`].
+    ///
+    /// This has no effect when the window uses server side decorations or a custom
+    /// [`DecorationFrame`].
+    fn set_frame_theme(&self, frame_theme: Option<FrameTheme>);
+
+    /// The window management actions supported by the compositor for this window.
+    ///
+    /// The changes to the capabilities are reflected in [`Window::enabled_buttons`].
+    ///
+    /// Returns [`WmCapabilities::all`] when the window doesn't use Wayland.
+    fn wm_capabilities(&self) -> WmCapabilities;
 }
 
 impl WindowExtWayland for Window {
@@ -110,6 +129,24 @@ impl WindowExtWayland for Window {
             _ => None,
         }
     }
+
+    #[inline]
+    fn set_frame_theme(&self, frame_theme: Option<FrameTheme>) {
+        match self.window {
+            LinuxWindow::Wayland(ref w) => w.set_frame_theme(frame_theme),
+            #[cfg(x11_platform)]
+            _ => (),
+        }
+    }
+
+    #[inline]
+    fn wm_capabilities(&self) -> WmCapabilities {
+        match self.window {
+            LinuxWindow::Wayland(ref w) => w.wm_capabilities(),
+            #[cfg(x11_platform)]
+            _ => WmCapabilities::all(),
+        }
+    }
 }
 
 /// Additional methods on [`WindowBuilder`] that are specific to Wayland.
@@ -122,6 +159,39 @@ pub trait WindowBuilderExtWayland {
     /// For details about application ID conventions, see the
     /// [Desktop Entry Spec](https://specifications.freedesktop.org/desktop-entry-spec/desktop-entry-spec-latest.html#desktop-file-id)
     fn with_name(self, general: impl Into<String>, instance: impl Into<String>) -> Self;
+
+    /// Build window with the custom client side decorations frame.
+    ///
+    /// The `frame` is called every time the window needs a new frame, which happens when the
+    /// compositor asks the window to draw its decorations by itself. See [`DecorationFrame`]
+    /// for details.
+    ///
+    /// By default the frame bundled with winit is used.
+    fn with_decoration_frame<F>(self, frame: F) -> Self
+    where
+        F: Fn() -> Box<dyn DecorationFrame> + Send + Sync + 'static;
+
+    /// Build window with the given colors for the bundled client side decorations frame.
+    ///
+    /// See [`WindowExtWayland::set_frame_theme`] for details.
+    fn with_frame_theme(self, frame_theme: FrameTheme) -> Self;
+
+    /// Build window with base size for the resize increments.
+    ///
+    /// The sizes are snapped to the increments starting from the base size, like it's done by
+    /// the X11 window managers. See [`Window::set_resize_increments`] for details.
+    ///
+    /// ```
+    /// # use winit::dpi::{LogicalSize, PhysicalSize};
+    /// # use winit::window::WindowBuilder;
+    /// # use winit::platform::wayland::WindowBuilderExtWayland;
+    /// // Specify the size in logical dimensions like this:
+    /// WindowBuilder::new().with_base_size(LogicalSize::new(400.0, 200.0));
+    ///
+    /// // Or specify the size in physical dimensions like this:
+    /// WindowBuilder::new().with_base_size(PhysicalSize::new(400, 200));
+    /// ```
+    fn with_base_size<S: Into<Size>>(self, base_size: S) -> Self;
 }
 
 impl WindowBuilderExtWayland for WindowBuilder {
@@ -130,12 +200,52 @@ impl WindowBuilderExtWayland for WindowBuilder {
         self.platform_specific.name = Some(ApplicationName::new(general.into(), instance.into()));
         self
     }
+
+    #[inline]
+    fn with_decoration_frame<F>(mut self, frame: F) -> Self
+    where
+        F: Fn() -> Box<dyn DecorationFrame> + Send + Sync + 'static,
+    {
+        self.platform_specific.decoration_frame = Some(Arc::new(frame));
+        self
+    }
+
+    #[inline]
+    fn with_frame_theme(mut self, frame_theme: FrameTheme) -> Self {
+        self.platform_specific.frame_theme = Some(frame_theme);
+        self
+    }
+
+    #[inline]
+    fn with_base_size<S: Into<Size>>(mut self, base_size: S) -> Self {
+        self.platform_specific.base_size = Some(base_size.into());
+        self
+    }
 }
 
 /// Additional methods on `MonitorHandle` that are specific to Wayland.
 pub trait MonitorHandleExtWayland {
     /// Returns the inner identifier of the monitor.
     fn native_id(&self) -> u32;
+
+    /// Returns the gamma ramp of the output.
+    ///
+    /// The compositor doesn't expose the current ramps, so this returns the ramp set with
+    /// [`MonitorHandleExtWayland::set_gamma_ramp`], or a linear ramp of the right size.
+    ///
+    /// Requires `zwlr_gamma_control_manager_v1`.
+    fn gamma_ramp(&self) -> Result<GammaRamp, ExternalError>;
+
+    /// Sets the gamma ramp of the output.
+    ///
+    /// The tables must have the length of the ramps of the output, which can be queried with
+    /// [`MonitorHandleExtWayland::gamma_ramp`]. The compositor grants the control of the gamma
+    /// to a single client at a time, and restores the original ramps when the event loop
+    /// exits.
+    ///
+    /// Requires `zwlr_gamma_control_manager_v1`.
+    fn set_gamma_ramp(&self, red: &[u16], green: &[u16], blue: &[u16])
+        -> Result<(), ExternalError>;
 }
 
 impl MonitorHandleExtWayland for MonitorHandle {
@@ -143,4 +253,273 @@ impl MonitorHandleExtWayland for MonitorHandle {
     fn native_id(&self) -> u32 {
         self.inner.native_identifier()
     }
+
+    #[inline]
+    fn gamma_ramp(&self) -> Result<GammaRamp, ExternalError> {
+        self.inner.gamma_ramp()
+    }
+
+    #[inline]
+    fn set_gamma_ramp(
+        &self,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), ExternalError> {
+        self.inner.set_gamma_ramp(red, green, blue)
+    }
+}
+
+bitflags! {
+    /// The window management actions supported by the compositor.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct WmCapabilities: u32 {
+        /// The window menu can be shown.
+        const WINDOW_MENU = 1 << 0;
+        /// The window can be maximized.
+        const MAXIMIZE = 1 << 1;
+        /// The window can be made fullscreen.
+        const FULLSCREEN = 1 << 2;
+        /// The window can be minimized.
+        const MINIMIZE = 1 << 3;
+    }
+}
+
+/// The client side decorations frame.
+///
+/// The frame is drawn by winit into a set of subsurfaces placed around the window content, which
+/// are described by the [`DecorationFrame::layout`]. The coordinates used by the frame are in
+/// logical pixels and relative to the top-left corner of the window content, thus the frame
+/// parts placed above or to the left of the content have negative coordinates.
+///
+/// The frame is hidden by winit on its own when the user disables decorations and when the
+/// window is fullscreen, so the frame doesn't have to handle these cases.
+pub trait DecorationFrame: Send {
+    /// The size of the borders around the window content.
+    fn borders(&self) -> FrameBorders;
+
+    /// The parts of the frame for the window content of the given size.
+    ///
+    /// Each part is drawn into its own subsurface.
+    fn layout(&self, width: NonZeroU32, height: NonZeroU32) -> Vec<FramePart>;
+
+    /// Draw the part with the given `index` from the latest [`DecorationFrame::layout`].
+    fn draw(&mut self, index: usize, canvas: &mut FrameCanvas<'_>);
+
+    /// Whether the frame should be redrawn, for example, because the hovered button changed.
+    ///
+    /// Winit redraws the frame on its own when the frame state, size or title change.
+    fn is_dirty(&self) -> bool {
+        false
+    }
+
+    /// The pointer moved over the part with the given `index` to `x` and `y`, which are relative
+    /// to the window content like the [`FramePart`] location.
+    ///
+    /// The return value is the cursor icon to show for the given location.
+    fn pointer_moved(&mut self, index: usize, x: f64, y: f64) -> CursorIcon;
+
+    /// The pointer left the frame.
+    fn pointer_left(&mut self) {}
+
+    /// The click at the location from the latest [`DecorationFrame::pointer_moved`].
+    ///
+    /// The returned action is performed by winit.
+    fn on_click(&mut self, click: FrameClick, pressed: bool) -> Option<FrameAction>;
+
+    /// The state of the window changed.
+    fn set_state(&mut self, _state: FrameState) {}
+
+    /// The buttons the frame should show changed.
+    fn set_buttons(&mut self, _buttons: WindowButtons) {}
+
+    /// Whether the frame should offer resize actions.
+    fn set_resizable(&mut self, _resizable: bool) {}
+
+    /// The title of the window changed.
+    fn set_title(&mut self, _title: &str) {}
+
+    /// The theme the frame should use changed.
+    ///
+    /// `None` means that the frame should pick the theme on its own.
+    fn set_theme(&mut self, _theme: Option<Theme>) {}
+}
+
+/// The size of the client side decorations around the window content in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameBorders {
+    pub top: u32,
+    pub left: u32,
+    pub right: u32,
+    pub bottom: u32,
+}
+
+/// The part of the client side decorations frame.
+///
+/// The location is relative to the top-left corner of the window content in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FramePart {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// The buffer to draw the [`FramePart`] into.
+#[derive(Debug)]
+pub struct FrameCanvas<'a> {
+    pub(crate) buffer: &'a mut [u8],
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) scale: u32,
+}
+
+impl<'a> FrameCanvas<'a> {
+    /// The pixels of the part in the premultiplied `ARGB8888` format with native endianness.
+    ///
+    /// The buffer is cleared before each draw.
+    #[inline]
+    pub fn buffer(&mut self) -> &mut [u8] {
+        self.buffer
+    }
+
+    /// The width of the buffer in physical pixels.
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the buffer in physical pixels.
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The scale of the buffer, as in the ratio between physical and logical pixels.
+    #[inline]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+}
+
+/// The kind of click on the client side decorations frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameClick {
+    /// The normal click, usually with the left mouse button.
+    Normal,
+
+    /// The alternate click, usually with the right mouse button.
+    Alternate,
+}
+
+/// The action winit should perform in response to the click on the frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameAction {
+    /// Minimize the window.
+    Minimize,
+    /// Maximize the window.
+    Maximize,
+    /// Unmaximize the window.
+    UnMaximize,
+    /// Close the window.
+    Close,
+    /// Start the interactive move.
+    Move,
+    /// Start the interactive resize in the given direction.
+    Resize(ResizeDirection),
+    /// Show the window menu at the given location relative to the window content.
+    ShowMenu(i32, i32),
+}
+
+bitflags! {
+    /// The state of the window which affects the look of the frame.
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FrameState: u32 {
+        /// The window should be drawn as active.
+        const ACTIVATED = 1 << 0;
+        /// The window is maximized.
+        const MAXIMIZED = 1 << 1;
+        /// The window is fullscreen.
+        const FULLSCREEN = 1 << 2;
+        /// The window is being resized.
+        const RESIZING = 1 << 3;
+        /// The left edge of the window is adjacent to another window or the screen edge.
+        const TILED_LEFT = 1 << 4;
+        /// The right edge of the window is adjacent to another window or the screen edge.
+        const TILED_RIGHT = 1 << 5;
+        /// The top edge of the window is adjacent to another window or the screen edge.
+        const TILED_TOP = 1 << 6;
+        /// The bottom edge of the window is adjacent to another window or the screen edge.
+        const TILED_BOTTOM = 1 << 7;
+    }
+}
+
+/// The colors of the bundled client side decorations frame.
+///
+/// Only the colors can be customized, the frame doesn't allow to change its title font or the
+/// layout of its buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameTheme {
+    /// The colors used when the window is active.
+    pub active: FrameColors,
+
+    /// The colors used when the window is inactive.
+    pub inactive: FrameColors,
+}
+
+impl FrameTheme {
+    /// The colors matching the light Adwaita theme.
+    pub fn light() -> Self {
+        Self {
+            active: FrameColors {
+                headerbar: [235, 235, 235, 255],
+                button_idle: [216, 216, 216, 255],
+                button_hover: [207, 207, 207, 255],
+                button_icon: [42, 42, 42, 255],
+                border: [220, 220, 220, 255],
+                font: [47, 47, 47, 255],
+            },
+            inactive: FrameColors {
+                headerbar: [250, 250, 250, 255],
+                button_idle: [240, 240, 240, 255],
+                button_hover: [216, 216, 216, 255],
+                button_icon: [148, 148, 148, 255],
+                border: [220, 220, 220, 255],
+                font: [150, 150, 150, 255],
+            },
+        }
+    }
+
+    /// The colors matching the dark Adwaita theme.
+    pub fn dark() -> Self {
+        Self {
+            active: FrameColors {
+                headerbar: [48, 48, 48, 255],
+                button_idle: [69, 69, 69, 255],
+                button_hover: [79, 79, 79, 255],
+                button_icon: [255, 255, 255, 255],
+                border: [58, 58, 58, 255],
+                font: [255, 255, 255, 255],
+            },
+            inactive: FrameColors {
+                headerbar: [36, 36, 36, 255],
+                button_idle: [47, 47, 47, 255],
+                button_hover: [57, 57, 57, 255],
+                button_icon: [144, 144, 144, 255],
+                border: [58, 58, 58, 255],
+                font: [144, 144, 144, 255],
+            },
+        }
+    }
+}
+
+/// The colors of the frame parts as `[red, green, blue, alpha]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameColors {
+    pub headerbar: [u8; 4],
+    pub button_idle: [u8; 4],
+    pub button_hover: [u8; 4],
+    pub button_icon: [u8; 4],
+    pub border: [u8; 4],
+    pub font: [u8; 4],
 }
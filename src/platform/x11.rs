@@ -2,8 +2,9 @@ use std::os::raw;
 use std::ptr;
 
 use crate::{
+    error::ExternalError,
     event_loop::{EventLoopBuilder, EventLoopWindowTarget},
-    monitor::MonitorHandle,
+    monitor::{GammaRamp, MonitorHandle},
     window::{Window, WindowBuilder},
 };
 
@@ -223,6 +224,17 @@ impl WindowBuilderExtX11 for WindowBuilder {
 pub trait MonitorHandleExtX11 {
     /// Returns the inner identifier of the monitor.
     fn native_id(&self) -> u32;
+
+    /// Returns the gamma ramp of the CRTC driving the monitor.
+    fn gamma_ramp(&self) -> Result<GammaRamp, ExternalError>;
+
+    /// Sets the gamma ramp of the CRTC driving the monitor.
+    ///
+    /// The tables must have the length of the ramps of the CRTC, which can be queried with
+    /// [`MonitorHandleExtX11::gamma_ramp`]. The original ramps are restored when the event
+    /// loop exits.
+    fn set_gamma_ramp(&self, red: &[u16], green: &[u16], blue: &[u16])
+        -> Result<(), ExternalError>;
 }
 
 impl MonitorHandleExtX11 for MonitorHandle {
@@ -230,4 +242,19 @@ impl MonitorHandleExtX11 for MonitorHandle {
     fn native_id(&self) -> u32 {
         self.inner.native_identifier()
     }
+
+    #[inline]
+    fn gamma_ramp(&self) -> Result<GammaRamp, ExternalError> {
+        self.inner.gamma_ramp()
+    }
+
+    #[inline]
+    fn set_gamma_ramp(
+        &self,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), ExternalError> {
+        self.inner.set_gamma_ramp(red, green, blue)
+    }
 }
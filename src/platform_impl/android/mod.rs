@@ -26,8 +26,10 @@ use crate::{
     event::{self, StartCause},
     event_loop::{self, ControlFlow, EventLoopWindowTarget as RootELW},
     keyboard::NativeKey,
+    monitor::{MonitorTransform, SubpixelOrder},
     window::{
-        self, CursorGrabMode, ImePurpose, ResizeDirection, Theme, WindowButtons, WindowLevel,
+        self, CursorGrabMode, ImePurpose, ResizeDirection, Theme, TiledEdges, WindowButtons,
+        WindowLevel,
     },
 };
 
@@ -392,6 +394,7 @@ impl<T: 'static> EventLoop<T> {
                                         location,
                                         id: pointer.pointer_id() as u64,
                                         force: None,
+                                        contact: None,
                                     },
                                 ),
                             };
@@ -854,6 +857,10 @@ impl Window {
         false
     }
 
+    pub fn tiled_edges(&self) -> TiledEdges {
+        TiledEdges::empty()
+    }
+
     pub fn set_fullscreen(&self, _monitor: Option<Fullscreen>) {
         warn!("Cannot set fullscreen on Android");
     }
@@ -896,6 +903,26 @@ impl Window {
         ))
     }
 
+    pub fn set_seat_cursor_icon(
+        &self,
+        _: event::SeatId,
+        _: window::CursorIcon,
+    ) -> Result<(), error::ExternalError> {
+        Err(error::ExternalError::NotSupported(
+            error::NotSupportedError::new(),
+        ))
+    }
+
+    pub fn set_seat_cursor_grab(
+        &self,
+        _: event::SeatId,
+        _: CursorGrabMode,
+    ) -> Result<(), error::ExternalError> {
+        Err(error::ExternalError::NotSupported(
+            error::NotSupportedError::new(),
+        ))
+    }
+
     pub fn set_cursor_visible(&self, _: bool) {}
 
     pub fn drag_window(&self) -> Result<(), error::ExternalError> {
@@ -913,6 +940,8 @@ impl Window {
         ))
     }
 
+    pub fn show_window_menu(&self, _position: Position) {}
+
     pub fn set_cursor_hittest(&self, _hittest: bool) -> Result<(), error::ExternalError> {
         Err(error::ExternalError::NotSupported(
             error::NotSupportedError::new(),
@@ -1004,6 +1033,34 @@ impl MonitorHandle {
         (0, 0).into()
     }
 
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        (self.position(), self.size())
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    pub fn manufacturer(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        None
+    }
+
+    pub fn transform(&self) -> MonitorTransform {
+        MonitorTransform::Normal
+    }
+
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        SubpixelOrder::Unknown
+    }
+
     pub fn scale_factor(&self) -> f64 {
         self.app
             .config()
@@ -12,7 +12,7 @@ use objc2::rc::{Id, Shared};
 use super::uikit::{UIScreen, UIScreenMode};
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
-    monitor::VideoMode as RootVideoMode,
+    monitor::{MonitorTransform, SubpixelOrder, VideoMode as RootVideoMode},
     platform_impl::platform::app_state,
 };
 
@@ -168,6 +168,34 @@ impl Inner {
         (bounds.origin.x as f64, bounds.origin.y as f64).into()
     }
 
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        (self.position(), self.size())
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    pub fn manufacturer(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        None
+    }
+
+    pub fn transform(&self) -> MonitorTransform {
+        MonitorTransform::Normal
+    }
+
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        SubpixelOrder::Unknown
+    }
+
     pub fn scale_factor(&self) -> f64 {
         self.uiscreen.nativeScale() as f64
     }
@@ -253,6 +253,7 @@ impl WinitView {
                     id: touch_id,
                     location: physical_location,
                     force,
+                    contact: None,
                     phase,
                 }),
             }));
@@ -0,0 +1,261 @@
+//! The parts of the evdev interface used by the gamepads, from `linux/input.h`.
+
+#![allow(non_camel_case_types)]
+
+use std::io;
+use std::mem;
+use std::os::raw::{c_int, c_ulong};
+use std::os::unix::io::RawFd;
+
+// The event types.
+pub const EV_SYN: u16 = 0x00;
+pub const EV_KEY: u16 = 0x01;
+pub const EV_ABS: u16 = 0x03;
+pub const EV_FF: u16 = 0x15;
+
+// The synchronization events.
+pub const SYN_REPORT: u16 = 0;
+pub const SYN_DROPPED: u16 = 3;
+
+// The buttons of the gamepads.
+pub const BTN_JOYSTICK: u16 = 0x120;
+pub const BTN_SOUTH: u16 = 0x130;
+pub const BTN_EAST: u16 = 0x131;
+pub const BTN_NORTH: u16 = 0x133;
+pub const BTN_WEST: u16 = 0x134;
+pub const BTN_TL: u16 = 0x136;
+pub const BTN_TR: u16 = 0x137;
+pub const BTN_TL2: u16 = 0x138;
+pub const BTN_TR2: u16 = 0x139;
+pub const BTN_SELECT: u16 = 0x13a;
+pub const BTN_START: u16 = 0x13b;
+pub const BTN_MODE: u16 = 0x13c;
+pub const BTN_THUMBL: u16 = 0x13d;
+pub const BTN_THUMBR: u16 = 0x13e;
+pub const BTN_DPAD_UP: u16 = 0x220;
+pub const BTN_DPAD_DOWN: u16 = 0x221;
+pub const BTN_DPAD_LEFT: u16 = 0x222;
+pub const BTN_DPAD_RIGHT: u16 = 0x223;
+pub const KEY_CNT: usize = 0x300;
+
+// The absolute axes.
+pub const ABS_X: u16 = 0x00;
+pub const ABS_Y: u16 = 0x01;
+pub const ABS_Z: u16 = 0x02;
+pub const ABS_RX: u16 = 0x03;
+pub const ABS_RY: u16 = 0x04;
+pub const ABS_RZ: u16 = 0x05;
+pub const ABS_GAS: u16 = 0x09;
+pub const ABS_BRAKE: u16 = 0x0a;
+pub const ABS_HAT0X: u16 = 0x10;
+pub const ABS_HAT0Y: u16 = 0x11;
+pub const ABS_CNT: usize = 0x40;
+
+// The force feedback effects.
+pub const FF_RUMBLE: u16 = 0x50;
+pub const FF_CNT: usize = 0x80;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct input_event {
+    pub time: libc::timeval,
+    pub type_: u16,
+    pub code: u16,
+    pub value: i32,
+}
+
+impl input_event {
+    pub fn new(type_: u16, code: u16, value: i32) -> Self {
+        Self {
+            time: libc::timeval {
+                tv_sec: 0,
+                tv_usec: 0,
+            },
+            type_,
+            code,
+            value,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_id {
+    pub bustype: u16,
+    pub vendor: u16,
+    pub product: u16,
+    pub version: u16,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_absinfo {
+    pub value: i32,
+    pub minimum: i32,
+    pub maximum: i32,
+    pub fuzz: i32,
+    pub flat: i32,
+    pub resolution: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_trigger {
+    pub button: u16,
+    pub interval: u16,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_replay {
+    pub length: u16,
+    pub delay: u16,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ff_rumble_effect {
+    pub strong_magnitude: u16,
+    pub weak_magnitude: u16,
+}
+
+/// The largest member of the union of the effects, which gives it its size.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ff_periodic_effect {
+    pub waveform: u16,
+    pub period: u16,
+    pub magnitude: i16,
+    pub offset: i16,
+    pub phase: u16,
+    pub envelope: [u16; 4],
+    pub custom_len: u32,
+    pub custom_data: *mut i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ff_effect_union {
+    pub rumble: ff_rumble_effect,
+    pub periodic: ff_periodic_effect,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ff_effect {
+    pub type_: u16,
+    pub id: i16,
+    pub direction: u16,
+    pub trigger: ff_trigger,
+    pub replay: ff_replay,
+    pub u: ff_effect_union,
+}
+
+// The `_IOC` encoding of the requests.
+const IOC_WRITE: c_ulong = 1;
+const IOC_READ: c_ulong = 2;
+
+const fn ioc(dir: c_ulong, nr: c_ulong, size: usize) -> c_ulong {
+    (dir << 30) | ((size as c_ulong) << 16) | ((b'E' as c_ulong) << 8) | nr
+}
+
+const EVIOCGID: c_ulong = ioc(IOC_READ, 0x02, mem::size_of::<input_id>());
+const EVIOCSFF: c_ulong = ioc(IOC_WRITE, 0x80, mem::size_of::<ff_effect>());
+
+const fn eviocgname(len: usize) -> c_ulong {
+    ioc(IOC_READ, 0x06, len)
+}
+
+const fn eviocgkey(len: usize) -> c_ulong {
+    ioc(IOC_READ, 0x18, len)
+}
+
+const fn eviocgbit(ev: u16, len: usize) -> c_ulong {
+    ioc(IOC_READ, 0x20 + ev as c_ulong, len)
+}
+
+const fn eviocgabs(abs: u16) -> c_ulong {
+    ioc(
+        IOC_READ,
+        0x40 + abs as c_ulong,
+        mem::size_of::<input_absinfo>(),
+    )
+}
+
+unsafe fn ioctl<T>(fd: RawFd, request: c_ulong, arg: *mut T) -> io::Result<c_int> {
+    match libc::ioctl(fd, request as _, arg) {
+        -1 => Err(io::Error::last_os_error()),
+        ret => Ok(ret),
+    }
+}
+
+/// The name of the device.
+pub fn name(fd: RawFd) -> io::Result<String> {
+    let mut name = [0u8; 256];
+    let len = unsafe { ioctl(fd, eviocgname(name.len()), name.as_mut_ptr())? } as usize;
+    let name = &name[..len.min(name.len())];
+    let name = name.split(|&byte| byte == 0).next().unwrap_or_default();
+    Ok(String::from_utf8_lossy(name).into_owned())
+}
+
+/// The bus, vendor and product of the device.
+pub fn id(fd: RawFd) -> io::Result<input_id> {
+    let mut id = input_id::default();
+    unsafe { ioctl(fd, EVIOCGID, &mut id)? };
+    Ok(id)
+}
+
+/// The codes of the given event type supported by the device, as a bitmask.
+pub fn bits<const N: usize>(fd: RawFd, ev: u16) -> io::Result<Bits<N>> {
+    let mut bits = [0u8; N];
+    unsafe { ioctl(fd, eviocgbit(ev, N), bits.as_mut_ptr())? };
+    Ok(Bits(bits))
+}
+
+/// The keys currently pressed on the device, as a bitmask.
+pub fn key_state<const N: usize>(fd: RawFd) -> io::Result<Bits<N>> {
+    let mut bits = [0u8; N];
+    unsafe { ioctl(fd, eviocgkey(N), bits.as_mut_ptr())? };
+    Ok(Bits(bits))
+}
+
+/// The range and the current value of an absolute axis.
+pub fn absinfo(fd: RawFd, abs: u16) -> io::Result<input_absinfo> {
+    let mut absinfo = input_absinfo::default();
+    unsafe { ioctl(fd, eviocgabs(abs), &mut absinfo)? };
+    Ok(absinfo)
+}
+
+/// Upload the effect, the kernel assigns its id when it is `-1`.
+pub fn upload_effect(fd: RawFd, effect: &mut ff_effect) -> io::Result<()> {
+    unsafe { ioctl(fd, EVIOCSFF, effect)? };
+    Ok(())
+}
+
+/// A bitmask of the codes, indexed by code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bits<const N: usize>([u8; N]);
+
+impl<const N: usize> Default for Bits<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> Bits<N> {
+    pub fn contains(&self, code: u16) -> bool {
+        let code = code as usize;
+        code / 8 < N && self.0[code / 8] & (1 << (code % 8)) != 0
+    }
+
+    pub fn set(&mut self, code: u16, value: bool) {
+        let code = code as usize;
+        if code / 8 < N {
+            if value {
+                self.0[code / 8] |= 1 << (code % 8);
+            } else {
+                self.0[code / 8] &= !(1 << (code % 8));
+            }
+        }
+    }
+}
@@ -0,0 +1,912 @@
+//! Gamepads and joysticks, read from their evdev devices.
+//!
+//! The devices are discovered in `/dev/input` and hotplugged by watching the directory with
+//! inotify, without going through udev. The inotify instance and the devices are gathered in an
+//! epoll instance, so a single file descriptor has to be polled by the event loop.
+
+use std::ffi::{CString, OsStr};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::path::{Path, PathBuf};
+use std::slice;
+use std::time::Duration;
+
+use crate::event::{
+    DeviceEvent, DeviceId as RootDeviceId, DeviceInfo, DeviceKind, ElementState, GamepadAxis,
+    GamepadButton,
+};
+use crate::event_loop::DeviceEvents;
+use crate::platform_impl::platform::DeviceId as PlatformDeviceId;
+
+mod evdev;
+
+use evdev::*;
+
+const INPUT_DIR: &str = "/dev/input";
+
+/// The id of a gamepad, unique for the lifetime of the event loop.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GamepadId(u32);
+
+impl GamepadId {
+    fn device_id(self) -> RootDeviceId {
+        RootDeviceId(PlatformDeviceId::Gamepad(self))
+    }
+}
+
+/// Watch the gamepads of the system.
+///
+/// The failures are only logged, since the devices are often inaccessible.
+pub fn watch_gamepads() -> Option<Gamepads> {
+    match Gamepads::new() {
+        Ok(gamepads) => Some(gamepads),
+        Err(err) => {
+            warn!("Failed to watch the gamepads in {INPUT_DIR}: {err}");
+            None
+        }
+    }
+}
+
+/// Open the device at the path, returning `None` when it isn't a gamepad.
+type OpenFn = fn(&Path, GamepadId) -> io::Result<Option<Gamepad>>;
+
+/// Query the pressed keys and the values of the axes of the gamepad.
+type StateFn = fn(&Gamepad) -> io::Result<(Keys, Vec<(u16, i32)>)>;
+
+type Keys = Bits<{ KEY_CNT / 8 }>;
+
+/// Whether the event of the gamepads passes the [`DeviceEvents`] filter, given whether a window
+/// of the event loop has focus.
+///
+/// The gamepads are always reported as added and removed, only their input is filtered.
+pub fn is_listened(event: &DeviceEvent, filter: DeviceEvents, focused: bool) -> bool {
+    match event {
+        DeviceEvent::Added | DeviceEvent::Removed => true,
+        _ => match filter {
+            DeviceEvents::Always => true,
+            DeviceEvents::WhenFocused => focused,
+            DeviceEvents::Never => false,
+        },
+    }
+}
+
+/// The gamepads connected to the system.
+pub struct Gamepads {
+    /// The directory of the devices.
+    dir: PathBuf,
+
+    /// The instance watching the directory.
+    inotify: File,
+
+    /// The instance gathering the inotify instance and the gamepads.
+    epoll: File,
+
+    gamepads: Vec<Gamepad>,
+
+    /// The paths which failed to open, to retry them once their permissions change.
+    pending: Vec<PathBuf>,
+
+    next_id: u32,
+
+    open: OpenFn,
+}
+
+impl Gamepads {
+    fn new() -> io::Result<Self> {
+        Self::with_dir(INPUT_DIR, Gamepad::open)
+    }
+
+    fn with_dir(dir: impl Into<PathBuf>, open: OpenFn) -> io::Result<Self> {
+        let dir = dir.into();
+        let epoll = cvt(unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) })?;
+        let epoll = unsafe { File::from_raw_fd(epoll) };
+        let inotify = cvt(unsafe { libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC) })?;
+        let inotify = unsafe { File::from_raw_fd(inotify) };
+
+        // The devices are created by udev, which changes their permissions afterwards.
+        let dir_path = CString::new(dir.as_os_str().as_bytes())?;
+        cvt(unsafe {
+            libc::inotify_add_watch(
+                inotify.as_raw_fd(),
+                dir_path.as_ptr(),
+                libc::IN_CREATE | libc::IN_ATTRIB | libc::IN_DELETE,
+            )
+        })?;
+
+        let mut gamepads = Gamepads {
+            dir,
+            inotify,
+            epoll,
+            gamepads: Vec::new(),
+            pending: Vec::new(),
+            next_id: 0,
+            open,
+        };
+        gamepads.watch(gamepads.inotify.as_raw_fd())?;
+
+        // The gamepads connected before the event loop started aren't hotplugged.
+        let mut paths: Vec<_> = fs::read_dir(&gamepads.dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect();
+        paths.sort();
+        for path in paths {
+            gamepads.add(&path);
+        }
+
+        Ok(gamepads)
+    }
+
+    /// Add the file descriptor to the epoll instance.
+    fn watch(&self, fd: RawFd) -> io::Result<()> {
+        let mut event = libc::epoll_event {
+            events: libc::EPOLLIN as u32,
+            u64: fd as u64,
+        };
+        cvt(unsafe {
+            libc::epoll_ctl(self.epoll.as_raw_fd(), libc::EPOLL_CTL_ADD, fd, &mut event)
+        })?;
+        Ok(())
+    }
+
+    /// Open the device if it's a gamepad, returning its id.
+    fn add(&mut self, path: &Path) -> Option<GamepadId> {
+        let is_event_device = path
+            .file_name()
+            .map_or(false, |name| name.as_bytes().starts_with(b"event"));
+        if !is_event_device || self.gamepads.iter().any(|gamepad| gamepad.path == path) {
+            return None;
+        }
+
+        let id = GamepadId(self.next_id);
+        let gamepad = match (self.open)(path, id) {
+            Ok(Some(gamepad)) => gamepad,
+            Ok(None) => return None,
+            Err(err) => {
+                if !self.pending.iter().any(|pending| pending == path) {
+                    self.pending.push(path.to_owned());
+                }
+                debug!("Failed to open the input device {}: {err}", path.display());
+                return None;
+            }
+        };
+        self.pending.retain(|pending| pending != path);
+
+        if let Err(err) = self.watch(gamepad.file.as_raw_fd()) {
+            warn!("Failed to watch the gamepad {}: {err}", path.display());
+            return None;
+        }
+
+        self.next_id += 1;
+        self.gamepads.push(gamepad);
+        Some(id)
+    }
+
+    /// Read the pending hotplug and input events.
+    pub fn dispatch<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(RootDeviceId, DeviceEvent),
+    {
+        for (mask, name) in self.read_inotify() {
+            let path = self.dir.join(name);
+            if mask & libc::IN_DELETE != 0 {
+                self.pending.retain(|pending| *pending != path);
+                if let Some(index) = self.gamepads.iter().position(|g| g.path == path) {
+                    let gamepad = self.gamepads.remove(index);
+                    callback(gamepad.id.device_id(), DeviceEvent::Removed);
+                }
+            } else if mask & libc::IN_CREATE != 0 || self.pending.contains(&path) {
+                if let Some(id) = self.add(&path) {
+                    callback(id.device_id(), DeviceEvent::Added);
+                }
+            }
+        }
+
+        let mut index = 0;
+        while let Some(gamepad) = self.gamepads.get_mut(index) {
+            let device_id = gamepad.id.device_id();
+            match gamepad.read(|event| callback(device_id, event)) {
+                Ok(()) => index += 1,
+                Err(err) => {
+                    // The device is gone before its file, e.g. the gamepad was unplugged.
+                    if err.raw_os_error() != Some(libc::ENODEV) {
+                        warn!(
+                            "Failed to read the gamepad {}: {err}",
+                            gamepad.path.display()
+                        );
+                    }
+                    self.gamepads.remove(index);
+                    callback(device_id, DeviceEvent::Removed);
+                }
+            }
+        }
+    }
+
+    /// Read the changes of the directory, as their mask and the name of the file.
+    fn read_inotify(&mut self) -> Vec<(u32, PathBuf)> {
+        let mut changes = Vec::new();
+        let mut buffer = [0u8; 4096];
+        loop {
+            let len = match self.inotify.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            };
+
+            // Each event is a `struct inotify_event` followed by its null-padded name.
+            let mut events = &buffer[..len];
+            while events.len() >= mem::size_of::<libc::inotify_event>() {
+                let field = |offset: usize| {
+                    u32::from_ne_bytes(events[offset..offset + 4].try_into().unwrap())
+                };
+                let mask = field(4);
+                let name_len = field(12) as usize;
+                let name = &events[16..16 + name_len];
+                let name = name.split(|&byte| byte == 0).next().unwrap_or_default();
+                changes.push((mask, PathBuf::from(OsStr::from_bytes(name))));
+                events = &events[16 + name_len..];
+            }
+        }
+
+        changes
+    }
+
+    /// Describe the gamepads.
+    pub fn devices(&self) -> impl Iterator<Item = DeviceInfo> + '_ {
+        self.gamepads.iter().map(|gamepad| DeviceInfo {
+            id: gamepad.id.device_id(),
+            name: gamepad.name.clone(),
+            kind: DeviceKind::Gamepad,
+            vendor_id: Some(gamepad.input_id.vendor),
+            product_id: Some(gamepad.input_id.product),
+            seat: None,
+        })
+    }
+
+    /// Describe the gamepad.
+    pub fn device_info(&self, id: GamepadId) -> Option<DeviceInfo> {
+        self.devices().find(|device| device.id() == id.device_id())
+    }
+
+    /// Make the gamepad rumble.
+    pub fn set_rumble(
+        &mut self,
+        id: GamepadId,
+        strong: f32,
+        weak: f32,
+        duration: Duration,
+    ) -> io::Result<()> {
+        match self.gamepads.iter_mut().find(|gamepad| gamepad.id == id) {
+            Some(gamepad) => gamepad.set_rumble(strong, weak, duration),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "the gamepad is disconnected",
+            )),
+        }
+    }
+}
+
+impl AsRawFd for Gamepads {
+    fn as_raw_fd(&self) -> RawFd {
+        self.epoll.as_raw_fd()
+    }
+}
+
+/// An evdev device with the buttons of a gamepad or a joystick.
+#[derive(Debug)]
+struct Gamepad {
+    id: GamepadId,
+    path: PathBuf,
+    file: File,
+    name: String,
+    input_id: input_id,
+
+    /// Whether the device follows the layout of the gamepads, rather than being a joystick.
+    standard: bool,
+
+    /// The keys currently pressed.
+    keys: Keys,
+
+    /// The ranges and the latest values of the absolute axes.
+    axes: Vec<(u16, input_absinfo)>,
+
+    /// The latest direction of the hat, reported as the buttons of the D-pad.
+    hat: (i32, i32),
+
+    /// The id of the rumble effect, `-1` until uploaded, `None` when the device can't rumble.
+    rumble_effect: Option<i16>,
+
+    /// The events of the current report, applied once the report is complete.
+    pending: Vec<input_event>,
+
+    /// Whether the kernel dropped events, in which case the events are skipped until the next
+    /// report, and the state of the device is queried to catch up.
+    dropped: bool,
+
+    query_state: StateFn,
+}
+
+impl Gamepad {
+    fn open(path: &Path, id: GamepadId) -> io::Result<Option<Self>> {
+        let open = |write| {
+            OpenOptions::new()
+                .read(true)
+                .write(write)
+                .custom_flags(libc::O_NONBLOCK | libc::O_CLOEXEC)
+                .open(path)
+        };
+        // The rumble needs to write to the device, which may be forbidden.
+        let (file, writable) = match open(true) {
+            Ok(file) => (file, true),
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => (open(false)?, false),
+            Err(err) => return Err(err),
+        };
+
+        let fd = file.as_raw_fd();
+        let keys = evdev::bits::<{ KEY_CNT / 8 }>(fd, EV_KEY)?;
+        let standard = keys.contains(BTN_SOUTH);
+        if !standard && !keys.contains(BTN_JOYSTICK) {
+            return Ok(None);
+        }
+
+        let abs = evdev::bits::<{ ABS_CNT / 8 }>(fd, EV_ABS)?;
+        let axes = (0..ABS_CNT as u16)
+            .filter(|&code| abs.contains(code))
+            .filter_map(|code| Some((code, evdev::absinfo(fd, code).ok()?)))
+            .collect();
+
+        let rumble = writable && evdev::bits::<{ FF_CNT / 8 }>(fd, EV_FF)?.contains(FF_RUMBLE);
+
+        Ok(Some(Gamepad {
+            id,
+            path: path.to_owned(),
+            name: evdev::name(fd)?,
+            input_id: evdev::id(fd)?,
+            // The buttons held while plugging the gamepad are already pressed.
+            keys: evdev::key_state(fd)?,
+            file,
+            standard,
+            axes,
+            hat: (0, 0),
+            rumble_effect: rumble.then_some(-1),
+            pending: Vec::new(),
+            dropped: false,
+            query_state: Gamepad::state,
+        }))
+    }
+
+    /// Read the pressed keys and the values of the axes from the device.
+    fn state(&self) -> io::Result<(Keys, Vec<(u16, i32)>)> {
+        let fd = self.file.as_raw_fd();
+        let values = self
+            .axes
+            .iter()
+            .map(|&(code, _)| Ok((code, evdev::absinfo(fd, code)?.value)))
+            .collect::<io::Result<_>>()?;
+        Ok((evdev::key_state(fd)?, values))
+    }
+
+    /// Read the pending events of the device.
+    fn read<F: FnMut(DeviceEvent)>(&mut self, mut callback: F) -> io::Result<()> {
+        const EVENT_SIZE: usize = mem::size_of::<input_event>();
+        let mut events = [unsafe { mem::zeroed::<input_event>() }; 64];
+        loop {
+            let buffer = unsafe {
+                slice::from_raw_parts_mut(events.as_mut_ptr() as *mut u8, events.len() * EVENT_SIZE)
+            };
+            let len = match self.file.read(buffer) {
+                Ok(0) => return Ok(()),
+                Ok(len) => len,
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+
+            for event in &events[..len / EVENT_SIZE] {
+                self.process_event(event, &mut callback);
+            }
+        }
+    }
+
+    fn process_event<F: FnMut(DeviceEvent)>(&mut self, event: &input_event, callback: &mut F) {
+        match (event.type_, event.code) {
+            // The incomplete report is dropped along with the events the kernel couldn't queue.
+            (EV_SYN, SYN_DROPPED) => {
+                self.dropped = true;
+                self.pending.clear();
+            }
+            (EV_SYN, SYN_REPORT) if self.dropped => {
+                self.dropped = false;
+                if let Err(err) = self.resync(callback) {
+                    warn!(
+                        "Failed to query the state of the gamepad {}: {err}",
+                        self.path.display()
+                    );
+                }
+            }
+            (EV_SYN, SYN_REPORT) => {
+                for event in mem::take(&mut self.pending) {
+                    self.apply_event(&event, callback);
+                }
+            }
+            _ if self.dropped => (),
+            (EV_KEY, _) | (EV_ABS, _) => self.pending.push(*event),
+            _ => (),
+        }
+    }
+
+    /// Update the state of the device with the event of a complete report, and report the
+    /// change.
+    fn apply_event<F: FnMut(DeviceEvent)>(&mut self, event: &input_event, callback: &mut F) {
+        match (event.type_, event.code) {
+            // The key repeats are reported with the value 2.
+            (EV_KEY, code) if event.value <= 1 => {
+                let pressed = event.value == 1;
+                self.keys.set(code, pressed);
+                let state = if pressed {
+                    ElementState::Pressed
+                } else {
+                    ElementState::Released
+                };
+                callback(DeviceEvent::GamepadButton {
+                    button: self.button(code),
+                    state,
+                });
+            }
+            (EV_ABS, code) => {
+                let info = match self.axes.iter_mut().find(|(axis, _)| *axis == code) {
+                    Some((_, info)) => {
+                        info.value = event.value;
+                        *info
+                    }
+                    None => return,
+                };
+
+                match code {
+                    ABS_HAT0X if self.standard => {
+                        let hat = (event.value.signum(), self.hat.1);
+                        self.move_hat(hat, callback);
+                    }
+                    ABS_HAT0Y if self.standard => {
+                        let hat = (self.hat.0, event.value.signum());
+                        self.move_hat(hat, callback);
+                    }
+                    _ => {
+                        let axis = self.axis(code);
+                        let value = normalize(axis, &info, event.value);
+                        callback(DeviceEvent::GamepadAxis { axis, value });
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Report the changes missed while the kernel dropped the events, from the current state of
+    /// the device.
+    fn resync<F: FnMut(DeviceEvent)>(&mut self, callback: &mut F) -> io::Result<()> {
+        let (keys, values) = (self.query_state)(self)?;
+
+        let mut events = Vec::new();
+        for code in 0..KEY_CNT as u16 {
+            let pressed = keys.contains(code);
+            if pressed != self.keys.contains(code) {
+                events.push(input_event::new(EV_KEY, code, pressed as i32));
+            }
+        }
+        for (code, value) in values {
+            let changed = self
+                .axes
+                .iter()
+                .any(|&(axis, info)| axis == code && info.value != value);
+            if changed {
+                events.push(input_event::new(EV_ABS, code, value));
+            }
+        }
+
+        for event in &events {
+            self.apply_event(event, callback);
+        }
+        Ok(())
+    }
+
+    /// Report the change of direction of the hat as the buttons of the D-pad.
+    fn move_hat<F: FnMut(DeviceEvent)>(&mut self, hat: (i32, i32), callback: &mut F) {
+        let buttons = |(x, y): (i32, i32)| {
+            let x = match x {
+                -1 => Some(GamepadButton::DPadLeft),
+                1 => Some(GamepadButton::DPadRight),
+                _ => None,
+            };
+            let y = match y {
+                -1 => Some(GamepadButton::DPadUp),
+                1 => Some(GamepadButton::DPadDown),
+                _ => None,
+            };
+            [x, y]
+        };
+
+        let released = buttons(self.hat);
+        let pressed = buttons(hat);
+        for (released, pressed) in released.into_iter().zip(pressed) {
+            if released == pressed {
+                continue;
+            }
+            if let Some(button) = released {
+                callback(DeviceEvent::GamepadButton {
+                    button,
+                    state: ElementState::Released,
+                });
+            }
+            if let Some(button) = pressed {
+                callback(DeviceEvent::GamepadButton {
+                    button,
+                    state: ElementState::Pressed,
+                });
+            }
+        }
+        self.hat = hat;
+    }
+
+    fn button(&self, code: u16) -> GamepadButton {
+        if !self.standard {
+            return GamepadButton::Other(code);
+        }
+
+        match code {
+            BTN_SOUTH => GamepadButton::South,
+            BTN_EAST => GamepadButton::East,
+            BTN_NORTH => GamepadButton::North,
+            BTN_WEST => GamepadButton::West,
+            BTN_TL => GamepadButton::LeftBumper,
+            BTN_TR => GamepadButton::RightBumper,
+            BTN_TL2 => GamepadButton::LeftTrigger,
+            BTN_TR2 => GamepadButton::RightTrigger,
+            BTN_SELECT => GamepadButton::Select,
+            BTN_START => GamepadButton::Start,
+            BTN_MODE => GamepadButton::Mode,
+            BTN_THUMBL => GamepadButton::LeftStick,
+            BTN_THUMBR => GamepadButton::RightStick,
+            BTN_DPAD_UP => GamepadButton::DPadUp,
+            BTN_DPAD_DOWN => GamepadButton::DPadDown,
+            BTN_DPAD_LEFT => GamepadButton::DPadLeft,
+            BTN_DPAD_RIGHT => GamepadButton::DPadRight,
+            code => GamepadButton::Other(code),
+        }
+    }
+
+    fn axis(&self, code: u16) -> GamepadAxis {
+        match code {
+            ABS_X => GamepadAxis::LeftStickX,
+            ABS_Y => GamepadAxis::LeftStickY,
+            ABS_RX if self.standard => GamepadAxis::RightStickX,
+            ABS_RY if self.standard => GamepadAxis::RightStickY,
+            ABS_Z | ABS_BRAKE if self.standard => GamepadAxis::LeftTrigger,
+            ABS_RZ | ABS_GAS if self.standard => GamepadAxis::RightTrigger,
+            code => GamepadAxis::Other(code),
+        }
+    }
+
+    fn set_rumble(&mut self, strong: f32, weak: f32, duration: Duration) -> io::Result<()> {
+        let id = self.rumble_effect.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::Unsupported, "the gamepad can't rumble")
+        })?;
+
+        let magnitude = |magnitude: f32| (magnitude.clamp(0.0, 1.0) * u16::MAX as f32) as u16;
+        let mut effect = ff_effect {
+            type_: FF_RUMBLE,
+            id,
+            direction: 0,
+            trigger: Default::default(),
+            replay: ff_replay {
+                length: duration.as_millis().min(u16::MAX as u128) as u16,
+                delay: 0,
+            },
+            u: ff_effect_union {
+                rumble: ff_rumble_effect {
+                    strong_magnitude: magnitude(strong),
+                    weak_magnitude: magnitude(weak),
+                },
+            },
+        };
+        evdev::upload_effect(self.file.as_raw_fd(), &mut effect)?;
+        self.rumble_effect = Some(effect.id);
+
+        let play = input_event::new(EV_FF, effect.id as u16, 1);
+        let play = unsafe {
+            slice::from_raw_parts(
+                &play as *const input_event as *const u8,
+                mem::size_of::<input_event>(),
+            )
+        };
+        self.file.write_all(play)
+    }
+}
+
+/// Normalize the value of the axis, from 0 to 1 for the triggers and from -1 to 1 otherwise.
+fn normalize(axis: GamepadAxis, info: &input_absinfo, value: i32) -> f64 {
+    let (min, max) = (info.minimum as f64, info.maximum as f64);
+    if max <= min {
+        return 0.;
+    }
+
+    let value = value as f64;
+    match axis {
+        GamepadAxis::LeftTrigger | GamepadAxis::RightTrigger => {
+            ((value - min) / (max - min)).clamp(0., 1.)
+        }
+        _ => {
+            let center = (min + max) / 2.;
+            if (value - center).abs() <= info.flat as f64 {
+                0.
+            } else {
+                ((value - center) / (max - center)).clamp(-1., 1.)
+            }
+        }
+    }
+}
+
+fn cvt(ret: libc::c_int) -> io::Result<libc::c_int> {
+    if ret == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// An Xbox controller, with the ranges reported by `xpad`, whose events are read from a FIFO
+    /// standing for the device.
+    fn fake_open(path: &Path, id: GamepadId) -> io::Result<Option<Gamepad>> {
+        let stick = input_absinfo {
+            minimum: -32768,
+            maximum: 32767,
+            flat: 128,
+            ..Default::default()
+        };
+        let trigger = input_absinfo {
+            maximum: 1023,
+            ..Default::default()
+        };
+        let hat = input_absinfo {
+            minimum: -1,
+            maximum: 1,
+            ..Default::default()
+        };
+
+        Ok(Some(Gamepad {
+            id,
+            path: path.to_owned(),
+            file: OpenOptions::new()
+                .read(true)
+                .custom_flags(libc::O_NONBLOCK)
+                .open(path)?,
+            name: "Microsoft X-Box 360 pad".into(),
+            input_id: input_id {
+                bustype: 3,
+                vendor: 0x045e,
+                product: 0x028e,
+                version: 0x110,
+            },
+            standard: true,
+            axes: vec![
+                (ABS_X, stick),
+                (ABS_Y, stick),
+                (ABS_Z, trigger),
+                (ABS_RX, stick),
+                (ABS_RY, stick),
+                (ABS_RZ, trigger),
+                (ABS_HAT0X, hat),
+                (ABS_HAT0Y, hat),
+            ],
+            keys: Keys::default(),
+            hat: (0, 0),
+            rumble_effect: None,
+            pending: Vec::new(),
+            dropped: false,
+            query_state: fake_state,
+        }))
+    }
+
+    /// The state of the controller once the recorded stream is over, which the events dropped by
+    /// the kernel are recovered from.
+    fn fake_state(gamepad: &Gamepad) -> io::Result<(Keys, Vec<(u16, i32)>)> {
+        let mut keys = Keys::default();
+        keys.set(BTN_EAST, true);
+        keys.set(BTN_SOUTH, true);
+        let values = [
+            (ABS_X, 32767),
+            (ABS_Y, 100),
+            (ABS_RZ, 0),
+            (ABS_HAT0X, 1),
+            (ABS_HAT0Y, 1),
+        ];
+        let values = gamepad
+            .axes
+            .iter()
+            .map(|&(code, _)| {
+                let value = values.iter().find(|(axis, _)| *axis == code);
+                (code, value.map_or(0, |&(_, value)| value))
+            })
+            .collect();
+        Ok((keys, values))
+    }
+
+    /// Create a fake device replaying the recorded events, returning its writing end.
+    fn plug(dir: &Path, name: &str, events: &[(u16, u16, i32)]) -> File {
+        let path = dir.join(name);
+        let c_path = CString::new(path.as_os_str().as_bytes()).unwrap();
+        cvt(unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) }).unwrap();
+
+        // Opening both ends doesn't wait for a reader.
+        let mut device = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&path)
+            .unwrap();
+        device.write_all(&record(events)).unwrap();
+        device
+    }
+
+    /// Serialize the events as the kernel reports them.
+    fn record(events: &[(u16, u16, i32)]) -> Vec<u8> {
+        let mut stream = Vec::new();
+        for &(type_, code, value) in events {
+            let event = input_event::new(type_, code, value);
+            stream.extend_from_slice(unsafe {
+                slice::from_raw_parts(
+                    &event as *const input_event as *const u8,
+                    mem::size_of::<input_event>(),
+                )
+            });
+        }
+        stream
+    }
+
+    /// A directory standing for `/dev/input`, removed on drop.
+    struct InputDir(PathBuf);
+
+    impl InputDir {
+        fn new() -> Self {
+            static COUNT: AtomicUsize = AtomicUsize::new(0);
+            let dir = std::env::temp_dir().join(format!(
+                "winit-gamepad-{}-{}",
+                std::process::id(),
+                COUNT.fetch_add(1, Ordering::Relaxed)
+            ));
+            fs::create_dir(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for InputDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn dispatch(gamepads: &mut Gamepads) -> Vec<DeviceEvent> {
+        let mut events = Vec::new();
+        gamepads.dispatch(|_, event| events.push(event));
+        events
+    }
+
+    fn button(button: GamepadButton, state: ElementState) -> DeviceEvent {
+        DeviceEvent::GamepadButton { button, state }
+    }
+
+    fn axis(axis: GamepadAxis, value: f64) -> DeviceEvent {
+        DeviceEvent::GamepadAxis { axis, value }
+    }
+
+    #[test]
+    fn decodes_recorded_stream() {
+        let dir = InputDir::new();
+        let _device = plug(
+            &dir.0,
+            "event4",
+            &[
+                (EV_KEY, BTN_SOUTH, 1),
+                (EV_SYN, SYN_REPORT, 0),
+                (EV_ABS, ABS_X, 32767),
+                (EV_ABS, ABS_Y, 100),
+                (EV_ABS, ABS_RZ, 1023),
+                (EV_SYN, SYN_REPORT, 0),
+                (EV_ABS, ABS_HAT0X, -1),
+                (EV_SYN, SYN_REPORT, 0),
+                (EV_ABS, ABS_HAT0X, 1),
+                (EV_ABS, ABS_HAT0Y, 1),
+                (EV_SYN, SYN_REPORT, 0),
+                // The start of a report, which the kernel couldn't complete.
+                (EV_KEY, BTN_NORTH, 1),
+                (EV_ABS, ABS_Y, -32768),
+                (EV_SYN, SYN_DROPPED, 0),
+                (EV_KEY, BTN_EAST, 1),
+                (EV_ABS, ABS_RZ, 0),
+                (EV_SYN, SYN_REPORT, 0),
+                (EV_KEY, BTN_SOUTH, 0),
+                (EV_SYN, SYN_REPORT, 0),
+            ],
+        );
+
+        let mut gamepads = Gamepads::with_dir(&dir.0, fake_open).unwrap();
+        assert_eq!(gamepads.devices().count(), 1);
+        assert_eq!(
+            dispatch(&mut gamepads),
+            [
+                button(GamepadButton::South, ElementState::Pressed),
+                axis(GamepadAxis::LeftStickX, 1.),
+                axis(GamepadAxis::LeftStickY, 0.),
+                axis(GamepadAxis::RightTrigger, 1.),
+                button(GamepadButton::DPadLeft, ElementState::Pressed),
+                button(GamepadButton::DPadLeft, ElementState::Released),
+                button(GamepadButton::DPadRight, ElementState::Pressed),
+                button(GamepadButton::DPadDown, ElementState::Pressed),
+                // The dropped events are recovered from the state of the device.
+                button(GamepadButton::East, ElementState::Pressed),
+                axis(GamepadAxis::RightTrigger, 0.),
+                button(GamepadButton::South, ElementState::Released),
+            ]
+        );
+    }
+
+    #[test]
+    fn filters_input_events() {
+        let input = button(GamepadButton::South, ElementState::Pressed);
+        assert!(is_listened(&input, DeviceEvents::Always, false));
+        assert!(is_listened(&input, DeviceEvents::WhenFocused, true));
+        assert!(!is_listened(&input, DeviceEvents::WhenFocused, false));
+        assert!(!is_listened(&input, DeviceEvents::Never, true));
+
+        // The hotplug is always reported.
+        assert!(is_listened(&DeviceEvent::Added, DeviceEvents::Never, false));
+        assert!(is_listened(
+            &DeviceEvent::Removed,
+            DeviceEvents::Never,
+            false
+        ));
+    }
+
+    #[test]
+    fn hotplugs_devices() {
+        let dir = InputDir::new();
+        let mut gamepads = Gamepads::with_dir(&dir.0, fake_open).unwrap();
+        assert!(dispatch(&mut gamepads).is_empty());
+
+        // Only the event devices are gamepads.
+        fs::write(dir.0.join("js0"), []).unwrap();
+        let _device = plug(
+            &dir.0,
+            "event7",
+            &[(EV_KEY, BTN_START, 1), (EV_SYN, SYN_REPORT, 0)],
+        );
+        assert_eq!(
+            dispatch(&mut gamepads),
+            [
+                DeviceEvent::Added,
+                button(GamepadButton::Start, ElementState::Pressed),
+            ]
+        );
+
+        let info = gamepads.devices().next().unwrap();
+        assert_eq!(info.kind(), DeviceKind::Gamepad);
+        assert_eq!(info.name(), "Microsoft X-Box 360 pad");
+        assert_eq!(
+            (info.vendor_id(), info.product_id()),
+            (Some(0x045e), Some(0x028e))
+        );
+
+        fs::remove_file(dir.0.join("event7")).unwrap();
+        assert_eq!(dispatch(&mut gamepads), [DeviceEvent::Removed]);
+        assert_eq!(gamepads.devices().count(), 0);
+    }
+}
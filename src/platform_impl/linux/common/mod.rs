@@ -1,2 +1,7 @@
+#[cfg(gamepad_platform)]
+pub mod gamepad;
 pub mod keymap;
+pub mod seat_focus;
+#[cfg(feature = "xdg-portal")]
+pub mod xdg_portal;
 pub mod xkb_state;
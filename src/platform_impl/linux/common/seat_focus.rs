@@ -0,0 +1,59 @@
+//! Focus of a window shared by several seats.
+
+/// The seats focusing a window, which has focus as long as any of them focuses it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SeatFocus {
+    seats: Vec<u32>,
+}
+
+impl SeatFocus {
+    #[inline]
+    pub fn has_focus(&self) -> bool {
+        !self.seats.is_empty()
+    }
+
+    /// Mark that the seat focused the window, returning whether the window has focus changed.
+    pub fn set(&mut self, seat: u32, focused: bool) -> bool {
+        let had_focus = self.has_focus();
+        self.seats.retain(|&focused_seat| focused_seat != seat);
+        if focused {
+            self.seats.push(seat);
+        }
+        had_focus != self.has_focus()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_seat() {
+        let mut focus = SeatFocus::default();
+        assert!(focus.set(1, true));
+        assert!(focus.has_focus());
+        assert!(!focus.set(1, true));
+        assert!(focus.set(1, false));
+        assert!(!focus.has_focus());
+        assert!(!focus.set(1, false));
+    }
+
+    #[test]
+    fn focused_while_any_seat_focuses() {
+        let mut focus = SeatFocus::default();
+        assert!(focus.set(1, true));
+        assert!(!focus.set(2, true));
+        assert!(!focus.set(1, false));
+        assert!(focus.has_focus());
+        assert!(focus.set(2, false));
+        assert!(!focus.has_focus());
+    }
+
+    #[test]
+    fn unknown_seat_leaving() {
+        let mut focus = SeatFocus::default();
+        assert!(focus.set(1, true));
+        assert!(!focus.set(2, false));
+        assert!(focus.has_focus());
+    }
+}
@@ -0,0 +1,233 @@
+//! Access to the desktop settings through the XDG desktop portal.
+//!
+//! The portal lives on the session bus, which isn't integrated into the event loop, so its
+//! signals are received on a separate thread.
+
+use std::thread;
+
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::{OwnedValue, Value};
+
+use crate::window::Theme;
+
+const DESTINATION: &str = "org.freedesktop.portal.Desktop";
+const PATH: &str = "/org/freedesktop/portal/desktop";
+const SETTINGS_INTERFACE: &str = "org.freedesktop.portal.Settings";
+
+const APPEARANCE_NAMESPACE: &str = "org.freedesktop.appearance";
+const COLOR_SCHEME_KEY: &str = "color-scheme";
+
+/// Read the color scheme preferred by the desktop, and watch it for changes.
+///
+/// `on_change` is called with the current theme, then with the new one whenever the color scheme
+/// changes, until it returns `false`. This blocks for as long as the color scheme is watched.
+///
+/// The theme is `None` when the desktop doesn't have any preference.
+pub fn watch_color_scheme<F>(connection: &Connection, mut on_change: F) -> zbus::Result<()>
+where
+    F: FnMut(Option<Theme>) -> bool,
+{
+    let proxy = Proxy::new(connection, DESTINATION, PATH, SETTINGS_INTERFACE)?;
+
+    // Subscribe before reading the current value to not miss any change.
+    let changes = proxy.receive_signal_with_args(
+        "SettingChanged",
+        &[(0, APPEARANCE_NAMESPACE), (1, COLOR_SCHEME_KEY)],
+    )?;
+    let color_scheme: OwnedValue = proxy.call("Read", &(APPEARANCE_NAMESPACE, COLOR_SCHEME_KEY))?;
+    if !on_change(theme_from_color_scheme(&color_scheme)) {
+        return Ok(());
+    }
+
+    for message in changes {
+        let theme = match message.body::<(String, String, OwnedValue)>() {
+            Ok((_, _, color_scheme)) => theme_from_color_scheme(&color_scheme),
+            Err(err) => {
+                warn!("Invalid `{COLOR_SCHEME_KEY}` setting from the portal: {err}");
+                continue;
+            }
+        };
+        if !on_change(theme) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Watch the color scheme on the session bus from another thread, see [`watch_color_scheme`].
+///
+/// Even connecting to the bus is done from that thread, since a slow portal would otherwise stall
+/// the creation of the event loop. The failures are only logged, since the portal is often
+/// missing.
+pub fn watch_session_color_scheme<F>(on_change: F)
+where
+    F: FnMut(Option<Theme>) -> bool + Send + 'static,
+{
+    let spawned = thread::Builder::new()
+        .name("winit-xdg-portal".into())
+        .spawn(move || {
+            let result = Connection::session()
+                .and_then(|connection| watch_color_scheme(&connection, on_change));
+            if let Err(err) = result {
+                info!("Failed to read the color scheme from the XDG desktop portal: {err}");
+            }
+        });
+    if let Err(err) = spawned {
+        warn!("Failed to spawn the XDG desktop portal thread: {err}");
+    }
+}
+
+/// Convert the value of the `color-scheme` setting into a theme.
+fn theme_from_color_scheme(value: &Value<'_>) -> Option<Theme> {
+    match value {
+        // Older versions of the portal wrap the value into another variant.
+        Value::Value(value) => theme_from_color_scheme(value),
+        Value::U32(1) => Some(Theme::Dark),
+        Value::U32(2) => Some(Theme::Light),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use std::sync::mpsc;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use zbus::blocking::ConnectionBuilder;
+    use zbus::dbus_interface;
+
+    use super::*;
+
+    /// A private session bus, which is stopped on drop.
+    struct SessionBus {
+        daemon: Child,
+        address: String,
+    }
+
+    impl SessionBus {
+        fn spawn() -> Option<Self> {
+            let mut daemon = Command::new("dbus-daemon")
+                .args(["--session", "--nofork", "--print-address"])
+                .arg(format!(
+                    "--address=unix:tmpdir={}",
+                    std::env::temp_dir().display()
+                ))
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .ok()?;
+
+            let mut address = String::new();
+            BufReader::new(daemon.stdout.as_mut().unwrap())
+                .read_line(&mut address)
+                .ok()?;
+            let address = address.trim().to_owned();
+            Some(Self { daemon, address })
+        }
+
+        fn connect(&self) -> Connection {
+            ConnectionBuilder::address(self.address.as_str())
+                .unwrap()
+                .build()
+                .unwrap()
+        }
+    }
+
+    impl Drop for SessionBus {
+        fn drop(&mut self) {
+            let _ = self.daemon.kill();
+            let _ = self.daemon.wait();
+        }
+    }
+
+    /// The settings interface of the portal, as implemented by older portals.
+    struct FakeSettings {
+        color_scheme: Arc<Mutex<u32>>,
+    }
+
+    #[dbus_interface(name = "org.freedesktop.portal.Settings")]
+    impl FakeSettings {
+        fn read(&self, namespace: &str, key: &str) -> zbus::fdo::Result<OwnedValue> {
+            if (namespace, key) != (APPEARANCE_NAMESPACE, COLOR_SCHEME_KEY) {
+                return Err(zbus::fdo::Error::Failed("unknown setting".into()));
+            }
+            let color_scheme = Value::U32(*self.color_scheme.lock().unwrap());
+            Ok(Value::Value(Box::new(color_scheme)).into())
+        }
+    }
+
+    fn serve_portal(bus: &SessionBus, color_scheme: u32) -> (Connection, Arc<Mutex<u32>>) {
+        let color_scheme = Arc::new(Mutex::new(color_scheme));
+        let connection = bus.connect();
+        connection
+            .object_server()
+            .at(
+                PATH,
+                FakeSettings {
+                    color_scheme: color_scheme.clone(),
+                },
+            )
+            .unwrap();
+        connection.request_name(DESTINATION).unwrap();
+        (connection, color_scheme)
+    }
+
+    fn change_setting(portal: &Connection, namespace: &str, key: &str, value: u32) {
+        portal
+            .emit_signal(
+                None::<()>,
+                PATH,
+                SETTINGS_INTERFACE,
+                "SettingChanged",
+                &(namespace, key, Value::U32(value)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn follows_color_scheme() {
+        let bus = match SessionBus::spawn() {
+            Some(bus) => bus,
+            None => {
+                eprintln!("`dbus-daemon` isn't available, skipping the test");
+                return;
+            }
+        };
+        let (portal, color_scheme) = serve_portal(&bus, 1);
+
+        let (sender, receiver) = mpsc::channel();
+        let connection = bus.connect();
+        thread::spawn(move || {
+            watch_color_scheme(&connection, move |theme| sender.send(theme).is_ok())
+        });
+        let timeout = Duration::from_secs(5);
+        assert_eq!(receiver.recv_timeout(timeout), Ok(Some(Theme::Dark)));
+
+        // Unrelated settings are ignored.
+        change_setting(&portal, "org.gnome.desktop.interface", COLOR_SCHEME_KEY, 2);
+        change_setting(&portal, APPEARANCE_NAMESPACE, "accent-color", 2);
+
+        *color_scheme.lock().unwrap() = 2;
+        change_setting(&portal, APPEARANCE_NAMESPACE, COLOR_SCHEME_KEY, 2);
+        assert_eq!(receiver.recv_timeout(timeout), Ok(Some(Theme::Light)));
+
+        change_setting(&portal, APPEARANCE_NAMESPACE, COLOR_SCHEME_KEY, 0);
+        assert_eq!(receiver.recv_timeout(timeout), Ok(None));
+    }
+
+    #[test]
+    fn fails_without_portal() {
+        let bus = match SessionBus::spawn() {
+            Some(bus) => bus,
+            None => {
+                eprintln!("`dbus-daemon` isn't available, skipping the test");
+                return;
+            }
+        };
+        assert!(watch_color_scheme(&bus.connect(), |_| true).is_err());
+    }
+}
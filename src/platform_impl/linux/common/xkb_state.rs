@@ -1,6 +1,6 @@
 use std::convert::TryInto;
 use std::env;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::os::unix::ffi::OsStringExt;
 use std::ptr;
@@ -22,7 +22,7 @@ use crate::platform_impl::common::keymap;
 use crate::platform_impl::KeyEventExtra;
 use crate::{
     event::ElementState,
-    keyboard::{Key, KeyCode, KeyLocation},
+    keyboard::{Key, KeyCode, KeyLocation, LayoutInfo},
 };
 
 // TODO: Wire this up without using a static `AtomicBool`.
@@ -49,6 +49,7 @@ pub struct KbdState {
     xkb_compose_state: *mut ffi::xkb_compose_state,
     xkb_compose_state_2: *mut ffi::xkb_compose_state,
     mods_state: ModifiersState,
+    layouts: Vec<LayoutInfo>,
     #[cfg(feature = "x11")]
     pub core_keyboard_id: i32,
     scratch_buffer: Vec<u8>,
@@ -206,6 +207,7 @@ impl KbdState {
             xkb_compose_state: ptr::null_mut(),
             xkb_compose_state_2: ptr::null_mut(),
             mods_state: ModifiersState::new(),
+            layouts: Vec::new(),
             #[cfg(feature = "x11")]
             core_keyboard_id: 0,
             scratch_buffer: Vec::new(),
@@ -293,6 +295,7 @@ impl KbdState {
         self.xkb_keymap = keymap;
         self.xkb_state = state;
         self.mods_state.update_with(state);
+        self.layouts = keymap_layouts(keymap);
     }
 
     unsafe fn de_init(&mut self) {
@@ -300,6 +303,7 @@ impl KbdState {
         self.xkb_state = ptr::null_mut();
         (XKBH.xkb_keymap_unref)(self.xkb_keymap);
         self.xkb_keymap = ptr::null_mut();
+        self.layouts.clear();
     }
 
     #[cfg(feature = "x11")]
@@ -368,6 +372,24 @@ impl KbdState {
         self.mods_state
     }
 
+    #[inline]
+    pub fn layouts(&self) -> &[LayoutInfo] {
+        &self.layouts
+    }
+
+    pub fn active_layout(&self) -> Option<usize> {
+        if !self.ready() {
+            return None;
+        }
+        let layout = unsafe {
+            (XKBH.xkb_state_serialize_layout)(
+                self.xkb_state,
+                xkb_state_component::XKB_STATE_LAYOUT_EFFECTIVE,
+            )
+        };
+        Some(layout as usize)
+    }
+
     pub fn process_key_event(
         &mut self,
         keycode: u32,
@@ -648,6 +670,90 @@ impl From<ModifiersState> for crate::keyboard::ModifiersState {
     }
 }
 
+/// Lists the layouts of the keymap.
+///
+/// The bindings of `xkbcommon-dl` lack `xkb_keymap_layout_get_name`, so the names of the layouts
+/// are read from the text form of the keymap, where the symbols section is also named after the
+/// XKB rules that built it, e.g. `pc+us+ru:2+inet(evdev)`, which gives the short names.
+unsafe fn keymap_layouts(keymap: *mut ffi::xkb_keymap) -> Vec<LayoutInfo> {
+    let count = (XKBH.xkb_keymap_num_layouts)(keymap) as usize;
+    let mut layouts = vec![LayoutInfo::default(); count];
+
+    let text =
+        (XKBH.xkb_keymap_get_as_string)(keymap, ffi::xkb_keymap_format::XKB_KEYMAP_FORMAT_TEXT_V1);
+    if !text.is_null() {
+        parse_layouts(&CStr::from_ptr(text).to_string_lossy(), &mut layouts);
+        libc::free(text as *mut _);
+    }
+
+    layouts
+}
+
+fn parse_layouts(keymap: &str, layouts: &mut [LayoutInfo]) {
+    let symbols = match keymap.find("xkb_symbols") {
+        Some(start) => &keymap[start..],
+        None => return,
+    };
+
+    // The first component is the model, then the layout of the first group comes without index,
+    // and the ones of the next groups with it, e.g. `ru:2`.
+    let section_name = symbols.split('"').nth(1).unwrap_or_default();
+    for (i, component) in section_name.split('+').enumerate() {
+        let (symbols, group) = match component.split_once(':') {
+            Some((symbols, group)) => match group.parse::<usize>() {
+                Ok(group) => (symbols, group),
+                Err(_) => continue,
+            },
+            None if i == 1 => (component, 1),
+            None => continue,
+        };
+
+        // Drop the variant, e.g. `us(dvorak)`.
+        let short_name = symbols.split('(').next().unwrap_or_default();
+        if let Some(layout) = group
+            .checked_sub(1)
+            .and_then(|group| layouts.get_mut(group))
+        {
+            layout.short_name = Some(short_name.to_owned());
+        }
+    }
+
+    for line in symbols.lines().skip(1) {
+        let line = line.trim();
+        if line.starts_with('}') {
+            break;
+        }
+
+        // e.g. `name[Group1]="English (US)";`
+        let (group, name) = match line.strip_prefix("name[").and_then(|l| l.split_once(']')) {
+            Some(entry) => entry,
+            None => continue,
+        };
+        let group = group.trim_start_matches(|c: char| !c.is_ascii_digit());
+        let name = name.trim_start_matches(|c: char| c == '=' || c.is_whitespace());
+        let name = name.trim_end_matches(';').trim_matches('"');
+        let group = match group.parse::<usize>() {
+            Ok(group) => group,
+            Err(_) => continue,
+        };
+        if let Some(layout) = group
+            .checked_sub(1)
+            .and_then(|group| layouts.get_mut(group))
+        {
+            layout.name = name.to_owned();
+        }
+    }
+
+    // Fallback to the short name for the unnamed layouts.
+    for layout in layouts {
+        if layout.name.is_empty() {
+            if let Some(short_name) = layout.short_name.as_ref() {
+                layout.name = short_name.clone();
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     /// libxkbcommon is not available
@@ -674,3 +780,32 @@ fn byte_slice_to_smol_str(bytes: &[u8]) -> Option<SmolStr> {
         })
         .ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layouts_from_keymap() {
+        let keymap = r#"xkb_keymap {
+xkb_keycodes "evdev+aliases(qwerty)" {
+	minimum = 8;
+};
+xkb_symbols "pc+us(dvorak)+ru:2+inet(evdev)+group(alt_shift_toggle)" {
+	name[Group1]="English (Dvorak)";
+	name[Group2]="Russian";
+
+	key <AE01> { [ 1, exclam ], [ 1, exclam ] };
+};
+};"#;
+
+        let mut layouts = vec![LayoutInfo::default(); 3];
+        parse_layouts(keymap, &mut layouts);
+
+        assert_eq!(layouts[0].name(), "English (Dvorak)");
+        assert_eq!(layouts[0].short_name(), Some("us"));
+        assert_eq!(layouts[1].name(), "Russian");
+        assert_eq!(layouts[1].short_name(), Some("ru"));
+        assert_eq!(layouts[2], LayoutInfo::default());
+    }
+}
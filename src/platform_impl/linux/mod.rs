@@ -20,26 +20,31 @@ use once_cell::sync::Lazy;
 use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
 use smol_str::SmolStr;
 
+#[cfg(wayland_platform)]
+use self::wayland::DecorationFrameFactory;
 #[cfg(x11_platform)]
 pub use self::x11::XNotSupported;
 #[cfg(x11_platform)]
 use self::x11::{ffi::XVisualInfo, util::WindowType as XWindowType, X11Error, XConnection, XError};
+#[cfg(wayland_platform)]
+use crate::platform::wayland::FrameTheme;
 #[cfg(x11_platform)]
 use crate::platform::x11::XlibErrorHook;
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
-    event::{Event, KeyEvent},
+    event::{Event, KeyEvent, SeatId},
     event_loop::{
         AsyncRequestSerial, ControlFlow, DeviceEvents, EventLoopClosed,
         EventLoopWindowTarget as RootELW,
     },
     icon::Icon,
     keyboard::{Key, KeyCode},
+    monitor::{GammaRamp, MonitorTransform, SubpixelOrder},
     platform::{modifier_supplement::KeyEventExtModifierSupplement, scancode::KeyCodeExtScancode},
     window::{
         ActivationToken, CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme,
-        UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
+        TiledEdges, UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
     },
 };
 
@@ -95,12 +100,15 @@ pub struct PlatformSpecificWindowBuilderAttributes {
     pub visual_infos: Option<XVisualInfo>,
     #[cfg(x11_platform)]
     pub screen_id: Option<i32>,
-    #[cfg(x11_platform)]
     pub base_size: Option<Size>,
     #[cfg(x11_platform)]
     pub override_redirect: bool,
     #[cfg(x11_platform)]
     pub x11_window_types: Vec<XWindowType>,
+    #[cfg(wayland_platform)]
+    pub decoration_frame: Option<DecorationFrameFactory>,
+    #[cfg(wayland_platform)]
+    pub frame_theme: Option<FrameTheme>,
 }
 
 impl Default for PlatformSpecificWindowBuilderAttributes {
@@ -112,12 +120,15 @@ impl Default for PlatformSpecificWindowBuilderAttributes {
             visual_infos: None,
             #[cfg(x11_platform)]
             screen_id: None,
-            #[cfg(x11_platform)]
             base_size: None,
             #[cfg(x11_platform)]
             override_redirect: false,
             #[cfg(x11_platform)]
             x11_window_types: vec![XWindowType::Normal],
+            #[cfg(wayland_platform)]
+            decoration_frame: None,
+            #[cfg(wayland_platform)]
+            frame_theme: None,
         }
     }
 }
@@ -134,6 +145,8 @@ pub enum OsError {
     XMisc(&'static str),
     #[cfg(wayland_platform)]
     WaylandMisc(&'static str),
+    #[cfg(gamepad_platform)]
+    IoError(std::sync::Arc<std::io::Error>),
 }
 
 impl fmt::Display for OsError {
@@ -145,6 +158,8 @@ impl fmt::Display for OsError {
             OsError::XMisc(e) => _f.pad(e),
             #[cfg(wayland_platform)]
             OsError::WaylandMisc(e) => _f.pad(e),
+            #[cfg(gamepad_platform)]
+            OsError::IoError(ref e) => fmt::Display::fmt(e, _f),
         }
     }
 }
@@ -183,6 +198,8 @@ pub enum DeviceId {
     X(x11::DeviceId),
     #[cfg(wayland_platform)]
     Wayland(wayland::DeviceId),
+    #[cfg(gamepad_platform)]
+    Gamepad(common::gamepad::GamepadId),
 }
 
 impl DeviceId {
@@ -192,6 +209,17 @@ impl DeviceId {
         #[cfg(all(not(wayland_platform), x11_platform))]
         return DeviceId::X(x11::DeviceId::dummy());
     }
+
+    pub fn seat_id(&self) -> Option<SeatId> {
+        match self {
+            #[cfg(x11_platform)]
+            DeviceId::X(device_id) => device_id.seat_id(),
+            #[cfg(wayland_platform)]
+            DeviceId::Wayland(device_id) => Some(device_id.seat_id()),
+            #[cfg(gamepad_platform)]
+            DeviceId::Gamepad(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -241,6 +269,21 @@ impl MonitorHandle {
         x11_or_wayland!(match self; MonitorHandle(m) => m.native_identifier())
     }
 
+    #[inline]
+    pub fn gamma_ramp(&self) -> Result<GammaRamp, ExternalError> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.gamma_ramp())
+    }
+
+    #[inline]
+    pub fn set_gamma_ramp(
+        &self,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), ExternalError> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.set_gamma_ramp(red, green, blue))
+    }
+
     #[inline]
     pub fn size(&self) -> PhysicalSize<u32> {
         x11_or_wayland!(match self; MonitorHandle(m) => m.size())
@@ -251,6 +294,11 @@ impl MonitorHandle {
         x11_or_wayland!(match self; MonitorHandle(m) => m.position())
     }
 
+    #[inline]
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.work_area())
+    }
+
     #[inline]
     pub fn refresh_rate_millihertz(&self) -> Option<u32> {
         x11_or_wayland!(match self; MonitorHandle(m) => m.refresh_rate_millihertz())
@@ -261,6 +309,36 @@ impl MonitorHandle {
         x11_or_wayland!(match self; MonitorHandle(m) => m.scale_factor() as _)
     }
 
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.physical_size_mm())
+    }
+
+    #[inline]
+    pub fn manufacturer(&self) -> Option<String> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.manufacturer())
+    }
+
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.model())
+    }
+
+    #[inline]
+    pub fn serial_number(&self) -> Option<String> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.serial_number())
+    }
+
+    #[inline]
+    pub fn transform(&self) -> MonitorTransform {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.transform())
+    }
+
+    #[inline]
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.subpixel_order())
+    }
+
     #[inline]
     pub fn video_modes(&self) -> Box<dyn Iterator<Item = VideoMode>> {
         x11_or_wayland!(match self; MonitorHandle(m) => Box::new(m.video_modes()))
@@ -366,6 +444,16 @@ impl Window {
         x11_or_wayland!(match self; Window(w) => w.inner_size())
     }
 
+    #[inline]
+    pub fn restored_outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
+        x11_or_wayland!(match self; Window(w) => w.restored_outer_position())
+    }
+
+    #[inline]
+    pub fn restored_inner_size(&self) -> PhysicalSize<u32> {
+        x11_or_wayland!(match self; Window(w) => w.restored_inner_size())
+    }
+
     #[inline]
     pub fn outer_size(&self) -> PhysicalSize<u32> {
         x11_or_wayland!(match self; Window(w) => w.outer_size())
@@ -431,6 +519,24 @@ impl Window {
         x11_or_wayland!(match self; Window(window) => window.set_cursor_grab(mode))
     }
 
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        seat_id: SeatId,
+        cursor: CursorIcon,
+    ) -> Result<(), ExternalError> {
+        x11_or_wayland!(match self; Window(w) => w.set_seat_cursor_icon(seat_id, cursor))
+    }
+
+    #[inline]
+    pub fn set_seat_cursor_grab(
+        &self,
+        seat_id: SeatId,
+        mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        x11_or_wayland!(match self; Window(window) => window.set_seat_cursor_grab(seat_id, mode))
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, visible: bool) {
         x11_or_wayland!(match self; Window(window) => window.set_cursor_visible(visible))
@@ -446,6 +552,11 @@ impl Window {
         x11_or_wayland!(match self; Window(window) => window.drag_resize_window(direction))
     }
 
+    #[inline]
+    pub fn show_window_menu(&self, position: Position) {
+        x11_or_wayland!(match self; Window(w) => w.show_window_menu(position))
+    }
+
     #[inline]
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
         x11_or_wayland!(match self; Window(w) => w.set_cursor_hittest(hittest))
@@ -471,6 +582,11 @@ impl Window {
         x11_or_wayland!(match self; Window(w) => w.is_maximized())
     }
 
+    #[inline]
+    pub fn tiled_edges(&self) -> TiledEdges {
+        x11_or_wayland!(match self; Window(w) => w.tiled_edges())
+    }
+
     #[inline]
     pub fn set_minimized(&self, minimized: bool) {
         x11_or_wayland!(match self; Window(w) => w.set_minimized(minimized))
@@ -904,13 +1020,80 @@ impl<T> EventLoopWindowTarget<T> {
     #[inline]
     pub fn listen_device_events(&self, _allowed: DeviceEvents) {
         match *self {
-            #[cfg(wayland_platform)]
+            #[cfg(all(wayland_platform, gamepad_platform))]
+            EventLoopWindowTarget::Wayland(ref evlp) => {
+                evlp.state.borrow_mut().device_events = _allowed
+            }
+            #[cfg(all(wayland_platform, not(gamepad_platform)))]
             EventLoopWindowTarget::Wayland(_) => (),
             #[cfg(x11_platform)]
             EventLoopWindowTarget::X(ref evlp) => evlp.set_listen_device_events(_allowed),
         }
     }
 
+    #[inline]
+    pub fn devices(&self) -> Vec<crate::event::DeviceInfo> {
+        #[allow(unused_mut)]
+        let mut devices = x11_or_wayland!(match self; Self(evlp) => evlp.devices());
+        #[cfg(gamepad_platform)]
+        if let Some(gamepads) = x11_or_wayland!(match self; Self(evlp) => evlp.gamepads()).as_ref()
+        {
+            devices.extend(gamepads.devices());
+        }
+        devices
+    }
+
+    pub fn device_info(&self, device_id: DeviceId) -> Option<crate::event::DeviceInfo> {
+        match (device_id, self) {
+            #[cfg(x11_platform)]
+            (DeviceId::X(device_id), EventLoopWindowTarget::X(evlp)) => {
+                evlp.device_info(device_id.id())
+            }
+            #[cfg(wayland_platform)]
+            (DeviceId::Wayland(_), EventLoopWindowTarget::Wayland(evlp)) => {
+                let id = crate::event::DeviceId(device_id);
+                evlp.devices().into_iter().find(|device| device.id() == id)
+            }
+            #[cfg(gamepad_platform)]
+            (DeviceId::Gamepad(id), _) => {
+                x11_or_wayland!(match self; Self(evlp) => evlp.gamepads())
+                    .as_ref()?
+                    .device_info(id)
+            }
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn keyboard_layouts(&self) -> Vec<crate::keyboard::LayoutInfo> {
+        x11_or_wayland!(match self; Self(evlp) => evlp.keyboard_layouts())
+    }
+
+    #[inline]
+    pub fn active_keyboard_layout(&self) -> Option<usize> {
+        x11_or_wayland!(match self; Self(evlp) => evlp.active_keyboard_layout())
+    }
+
+    #[cfg(gamepad_platform)]
+    pub fn set_gamepad_rumble(
+        &self,
+        device_id: DeviceId,
+        strong: f32,
+        weak: f32,
+        duration: std::time::Duration,
+    ) -> Result<(), ExternalError> {
+        let mut gamepads = x11_or_wayland!(match self; Self(evlp) => evlp.gamepads());
+        match (device_id, gamepads.as_mut()) {
+            (DeviceId::Gamepad(id), Some(gamepads)) => gamepads
+                .set_rumble(id, strong, weak, duration)
+                .map_err(|err| {
+                    ExternalError::Os(os_error!(OsError::IoError(std::sync::Arc::new(err))))
+                }),
+            _ => Err(ExternalError::NotSupported(NotSupportedError::new())),
+        }
+    }
+
     pub fn raw_display_handle(&self) -> raw_window_handle::RawDisplayHandle {
         x11_or_wayland!(match self; Self(evlp) => evlp.raw_display_handle())
     }
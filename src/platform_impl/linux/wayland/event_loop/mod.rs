@@ -1,10 +1,14 @@
 //! The event-loop routines.
 
 use std::cell::RefCell;
+#[cfg(gamepad_platform)]
+use std::cell::RefMut;
 use std::error::Error;
 use std::io::Result as IOResult;
 use std::marker::PhantomData;
 use std::mem;
+#[cfg(gamepad_platform)]
+use std::os::unix::io::AsRawFd;
 use std::process;
 use std::rc::Rc;
 use std::sync::atomic::Ordering;
@@ -19,6 +23,10 @@ use sctk::reexports::client::{Connection, Proxy, QueueHandle, WaylandSource};
 use crate::dpi::{LogicalSize, PhysicalSize};
 use crate::event::{Event, StartCause, WindowEvent};
 use crate::event_loop::{ControlFlow, EventLoopWindowTarget as RootEventLoopWindowTarget};
+#[cfg(gamepad_platform)]
+use crate::platform_impl::platform::common::gamepad::{self, Gamepads};
+#[cfg(feature = "xdg-portal")]
+use crate::platform_impl::platform::common::xdg_portal;
 use crate::platform_impl::platform::sticky_exit_callback;
 use crate::platform_impl::EventLoopWindowTarget as PlatformEventLoopWindowTarget;
 
@@ -29,6 +37,7 @@ pub use proxy::EventLoopProxy;
 use sink::EventSink;
 
 use super::state::{WindowCompositorUpdate, WinitState};
+use super::types::wlr_gamma_control;
 use super::{DeviceId, WindowId};
 
 type WaylandDispatcher = calloop::Dispatcher<'static, WaylandSource<WinitState>, WinitState>;
@@ -73,6 +82,9 @@ impl<T: 'static> EventLoop<T> {
         // races with the server.
         event_queue.roundtrip(&mut winit_state)?;
 
+        // Outputs advertised during the initial roundtrip aren't hotplugged.
+        winit_state.events_sink.clear_monitor_events();
+
         // Register Wayland source.
         let wayland_source = WaylandSource::new(event_queue)?;
         let wayland_dispatcher =
@@ -84,6 +96,52 @@ impl<T: 'static> EventLoop<T> {
             .handle()
             .register_dispatcher(wayland_dispatcher.clone())?;
 
+        // Follow the color scheme of the desktop.
+        #[cfg(feature = "xdg-portal")]
+        {
+            let (theme_sender, theme_channel) = calloop::channel::channel();
+            event_loop
+                .handle()
+                .insert_source(theme_channel, |event, _, winit_state| {
+                    if let calloop::channel::Event::Msg(theme) = event {
+                        winit_state.system_theme_changed(theme);
+                    }
+                })?;
+            xdg_portal::watch_session_color_scheme(move |theme| theme_sender.send(theme).is_ok());
+        }
+
+        // Read the gamepads.
+        #[cfg(gamepad_platform)]
+        if let Some(gamepads) = gamepad::watch_gamepads() {
+            let source = calloop::generic::Generic::new(
+                gamepads.as_raw_fd(),
+                calloop::Interest::READ,
+                calloop::Mode::Level,
+            );
+            event_loop
+                .handle()
+                .insert_source(source, |_, _, winit_state| {
+                    let focused = winit_state
+                        .windows
+                        .get_mut()
+                        .values()
+                        .any(|window| window.lock().unwrap().has_focus());
+                    let filter = winit_state.device_events;
+                    if let Some(gamepads) = winit_state.gamepads.as_mut() {
+                        let events_sink = &mut winit_state.events_sink;
+                        gamepads.dispatch(|device_id, event| {
+                            if gamepad::is_listened(&event, filter, focused) {
+                                events_sink
+                                    .window_events
+                                    .push(Event::DeviceEvent { device_id, event });
+                            }
+                        });
+                    }
+                    Ok(calloop::PostAction::Continue)
+                })?;
+            winit_state.gamepads = Some(gamepads);
+        }
+
         // Setup the user proxy.
         let pending_user_events = Rc::new(RefCell::new(Vec::new()));
         let pending_user_events_clone = pending_user_events.clone();
@@ -333,7 +391,10 @@ impl<T: 'static> EventLoop<T> {
                         let window = windows.get(&window_id).unwrap().lock().unwrap();
 
                         let scale_factor = window.scale_factor();
-                        let physical_size = logical_to_physical_rounded(size, scale_factor);
+                        let physical_size = match window.emulated_mode() {
+                            Some(mode) => mode.size(),
+                            None => logical_to_physical_rounded(size, scale_factor),
+                        };
 
                         // TODO could probably bring back size reporting optimization.
 
@@ -451,6 +512,10 @@ impl<T: 'static> EventLoop<T> {
         };
 
         callback(Event::LoopDestroyed, &self.window_target, &mut control_flow);
+
+        // Don't leave the gamma ramps changed by the application behind.
+        wlr_gamma_control::reset();
+
         exit_code
     }
 
@@ -509,6 +574,11 @@ pub struct EventLoopWindowTarget<T> {
 }
 
 impl<T> EventLoopWindowTarget<T> {
+    #[cfg(gamepad_platform)]
+    pub fn gamepads(&self) -> RefMut<'_, Option<Gamepads>> {
+        RefMut::map(self.state.borrow_mut(), |state| &mut state.gamepads)
+    }
+
     pub fn raw_display_handle(&self) -> RawDisplayHandle {
         let mut display_handle = WaylandDisplayHandle::empty();
         display_handle.display = self.connection.display().id().as_ptr() as *mut _;
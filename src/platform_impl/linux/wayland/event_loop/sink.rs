@@ -3,9 +3,13 @@
 use std::vec::Drain;
 
 use crate::event::{DeviceEvent, DeviceId as RootDeviceId, Event, WindowEvent};
-use crate::platform_impl::platform::DeviceId as PlatformDeviceId;
+use crate::monitor::MonitorHandle as RootMonitorHandle;
+use crate::platform_impl::platform::{
+    DeviceId as PlatformDeviceId, MonitorHandle as PlatformMonitorHandle,
+};
 use crate::window::WindowId as RootWindowId;
 
+use super::super::MonitorHandle;
 use super::{DeviceId, WindowId};
 
 /// An event loop's sink to deliver events from the Wayland event callbacks
@@ -38,6 +42,29 @@ impl EventSink {
         });
     }
 
+    /// Add new monitor event to a queue.
+    #[inline]
+    pub fn push_monitor_event(
+        &mut self,
+        event: fn(RootMonitorHandle) -> Event<'static, ()>,
+        monitor: MonitorHandle,
+    ) {
+        self.window_events.push(event(RootMonitorHandle {
+            inner: PlatformMonitorHandle::Wayland(monitor),
+        }));
+    }
+
+    /// Drop the monitor events, keeping the other ones.
+    #[inline]
+    pub fn clear_monitor_events(&mut self) {
+        self.window_events.retain(|event| {
+            !matches!(
+                event,
+                Event::MonitorAdded(_) | Event::MonitorRemoved(_) | Event::MonitorChanged(_)
+            )
+        });
+    }
+
     #[inline]
     pub fn append(&mut self, other: &mut Self) {
         self.window_events.append(&mut other.window_events);
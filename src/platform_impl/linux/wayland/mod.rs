@@ -2,13 +2,16 @@
 
 //! Winit's Wayland backend.
 
+use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::protocol::wl_surface::WlSurface;
 use sctk::reexports::client::Proxy;
 
+use crate::event::SeatId;
+
 pub use crate::platform_impl::platform::WindowId;
 pub use event_loop::{EventLoop, EventLoopProxy, EventLoopWindowTarget};
 pub use output::{MonitorHandle, VideoMode};
-pub use window::Window;
+pub use window::{DecorationFrameFactory, Window};
 
 mod event_loop;
 mod output;
@@ -17,14 +20,36 @@ mod state;
 mod types;
 mod window;
 
-/// Dummy device id, since Wayland doesn't have device events.
+/// The id of an input device, which is the protocol id of its pointer, keyboard, touch or tablet
+/// object, along with the protocol id of its seat.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DeviceId;
+pub struct DeviceId {
+    id: u32,
+    seat: u32,
+}
 
 impl DeviceId {
     pub const unsafe fn dummy() -> Self {
-        DeviceId
+        DeviceId { id: 0, seat: 0 }
     }
+
+    /// Get the device id of the input object of the seat.
+    fn from_proxy<I: Proxy>(proxy: &I, seat: &WlSeat) -> Self {
+        DeviceId {
+            id: proxy.id().protocol_id(),
+            seat: seat.id().protocol_id(),
+        }
+    }
+
+    pub fn seat_id(&self) -> SeatId {
+        SeatId(self.seat)
+    }
+}
+
+/// Get the SeatId out of the seat.
+#[inline]
+fn make_seat_id(seat: &WlSeat) -> SeatId {
+    SeatId(seat.id().protocol_id())
 }
 
 /// Get the WindowId out of the surface.
@@ -1,14 +1,17 @@
-use sctk::reexports::client::protocol::wl_output::WlOutput;
+use sctk::reexports::client::protocol::wl_output::{Subpixel, Transform, WlOutput};
 use sctk::reexports::client::Proxy;
 
 use sctk::output::OutputData;
 
 use crate::dpi::{PhysicalPosition, PhysicalSize};
+use crate::error::ExternalError;
+use crate::monitor::{GammaRamp, MonitorTransform, SubpixelOrder};
 use crate::platform_impl::platform::{
     MonitorHandle as PlatformMonitorHandle, VideoMode as PlatformVideoMode,
 };
 
 use super::event_loop::EventLoopWindowTarget;
+use super::types::wlr_gamma_control;
 
 impl<T> EventLoopWindowTarget<T> {
     #[inline]
@@ -33,12 +36,33 @@ pub struct MonitorHandle {
     pub(crate) proxy: WlOutput,
 }
 
+/// The properties of a monitor whose changes are reported with `Event::MonitorChanged`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonitorState {
+    size: PhysicalSize<u32>,
+    position: PhysicalPosition<i32>,
+    refresh_rate_millihertz: Option<u32>,
+    scale_factor: i32,
+    transform: MonitorTransform,
+}
+
 impl MonitorHandle {
     #[inline]
     pub(crate) fn new(proxy: WlOutput) -> Self {
         Self { proxy }
     }
 
+    /// Snapshot the current properties of the monitor.
+    pub(crate) fn state(&self) -> MonitorState {
+        MonitorState {
+            size: self.size(),
+            position: self.position(),
+            refresh_rate_millihertz: self.refresh_rate_millihertz(),
+            scale_factor: self.scale_factor(),
+            transform: self.transform(),
+        }
+    }
+
     #[inline]
     pub fn name(&self) -> Option<String> {
         let output_data = self.proxy.data::<OutputData>().unwrap();
@@ -73,6 +97,27 @@ impl MonitorHandle {
         output_data.with_output_info(|info| info.location).into()
     }
 
+    #[inline]
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        // The work area isn't exposed to the clients.
+        (self.position(), self.size())
+    }
+
+    #[inline]
+    pub fn gamma_ramp(&self) -> Result<GammaRamp, ExternalError> {
+        wlr_gamma_control::gamma_ramp(&self.proxy)
+    }
+
+    #[inline]
+    pub fn set_gamma_ramp(
+        &self,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), ExternalError> {
+        wlr_gamma_control::set_gamma_ramp(&self.proxy, red, green, blue)
+    }
+
     #[inline]
     pub fn refresh_rate_millihertz(&self) -> Option<u32> {
         let output_data = self.proxy.data::<OutputData>().unwrap();
@@ -89,6 +134,63 @@ impl MonitorHandle {
         output_data.scale_factor()
     }
 
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        match output_data.with_output_info(|info| info.physical_size) {
+            (width, height) if width > 0 && height > 0 => Some((width as u32, height as u32)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn manufacturer(&self) -> Option<String> {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        let make = output_data.with_output_info(|info| info.make.clone());
+        (!make.is_empty()).then_some(make)
+    }
+
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        let model = output_data.with_output_info(|info| info.model.clone());
+        (!model.is_empty()).then_some(model)
+    }
+
+    #[inline]
+    pub fn serial_number(&self) -> Option<String> {
+        // Not exposed by `wl_output`.
+        None
+    }
+
+    #[inline]
+    pub fn transform(&self) -> MonitorTransform {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        match output_data.with_output_info(|info| info.transform) {
+            Transform::_90 => MonitorTransform::Rotate90,
+            Transform::_180 => MonitorTransform::Rotate180,
+            Transform::_270 => MonitorTransform::Rotate270,
+            Transform::Flipped => MonitorTransform::Flipped,
+            Transform::Flipped90 => MonitorTransform::Flipped90,
+            Transform::Flipped180 => MonitorTransform::Flipped180,
+            Transform::Flipped270 => MonitorTransform::Flipped270,
+            _ => MonitorTransform::Normal,
+        }
+    }
+
+    #[inline]
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        match output_data.with_output_info(|info| info.subpixel) {
+            Subpixel::None => SubpixelOrder::None,
+            Subpixel::HorizontalRgb => SubpixelOrder::HorizontalRgb,
+            Subpixel::HorizontalBgr => SubpixelOrder::HorizontalBgr,
+            Subpixel::VerticalRgb => SubpixelOrder::VerticalRgb,
+            Subpixel::VerticalBgr => SubpixelOrder::VerticalBgr,
+            _ => SubpixelOrder::Unknown,
+        }
+    }
+
     #[inline]
     pub fn video_modes(&self) -> impl Iterator<Item = PlatformVideoMode> {
         let output_data = self.proxy.data::<OutputData>().unwrap();
@@ -15,10 +15,11 @@ use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::{Connection, Dispatch, Proxy, QueueHandle, WEnum};
 
 use crate::event::{ElementState, WindowEvent};
-use crate::keyboard::ModifiersState;
+use crate::keyboard::{LayoutInfo, ModifiersState};
 
 use crate::platform_impl::common::xkb_state::KbdState;
 use crate::platform_impl::wayland::event_loop::sink::EventSink;
+use crate::platform_impl::wayland::event_loop::EventLoopWindowTarget;
 use crate::platform_impl::wayland::seat::WinitSeatState;
 use crate::platform_impl::wayland::state::WinitState;
 use crate::platform_impl::wayland::{self, DeviceId, WindowId};
@@ -43,14 +44,29 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                     WlKeymapFormat::NoKeymap => {
                         warn!("non-xkb compatible keymap")
                     }
-                    WlKeymapFormat::XkbV1 => unsafe {
-                        seat_state
-                            .keyboard_state
-                            .as_mut()
-                            .unwrap()
-                            .xkb_state
-                            .init_with_fd(fd, size as usize);
-                    },
+                    WlKeymapFormat::XkbV1 => {
+                        let xkb_state = &mut seat_state.keyboard_state.as_mut().unwrap().xkb_state;
+                        let prev_layouts = xkb_state.layouts().to_vec();
+                        unsafe { xkb_state.init_with_fd(fd, size as usize) };
+
+                        let window_id = *data.window_id.lock().unwrap();
+                        if let (Some(window_id), Some(layout)) =
+                            (window_id, xkb_state.active_layout())
+                        {
+                            if xkb_state.layouts() != prev_layouts {
+                                let device_id = crate::event::DeviceId(
+                                    crate::platform_impl::DeviceId::Wayland(DeviceId::from_proxy(
+                                        wl_keyboard,
+                                        &data.seat,
+                                    )),
+                                );
+                                state.events_sink.push_window_event(
+                                    WindowEvent::KeyboardLayoutChanged { device_id, layout },
+                                    window_id,
+                                );
+                            }
+                        }
+                    }
                     _ => unreachable!(),
                 },
                 WEnum::Unknown(value) => {
@@ -61,8 +77,8 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                 let window_id = wayland::make_wid(&surface);
 
                 // Mark the window as focused.
-                match state.windows.get_mut().get(&window_id) {
-                    Some(window) => window.lock().unwrap().set_has_focus(true),
+                let focus_changed = match state.windows.get_mut().get(&window_id) {
+                    Some(window) => window.lock().unwrap().set_seat_focus(&data.seat, true),
                     None => return,
                 };
 
@@ -70,9 +86,18 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                 seat_state.keyboard_state.as_mut().unwrap().current_repeat = None;
 
                 // The keyboard focus is considered as general focus.
-                state
-                    .events_sink
-                    .push_window_event(WindowEvent::Focused(true), window_id);
+                state.events_sink.push_window_event(
+                    WindowEvent::SeatFocused {
+                        seat_id: wayland::make_seat_id(&data.seat),
+                        focused: true,
+                    },
+                    window_id,
+                );
+                if focus_changed {
+                    state
+                        .events_sink
+                        .push_window_event(WindowEvent::Focused(true), window_id);
+                }
 
                 *data.window_id.lock().unwrap() = Some(window_id);
 
@@ -93,8 +118,8 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
 
                 // NOTE: The check whether the window exists is essential as we might get a
                 // nil surface, regardless of what protocol says.
-                match state.windows.get_mut().get(&window_id) {
-                    Some(window) => window.lock().unwrap().set_has_focus(false),
+                let focus_changed = match state.windows.get_mut().get(&window_id) {
+                    Some(window) => window.lock().unwrap().set_seat_focus(&data.seat, false),
                     None => return,
                 };
 
@@ -108,9 +133,18 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                 // anyway.
                 *data.window_id.lock().unwrap() = None;
 
-                state
-                    .events_sink
-                    .push_window_event(WindowEvent::Focused(false), window_id);
+                state.events_sink.push_window_event(
+                    WindowEvent::SeatFocused {
+                        seat_id: wayland::make_seat_id(&data.seat),
+                        focused: false,
+                    },
+                    window_id,
+                );
+                if focus_changed {
+                    state
+                        .events_sink
+                        .push_window_event(WindowEvent::Focused(false), window_id);
+                }
             }
             WlKeyboardEvent::Key {
                 key,
@@ -211,8 +245,12 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                 ..
             } => {
                 let xkb_state = &mut seat_state.keyboard_state.as_mut().unwrap().xkb_state;
+                let prev_layout = xkb_state.active_layout();
                 xkb_state.update_modifiers(mods_depressed, mods_latched, mods_locked, 0, 0, group);
                 seat_state.modifiers = xkb_state.mods_state().into();
+                let layout = xkb_state
+                    .active_layout()
+                    .filter(|layout| Some(*layout) != prev_layout);
 
                 // HACK: part of the workaround from `WlKeyboardEvent::Enter`.
                 let window_id = match *data.window_id.lock().unwrap() {
@@ -227,6 +265,17 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                     WindowEvent::ModifiersChanged(seat_state.modifiers.into()),
                     window_id,
                 );
+
+                if let Some(layout) = layout {
+                    let device_id =
+                        crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
+                            DeviceId::from_proxy(wl_keyboard, &data.seat),
+                        ));
+                    state.events_sink.push_window_event(
+                        WindowEvent::KeyboardLayoutChanged { device_id, layout },
+                        window_id,
+                    );
+                }
             }
             WlKeyboardEvent::RepeatInfo { rate, delay } => {
                 let keyboard_state = seat_state.keyboard_state.as_mut().unwrap();
@@ -248,6 +297,41 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
     }
 }
 
+impl<T> EventLoopWindowTarget<T> {
+    pub fn keyboard_layouts(&self) -> Vec<LayoutInfo> {
+        self.with_keyboard(|xkb_state| xkb_state.layouts().to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn active_keyboard_layout(&self) -> Option<usize> {
+        self.with_keyboard(KbdState::active_layout).flatten()
+    }
+
+    /// Runs `f` on the keyboard with the focus, or on the one of the first seat when no window
+    /// has the keyboard focus.
+    fn with_keyboard<R>(&self, f: impl FnOnce(&KbdState) -> R) -> Option<R> {
+        let state = self.state.borrow();
+        let keyboards = state
+            .seat_state
+            .seats()
+            .filter_map(|seat| state.seats.get(&seat.id())?.keyboard_state.as_ref())
+            .collect::<Vec<_>>();
+        let has_focus = |keyboard_state: &&KeyboardState| {
+            keyboard_state
+                .keyboard
+                .data::<KeyboardData>()
+                .map_or(false, |data| data.window_id.lock().unwrap().is_some())
+        };
+
+        let keyboard_state = keyboards
+            .iter()
+            .copied()
+            .find(has_focus)
+            .or_else(|| keyboards.first().copied())?;
+        Some(f(&keyboard_state.xkb_state))
+    }
+}
+
 /// The state of the keyboard on the current seat.
 #[derive(Debug)]
 pub struct KeyboardState {
@@ -357,7 +441,9 @@ fn key_input(
 
     let keyboard_state = seat_state.keyboard_state.as_mut().unwrap();
 
-    let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(DeviceId));
+    let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
+        DeviceId::from_proxy(&keyboard_state.keyboard, &data.seat),
+    ));
     let event = keyboard_state
         .xkb_state
         .process_key_event(keycode, state, repeat);
@@ -8,24 +8,34 @@ use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::protocol::wl_touch::WlTouch;
 use sctk::reexports::client::{Connection, Proxy, QueueHandle};
 use sctk::reexports::protocols::wp::relative_pointer::zv1::client::zwp_relative_pointer_v1::ZwpRelativePointerV1;
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_seat_v2::ZwpTabletSeatV2;
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_v2::ZwpTabletV2;
 use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_v3::ZwpTextInputV3;
 
+use sctk::globals::GlobalData;
 use sctk::seat::pointer::{ThemeSpec, ThemedPointer};
 use sctk::seat::{Capability as SeatCapability, SeatHandler, SeatState};
 
+use crate::event::{DeviceEvent, DeviceInfo, DeviceKind};
 use crate::keyboard::ModifiersState;
+use crate::platform_impl::wayland::event_loop::EventLoopWindowTarget;
 use crate::platform_impl::wayland::state::WinitState;
+use crate::platform_impl::wayland::DeviceId;
 
 mod keyboard;
 mod pointer;
+mod tablet;
 mod text_input;
 mod touch;
 
+pub use pointer::pointer_gestures::PointerGesturesState;
 pub use pointer::relative_pointer::RelativePointerState;
 pub use pointer::{PointerConstraintsState, WinitPointerData, WinitPointerDataExt};
+pub use tablet::TabletManagerState;
 pub use text_input::{TextInputState, ZwpTextInputV3Ext};
 
 use keyboard::{KeyboardData, KeyboardState};
+use pointer::pointer_gestures::PointerGestures;
 use text_input::TextInputData;
 use touch::TouchPoint;
 
@@ -46,6 +56,15 @@ pub struct WinitSeatState {
     /// The relative pointer bound on the seat.
     relative_pointer: Option<ZwpRelativePointerV1>,
 
+    /// The gestures of the pointer.
+    pointer_gestures: Option<PointerGestures>,
+
+    /// The drawing tablets of the seat.
+    tablet_seat: Option<ZwpTabletSeatV2>,
+
+    /// The tablets connected to the seat.
+    tablets: Vec<ZwpTabletV2>,
+
     /// The keyboard bound on the seat.
     keyboard_state: Option<KeyboardState>,
 
@@ -62,6 +81,9 @@ impl WinitSeatState {
             pointer: None,
             touch: None,
             relative_pointer: None,
+            pointer_gestures: None,
+            tablet_seat: None,
+            tablets: Vec::new(),
             text_input: None,
             touch_map: Default::default(),
             keyboard_state: None,
@@ -88,9 +110,15 @@ impl SeatHandler for WinitState {
         match capability {
             SeatCapability::Touch if seat_state.touch.is_none() => {
                 seat_state.touch = self.seat_state.get_touch(queue_handle, &seat).ok();
+                if let Some(touch) = seat_state.touch.as_ref() {
+                    self.events_sink
+                        .push_device_event(DeviceEvent::Added, DeviceId::from_proxy(touch, &seat));
+                }
             }
             SeatCapability::Keyboard if seat_state.keyboard_state.is_none() => {
                 let keyboard = seat.get_keyboard(queue_handle, KeyboardData::new(seat.clone()));
+                self.events_sink
+                    .push_device_event(DeviceEvent::Added, DeviceId::from_proxy(&keyboard, &seat));
                 seat_state.keyboard_state =
                     Some(KeyboardState::new(keyboard, self.loop_handle.clone()));
             }
@@ -112,10 +140,20 @@ impl SeatHandler for WinitState {
                     manager.get_relative_pointer(
                         themed_pointer.pointer(),
                         queue_handle,
-                        sctk::globals::GlobalData,
+                        DeviceId::from_proxy(themed_pointer.pointer(), &seat),
                     )
                 });
 
+                seat_state.pointer_gestures = self
+                    .pointer_gestures
+                    .as_ref()
+                    .map(|manager| manager.get_gestures(themed_pointer.pointer(), queue_handle));
+
+                self.events_sink.push_device_event(
+                    DeviceEvent::Added,
+                    DeviceId::from_proxy(themed_pointer.pointer(), &seat),
+                );
+
                 let themed_pointer = Arc::new(themed_pointer);
 
                 // Register cursor surface.
@@ -127,6 +165,16 @@ impl SeatHandler for WinitState {
             _ => (),
         }
 
+        if let Some(tablet_manager) = seat_state
+            .tablet_seat
+            .is_none()
+            .then_some(self.tablet_manager.as_ref())
+            .flatten()
+        {
+            seat_state.tablet_seat =
+                Some(tablet_manager.get_tablet_seat(&seat, queue_handle, GlobalData));
+        }
+
         if let Some(text_input_state) = seat_state
             .text_input
             .is_none()
@@ -136,7 +184,7 @@ impl SeatHandler for WinitState {
             seat_state.text_input = Some(Arc::new(text_input_state.get_text_input(
                 &seat,
                 queue_handle,
-                TextInputData::default(),
+                TextInputData::new(&seat),
             )));
         }
     }
@@ -153,6 +201,10 @@ impl SeatHandler for WinitState {
         match capability {
             SeatCapability::Touch => {
                 if let Some(touch) = seat_state.touch.take() {
+                    self.events_sink.push_device_event(
+                        DeviceEvent::Removed,
+                        DeviceId::from_proxy(&touch, &seat),
+                    );
                     if touch.version() >= 3 {
                         touch.release();
                     }
@@ -163,7 +215,15 @@ impl SeatHandler for WinitState {
                     relative_pointer.destroy();
                 }
 
+                if let Some(pointer_gestures) = seat_state.pointer_gestures.take() {
+                    pointer_gestures.destroy();
+                }
+
                 if let Some(pointer) = seat_state.pointer.take() {
+                    self.events_sink.push_device_event(
+                        DeviceEvent::Removed,
+                        DeviceId::from_proxy(pointer.pointer(), &seat),
+                    );
                     let pointer_data = pointer.pointer().winit_data();
 
                     // Remove the cursor from the mapping.
@@ -180,7 +240,12 @@ impl SeatHandler for WinitState {
                 }
             }
             SeatCapability::Keyboard => {
-                seat_state.keyboard_state = None;
+                if let Some(keyboard_state) = seat_state.keyboard_state.take() {
+                    self.events_sink.push_device_event(
+                        DeviceEvent::Removed,
+                        DeviceId::from_proxy(&keyboard_state.keyboard, &seat),
+                    );
+                }
             }
             _ => (),
         }
@@ -205,8 +270,64 @@ impl SeatHandler for WinitState {
         _queue_handle: &QueueHandle<Self>,
         seat: WlSeat,
     ) {
-        let _ = self.seats.remove(&seat.id());
+        if let Some(seat_state) = self.seats.remove(&seat.id()) {
+            if let Some(tablet_seat) = seat_state.tablet_seat {
+                tablet_seat.destroy();
+            }
+        }
     }
 }
 
 sctk::delegate_seat!(WinitState);
+
+impl<T> EventLoopWindowTarget<T> {
+    /// Lists the devices of the seats, named after the seat since the compositor only names the
+    /// tablets.
+    pub fn devices(&self) -> Vec<DeviceInfo> {
+        let state = self.state.borrow();
+        let mut devices = Vec::new();
+        for seat in state.seat_state.seats() {
+            let seat_state = match state.seats.get(&seat.id()) {
+                Some(seat_state) => seat_state,
+                None => continue,
+            };
+
+            let seat_name = state.seat_state.info(&seat).and_then(|info| info.name);
+            let device = |device_id, kind, capability: &str| DeviceInfo {
+                id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(device_id)),
+                name: match seat_name.as_ref() {
+                    Some(seat_name) => format!("{seat_name} {capability}"),
+                    None => capability.to_owned(),
+                },
+                kind,
+                vendor_id: None,
+                product_id: None,
+                seat: seat_name.clone(),
+            };
+
+            if let Some(keyboard_state) = seat_state.keyboard_state.as_ref() {
+                let device_id = DeviceId::from_proxy(&keyboard_state.keyboard, &seat);
+                devices.push(device(device_id, DeviceKind::Keyboard, "keyboard"));
+            }
+
+            if let Some(pointer) = seat_state.pointer.as_ref() {
+                let device_id = DeviceId::from_proxy(pointer.pointer(), &seat);
+                devices.push(device(device_id, DeviceKind::Pointer, "pointer"));
+            }
+
+            if let Some(touch) = seat_state.touch.as_ref() {
+                let device_id = DeviceId::from_proxy(touch, &seat);
+                devices.push(device(device_id, DeviceKind::Touchscreen, "touch"));
+            }
+
+            devices.extend(
+                seat_state
+                    .tablets
+                    .iter()
+                    .filter_map(|tablet| tablet::tablet_info(tablet, seat_name.clone())),
+            );
+        }
+
+        devices
+    }
+}
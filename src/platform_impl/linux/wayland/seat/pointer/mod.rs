@@ -4,7 +4,7 @@ use std::ops::Deref;
 use std::sync::{Arc, Mutex};
 
 use sctk::reexports::client::delegate_dispatch;
-use sctk::reexports::client::protocol::wl_pointer::WlPointer;
+use sctk::reexports::client::protocol::wl_pointer::{AxisSource, WlPointer};
 use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::protocol::wl_surface::WlSurface;
 use sctk::reexports::client::{Connection, Proxy, QueueHandle, Dispatch};
@@ -21,11 +21,14 @@ use sctk::seat::SeatState;
 use sctk::shell::xdg::frame::FrameClick;
 
 use crate::dpi::{LogicalPosition, PhysicalPosition};
-use crate::event::{ElementState, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent};
+use crate::event::{
+    ElementState, MouseButton, MouseScrollDelta, ScrollSource, TouchPhase, WindowEvent,
+};
 
 use crate::platform_impl::wayland::state::WinitState;
 use crate::platform_impl::wayland::{self, DeviceId, WindowId};
 
+pub mod pointer_gestures;
 pub mod relative_pointer;
 
 impl PointerHandler for WinitState {
@@ -39,7 +42,9 @@ impl PointerHandler for WinitState {
         let seat = pointer.winit_data().seat();
         let seat_state = self.seats.get(&seat.id()).unwrap();
 
-        let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(DeviceId));
+        let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
+            DeviceId::from_proxy(pointer, seat),
+        ));
 
         for event in events {
             let surface = &event.surface;
@@ -59,8 +64,8 @@ impl PointerHandler for WinitState {
             };
 
             let scale_factor = window.scale_factor();
-            let position: PhysicalPosition<f64> =
-                LogicalPosition::new(event.position.0, event.position.1).to_physical(scale_factor);
+            let position: PhysicalPosition<f64> = window
+                .surface_to_physical(LogicalPosition::new(event.position.0, event.position.1));
 
             match event.kind {
                 // Pointer movements on decorations.
@@ -181,23 +186,37 @@ impl PointerHandler for WinitState {
                 PointerEventKind::Axis {
                     horizontal,
                     vertical,
+                    source,
                     ..
                 } => {
                     // Get the current phase.
                     let mut pointer_data = pointer.winit_data().inner.lock().unwrap();
 
+                    // The source isn't sent with every frame, so keep the last one.
+                    let source = match source {
+                        Some(source) => {
+                            pointer_data.scroll_source = wayland_source_to_winit(source);
+                            pointer_data.scroll_source
+                        }
+                        None => pointer_data.scroll_source,
+                    };
+
                     let has_discrete_scroll = horizontal.discrete != 0 || vertical.discrete != 0;
 
-                    // Figure out what to do about start/ended phases here.
-                    //
-                    // Figure out how to deal with `Started`. Also the `Ended` is not guaranteed
-                    // to be sent for mouse wheels.
+                    // The `axis_stop` event ends the scrolling of the fingers and the continuous
+                    // sources, while the wheels never start nor end.
                     let phase = if horizontal.stop || vertical.stop {
                         TouchPhase::Ended
                     } else {
                         match pointer_data.phase {
-                            // Descrete scroll only results in moved events.
                             _ if has_discrete_scroll => TouchPhase::Moved,
+                            _ if matches!(
+                                source,
+                                ScrollSource::Wheel | ScrollSource::WheelTilt
+                            ) =>
+                            {
+                                TouchPhase::Moved
+                            }
                             TouchPhase::Started | TouchPhase::Moved => TouchPhase::Moved,
                             _ => TouchPhase::Started,
                         }
@@ -222,11 +241,17 @@ impl PointerHandler for WinitState {
                         )
                     };
 
+                    // The `axis_value120` event needs version 8 of `wl_seat`, so use the steps.
+                    let value120 = has_discrete_scroll
+                        .then(|| (-horizontal.discrete * 120, -vertical.discrete * 120));
+
                     self.events_sink.push_window_event(
                         WindowEvent::MouseWheel {
                             device_id,
                             delta,
                             phase,
+                            source,
+                            value120,
                         },
                         window_id,
                     )
@@ -369,6 +394,9 @@ pub struct WinitPointerDataInner {
 
     /// Current axis phase.
     phase: TouchPhase,
+
+    /// The source of the current scroll.
+    scroll_source: ScrollSource,
 }
 
 impl Drop for WinitPointerDataInner {
@@ -391,10 +419,22 @@ impl Default for WinitPointerDataInner {
             confined_pointer: None,
             latest_button_serial: 0,
             phase: TouchPhase::Ended,
+            scroll_source: ScrollSource::Unknown,
         }
     }
 }
 
+/// Convert the Wayland axis source into winit.
+fn wayland_source_to_winit(source: AxisSource) -> ScrollSource {
+    match source {
+        AxisSource::Wheel => ScrollSource::Wheel,
+        AxisSource::Finger => ScrollSource::Finger,
+        AxisSource::Continuous => ScrollSource::Continuous,
+        AxisSource::WheelTilt => ScrollSource::WheelTilt,
+        _ => ScrollSource::Unknown,
+    }
+}
+
 /// Convert the Wayland button into winit.
 fn wayland_button_to_winit(button: u32) -> MouseButton {
     // These values are coming from <linux/input-event-codes.h>.
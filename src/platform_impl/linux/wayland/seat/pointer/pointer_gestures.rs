@@ -0,0 +1,307 @@
+//! Pointer gestures.
+
+use std::ops::Deref;
+use std::sync::Mutex;
+
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_pointer::WlPointer;
+use sctk::reexports::client::protocol::wl_surface::WlSurface;
+use sctk::reexports::client::{delegate_dispatch, Dispatch};
+use sctk::reexports::client::{Connection, Proxy, QueueHandle};
+use sctk::reexports::protocols::wp::pointer_gestures::zv1::client::{
+    zwp_pointer_gesture_hold_v1::{self, ZwpPointerGestureHoldV1},
+    zwp_pointer_gesture_pinch_v1::{self, ZwpPointerGesturePinchV1},
+    zwp_pointer_gesture_swipe_v1::{self, ZwpPointerGestureSwipeV1},
+    zwp_pointer_gestures_v1::ZwpPointerGesturesV1,
+};
+
+use sctk::compositor::SurfaceData;
+use sctk::globals::GlobalData;
+
+use crate::dpi::LogicalPosition;
+use crate::event::{TouchPhase, WindowEvent};
+use crate::platform_impl::wayland::seat::WinitPointerDataExt;
+use crate::platform_impl::wayland::state::WinitState;
+use crate::platform_impl::wayland::{self, DeviceId, WindowId};
+
+/// Wrapper around the pointer gestures manager.
+pub struct PointerGesturesState {
+    manager: ZwpPointerGesturesV1,
+}
+
+impl PointerGesturesState {
+    /// Create new pointer gestures manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=3, GlobalData)?;
+        Ok(Self { manager })
+    }
+
+    /// Create the gestures of the pointer.
+    pub fn get_gestures(
+        &self,
+        pointer: &WlPointer,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> PointerGestures {
+        let hold = (self.manager.version() >= 3).then(|| {
+            self.manager
+                .get_hold_gesture(pointer, queue_handle, GestureData::new(pointer))
+        });
+        PointerGestures {
+            swipe: self
+                .manager
+                .get_swipe_gesture(pointer, queue_handle, GestureData::new(pointer)),
+            pinch: self
+                .manager
+                .get_pinch_gesture(pointer, queue_handle, GestureData::new(pointer)),
+            hold,
+        }
+    }
+}
+
+impl Deref for PointerGesturesState {
+    type Target = ZwpPointerGesturesV1;
+
+    fn deref(&self) -> &Self::Target {
+        &self.manager
+    }
+}
+
+/// The gestures of a pointer.
+#[derive(Debug)]
+pub struct PointerGestures {
+    swipe: ZwpPointerGestureSwipeV1,
+    pinch: ZwpPointerGesturePinchV1,
+    hold: Option<ZwpPointerGestureHoldV1>,
+}
+
+impl PointerGestures {
+    pub fn destroy(self) {
+        self.swipe.destroy();
+        self.pinch.destroy();
+        if let Some(hold) = self.hold {
+            hold.destroy();
+        }
+    }
+}
+
+/// The state of the current gesture.
+#[derive(Debug)]
+pub struct GestureData {
+    /// The pointer the gestures are performed with.
+    device_id: DeviceId,
+    inner: Mutex<GestureDataInner>,
+}
+
+#[derive(Debug, Default)]
+struct GestureDataInner {
+    /// The window the gesture started on.
+    window_id: Option<WindowId>,
+
+    /// The number of fingers of the gesture.
+    fingers: u32,
+
+    /// The scale of the last pinch update, since the compositor sends the total scale.
+    scale: f64,
+}
+
+impl GestureData {
+    fn new(pointer: &WlPointer) -> Self {
+        Self {
+            device_id: DeviceId::from_proxy(pointer, pointer.winit_data().seat()),
+            inner: Default::default(),
+        }
+    }
+
+    fn device_id(&self) -> crate::event::DeviceId {
+        crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(self.device_id))
+    }
+
+    /// Start the gesture on the surface, returning the window.
+    fn begin(&self, surface: &WlSurface, fingers: u32) -> WindowId {
+        // Gestures on the decorations are delivered to the window.
+        let surface = surface
+            .data::<SurfaceData>()
+            .and_then(|data| data.parent_surface())
+            .unwrap_or(surface);
+        let window_id = wayland::make_wid(surface);
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.window_id = Some(window_id);
+        inner.fingers = fingers;
+        inner.scale = 1.;
+        window_id
+    }
+}
+
+fn end_phase(cancelled: i32) -> TouchPhase {
+    if cancelled == 0 {
+        TouchPhase::Ended
+    } else {
+        TouchPhase::Cancelled
+    }
+}
+
+impl Dispatch<ZwpPointerGesturesV1, GlobalData, WinitState> for PointerGesturesState {
+    fn event(
+        _state: &mut WinitState,
+        _proxy: &ZwpPointerGesturesV1,
+        _event: <ZwpPointerGesturesV1 as wayland_client::Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpPointerGestureSwipeV1, GestureData, WinitState> for PointerGesturesState {
+    fn event(
+        state: &mut WinitState,
+        _proxy: &ZwpPointerGestureSwipeV1,
+        event: <ZwpPointerGestureSwipeV1 as wayland_client::Proxy>::Event,
+        data: &GestureData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        let (window_id, fingers, delta, phase) = match event {
+            zwp_pointer_gesture_swipe_v1::Event::Begin {
+                surface, fingers, ..
+            } => {
+                let window_id = data.begin(&surface, fingers);
+                (window_id, fingers, (0., 0.), TouchPhase::Started)
+            }
+            zwp_pointer_gesture_swipe_v1::Event::Update { dx, dy, .. } => {
+                let inner = data.inner.lock().unwrap();
+                match inner.window_id {
+                    Some(window_id) => (window_id, inner.fingers, (dx, dy), TouchPhase::Moved),
+                    None => return,
+                }
+            }
+            zwp_pointer_gesture_swipe_v1::Event::End { cancelled, .. } => {
+                let mut inner = data.inner.lock().unwrap();
+                match inner.window_id.take() {
+                    Some(window_id) => (window_id, inner.fingers, (0., 0.), end_phase(cancelled)),
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        let scale_factor = match state.windows.get_mut().get(&window_id) {
+            Some(window) => window.lock().unwrap().scale_factor(),
+            None => return,
+        };
+
+        state.events_sink.push_window_event(
+            WindowEvent::SwipeGesture {
+                device_id: data.device_id(),
+                fingers,
+                delta: LogicalPosition::new(delta.0, delta.1).to_physical(scale_factor),
+                phase,
+            },
+            window_id,
+        );
+    }
+}
+
+impl Dispatch<ZwpPointerGesturePinchV1, GestureData, WinitState> for PointerGesturesState {
+    fn event(
+        state: &mut WinitState,
+        _proxy: &ZwpPointerGesturePinchV1,
+        event: <ZwpPointerGesturePinchV1 as wayland_client::Proxy>::Event,
+        data: &GestureData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        let (window_id, magnify, rotate, phase) = match event {
+            zwp_pointer_gesture_pinch_v1::Event::Begin {
+                surface, fingers, ..
+            } => {
+                let window_id = data.begin(&surface, fingers);
+                (window_id, 0., 0., TouchPhase::Started)
+            }
+            zwp_pointer_gesture_pinch_v1::Event::Update {
+                scale, rotation, ..
+            } => {
+                let mut inner = data.inner.lock().unwrap();
+                let window_id = match inner.window_id {
+                    Some(window_id) => window_id,
+                    None => return,
+                };
+                let magnify = scale - inner.scale;
+                inner.scale = scale;
+                // The rotation is clockwise, in degrees.
+                (window_id, magnify, -rotation as f32, TouchPhase::Moved)
+            }
+            zwp_pointer_gesture_pinch_v1::Event::End { cancelled, .. } => {
+                match data.inner.lock().unwrap().window_id.take() {
+                    Some(window_id) => (window_id, 0., 0., end_phase(cancelled)),
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        let device_id = data.device_id();
+        state.events_sink.push_window_event(
+            WindowEvent::TouchpadMagnify {
+                device_id,
+                delta: magnify,
+                phase,
+            },
+            window_id,
+        );
+        state.events_sink.push_window_event(
+            WindowEvent::TouchpadRotate {
+                device_id,
+                delta: rotate,
+                phase,
+            },
+            window_id,
+        );
+    }
+}
+
+impl Dispatch<ZwpPointerGestureHoldV1, GestureData, WinitState> for PointerGesturesState {
+    fn event(
+        state: &mut WinitState,
+        _proxy: &ZwpPointerGestureHoldV1,
+        event: <ZwpPointerGestureHoldV1 as wayland_client::Proxy>::Event,
+        data: &GestureData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        let (window_id, fingers, phase) = match event {
+            zwp_pointer_gesture_hold_v1::Event::Begin {
+                surface, fingers, ..
+            } => {
+                let window_id = data.begin(&surface, fingers);
+                (window_id, fingers, TouchPhase::Started)
+            }
+            zwp_pointer_gesture_hold_v1::Event::End { cancelled, .. } => {
+                let mut inner = data.inner.lock().unwrap();
+                match inner.window_id.take() {
+                    Some(window_id) => (window_id, inner.fingers, end_phase(cancelled)),
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        state.events_sink.push_window_event(
+            WindowEvent::HoldGesture {
+                device_id: data.device_id(),
+                fingers,
+                phase,
+            },
+            window_id,
+        );
+    }
+}
+
+delegate_dispatch!(WinitState: [ZwpPointerGesturesV1: GlobalData] => PointerGesturesState);
+delegate_dispatch!(WinitState: [ZwpPointerGestureSwipeV1: GestureData] => PointerGesturesState);
+delegate_dispatch!(WinitState: [ZwpPointerGesturePinchV1: GestureData] => PointerGesturesState);
+delegate_dispatch!(WinitState: [ZwpPointerGestureHoldV1: GestureData] => PointerGesturesState);
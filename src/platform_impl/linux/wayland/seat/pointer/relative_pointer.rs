@@ -14,6 +14,7 @@ use sctk::globals::GlobalData;
 
 use crate::event::DeviceEvent;
 use crate::platform_impl::wayland::state::WinitState;
+use crate::platform_impl::wayland::DeviceId;
 
 /// Wrapper around the relative pointer.
 pub struct RelativePointerState {
@@ -51,16 +52,18 @@ impl Dispatch<ZwpRelativePointerManagerV1, GlobalData, WinitState> for RelativeP
     }
 }
 
-impl Dispatch<ZwpRelativePointerV1, GlobalData, WinitState> for RelativePointerState {
+impl Dispatch<ZwpRelativePointerV1, DeviceId, WinitState> for RelativePointerState {
     fn event(
         state: &mut WinitState,
         _proxy: &ZwpRelativePointerV1,
         event: <ZwpRelativePointerV1 as wayland_client::Proxy>::Event,
-        _data: &GlobalData,
+        device_id: &DeviceId,
         _conn: &Connection,
         _qhandle: &QueueHandle<WinitState>,
     ) {
         if let zwp_relative_pointer_v1::Event::RelativeMotion {
+            dx,
+            dy,
             dx_unaccel,
             dy_unaccel,
             ..
@@ -68,13 +71,14 @@ impl Dispatch<ZwpRelativePointerV1, GlobalData, WinitState> for RelativePointerS
         {
             state.events_sink.push_device_event(
                 DeviceEvent::MouseMotion {
-                    delta: (dx_unaccel, dy_unaccel),
+                    delta: (dx, dy),
+                    unaccelerated_delta: Some((dx_unaccel, dy_unaccel)),
                 },
-                super::DeviceId,
+                *device_id,
             );
         }
     }
 }
 
-delegate_dispatch!(WinitState: [ZwpRelativePointerV1: GlobalData] => RelativePointerState);
+delegate_dispatch!(WinitState: [ZwpRelativePointerV1: DeviceId] => RelativePointerState);
 delegate_dispatch!(WinitState: [ZwpRelativePointerManagerV1: GlobalData] => RelativePointerState);
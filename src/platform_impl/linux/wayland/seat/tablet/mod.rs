@@ -0,0 +1,833 @@
+//! Drawing tablets handling.
+
+use std::collections::hash_map::Entry;
+use std::env;
+use std::mem;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_surface::WlSurface;
+use sctk::reexports::client::{delegate_dispatch, event_created_child, Dispatch, WEnum};
+use sctk::reexports::client::{Connection, Proxy, QueueHandle};
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_manager_v2::ZwpTabletManagerV2;
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_pad_group_v2::{
+    self, ZwpTabletPadGroupV2,
+};
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_pad_ring_v2::{
+    self, ZwpTabletPadRingV2,
+};
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_pad_strip_v2::{
+    self, ZwpTabletPadStripV2,
+};
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_pad_v2::{
+    self, ZwpTabletPadV2,
+};
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_seat_v2::{
+    self, ZwpTabletSeatV2,
+};
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_tool_v2::{
+    self, Capability, Type as ToolType, ZwpTabletToolV2,
+};
+use sctk::reexports::protocols::wp::tablet::zv2::client::zwp_tablet_v2::{self, ZwpTabletV2};
+
+use sctk::globals::GlobalData;
+
+use fnv::FnvHashMap;
+use wayland_cursor::CursorTheme;
+
+use crate::dpi::LogicalPosition;
+use crate::event::{
+    ButtonId, DeviceEvent, DeviceInfo, DeviceKind, ElementState, MouseButton, PenAxes, PenEvent,
+    PenTool, PenToolKind, WindowEvent,
+};
+use crate::platform_impl::wayland::state::WinitState;
+use crate::platform_impl::wayland::{self, DeviceId, WindowId};
+
+// The buttons on the barrel of the tools, from `linux/input-event-codes.h`.
+const BTN_STYLUS: u32 = 0x14b;
+const BTN_STYLUS2: u32 = 0x14c;
+const BTN_STYLUS3: u32 = 0x149;
+
+/// The maximum value of the normalized axes.
+const AXIS_MAX: f64 = 65535.;
+
+/// Wrapper around the tablet manager.
+pub struct TabletManagerState {
+    manager: ZwpTabletManagerV2,
+
+    /// The cursor themes used for the tools, by scale.
+    cursor_themes: FnvHashMap<u32, CursorTheme>,
+}
+
+impl TabletManagerState {
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=1, GlobalData)?;
+        Ok(Self {
+            manager,
+            cursor_themes: Default::default(),
+        })
+    }
+}
+
+impl Deref for TabletManagerState {
+    type Target = ZwpTabletManagerV2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.manager
+    }
+}
+
+impl Dispatch<ZwpTabletManagerV2, GlobalData, WinitState> for TabletManagerState {
+    fn event(
+        _state: &mut WinitState,
+        _proxy: &ZwpTabletManagerV2,
+        _event: <ZwpTabletManagerV2 as Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpTabletSeatV2, GlobalData, WinitState> for TabletManagerState {
+    fn event(
+        state: &mut WinitState,
+        tablet_seat: &ZwpTabletSeatV2,
+        event: <ZwpTabletSeatV2 as Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        // The tools and pads are handled on their own, the tablets are listed as devices.
+        if let zwp_tablet_seat_v2::Event::TabletAdded { id } = event {
+            if let Some((seat, seat_state)) = state
+                .seats
+                .iter_mut()
+                .find(|(_, seat_state)| seat_state.tablet_seat.as_ref() == Some(tablet_seat))
+            {
+                if let Some(data) = id.data::<TabletData>() {
+                    data.inner.lock().unwrap().seat = seat.protocol_id();
+                }
+                seat_state.tablets.push(id);
+            }
+        }
+    }
+
+    event_created_child!(WinitState, ZwpTabletSeatV2, [
+        zwp_tablet_seat_v2::EVT_TABLET_ADDED_OPCODE => (ZwpTabletV2, TabletData::default()),
+        zwp_tablet_seat_v2::EVT_TOOL_ADDED_OPCODE => (ZwpTabletToolV2, TabletToolData::default()),
+        zwp_tablet_seat_v2::EVT_PAD_ADDED_OPCODE => (ZwpTabletPadV2, TabletPadData::default()),
+    ]);
+}
+
+/// The description of a tablet.
+#[derive(Debug, Default)]
+pub struct TabletData {
+    inner: Mutex<TabletInner>,
+}
+
+#[derive(Debug, Default)]
+struct TabletInner {
+    name: String,
+
+    /// The protocol id of the seat the tablet was added to.
+    seat: u32,
+
+    /// The USB vendor and product ids.
+    ids: Option<(u16, u16)>,
+
+    /// Whether the description is complete.
+    done: bool,
+}
+
+impl Dispatch<ZwpTabletV2, TabletData, WinitState> for TabletManagerState {
+    fn event(
+        state: &mut WinitState,
+        tablet: &ZwpTabletV2,
+        event: <ZwpTabletV2 as Proxy>::Event,
+        data: &TabletData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        let mut inner = data.inner.lock().unwrap();
+        match event {
+            zwp_tablet_v2::Event::Name { name } => inner.name = name,
+            zwp_tablet_v2::Event::Id { vid, pid } => inner.ids = Some((vid as u16, pid as u16)),
+            zwp_tablet_v2::Event::Done => {
+                inner.done = true;
+                state
+                    .events_sink
+                    .push_device_event(DeviceEvent::Added, inner.device_id(tablet));
+            }
+            zwp_tablet_v2::Event::Removed => {
+                for seat_state in state.seats.values_mut() {
+                    seat_state.tablets.retain(|other| other != tablet);
+                }
+                state
+                    .events_sink
+                    .push_device_event(DeviceEvent::Removed, inner.device_id(tablet));
+                tablet.destroy();
+            }
+            _ => (),
+        }
+    }
+}
+
+impl TabletInner {
+    /// The device id of the tablet, within the seat it was added to.
+    fn device_id(&self, tablet: &ZwpTabletV2) -> DeviceId {
+        DeviceId {
+            id: tablet.id().protocol_id(),
+            seat: self.seat,
+        }
+    }
+}
+
+/// Get the device id of the tablet.
+fn tablet_device_id(tablet: &ZwpTabletV2) -> DeviceId {
+    match tablet.data::<TabletData>() {
+        Some(data) => data.inner.lock().unwrap().device_id(tablet),
+        None => DeviceId {
+            id: tablet.id().protocol_id(),
+            seat: 0,
+        },
+    }
+}
+
+/// Describe the tablet, once the compositor has sent its description.
+pub(super) fn tablet_info(tablet: &ZwpTabletV2, seat: Option<String>) -> Option<DeviceInfo> {
+    let inner = tablet.data::<TabletData>()?.inner.lock().unwrap();
+    if !inner.done {
+        return None;
+    }
+
+    Some(DeviceInfo {
+        id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
+            inner.device_id(tablet),
+        )),
+        name: inner.name.clone(),
+        kind: DeviceKind::Tablet,
+        vendor_id: inner.ids.map(|(vendor_id, _)| vendor_id),
+        product_id: inner.ids.map(|(_, product_id)| product_id),
+        seat,
+    })
+}
+
+/// The state of a tablet tool.
+#[derive(Debug, Default)]
+pub struct TabletToolData {
+    inner: Mutex<TabletToolInner>,
+}
+
+#[derive(Debug, Default)]
+struct TabletToolInner {
+    kind: Option<PenToolKind>,
+    serial: Option<u64>,
+
+    /// The tablet the tool is used on.
+    tablet: Option<DeviceId>,
+
+    /// The axes supported by the tool, with their latest values.
+    axes: PenAxes,
+
+    /// The surface the tool is over.
+    surface: Option<WlSurface>,
+
+    /// The location of the tool on the surface.
+    location: LogicalPosition<f64>,
+
+    /// The surface showing the cursor of the window under the tool.
+    cursor_surface: Option<WlSurface>,
+
+    /// The buttons without a known meaning, numbered after the known ones in the order they
+    /// were first used.
+    extra_buttons: Vec<u32>,
+
+    /// The changes accumulated until the next frame.
+    pending: PendingToolFrame,
+}
+
+#[derive(Debug, Default)]
+struct PendingToolFrame {
+    proximity_in: bool,
+    down: bool,
+    moved: bool,
+    buttons: Vec<(ButtonId, ElementState)>,
+    up: bool,
+    proximity_out: bool,
+}
+
+impl TabletToolInner {
+    fn tool(&self) -> PenTool {
+        PenTool {
+            kind: self.kind.unwrap_or(PenToolKind::Pen),
+            serial: self.serial,
+        }
+    }
+
+    /// Number the button from 1, the buttons on the barrel coming first.
+    fn button_id(&mut self, button: u32) -> ButtonId {
+        match button {
+            BTN_STYLUS => 1,
+            BTN_STYLUS2 => 2,
+            BTN_STYLUS3 => 3,
+            button => {
+                let index = match self.extra_buttons.iter().position(|&other| other == button) {
+                    Some(index) => index,
+                    None => {
+                        self.extra_buttons.push(button);
+                        self.extra_buttons.len() - 1
+                    }
+                };
+                4 + index as ButtonId
+            }
+        }
+    }
+}
+
+impl Dispatch<ZwpTabletToolV2, TabletToolData, WinitState> for TabletManagerState {
+    fn event(
+        state: &mut WinitState,
+        tool: &ZwpTabletToolV2,
+        event: <ZwpTabletToolV2 as Proxy>::Event,
+        data: &TabletToolData,
+        conn: &Connection,
+        queue_handle: &QueueHandle<WinitState>,
+    ) {
+        let mut inner = data.inner.lock().unwrap();
+        match event {
+            zwp_tablet_tool_v2::Event::Type {
+                tool_type: WEnum::Value(tool_type),
+            } => {
+                inner.kind = Some(match tool_type {
+                    ToolType::Eraser => PenToolKind::Eraser,
+                    ToolType::Brush => PenToolKind::Brush,
+                    ToolType::Pencil => PenToolKind::Pencil,
+                    ToolType::Airbrush => PenToolKind::Airbrush,
+                    ToolType::Finger => PenToolKind::Finger,
+                    ToolType::Mouse => PenToolKind::Mouse,
+                    ToolType::Lens => PenToolKind::Lens,
+                    _ => PenToolKind::Pen,
+                });
+            }
+            zwp_tablet_tool_v2::Event::HardwareSerial {
+                hardware_serial_hi,
+                hardware_serial_lo,
+            } => {
+                inner.serial = Some((hardware_serial_hi as u64) << 32 | hardware_serial_lo as u64);
+            }
+            zwp_tablet_tool_v2::Event::Capability {
+                capability: WEnum::Value(capability),
+            } => match capability {
+                Capability::Pressure => inner.axes.pressure = Some(0.),
+                Capability::Tilt => inner.axes.tilt = Some((0., 0.)),
+                Capability::Distance => inner.axes.distance = Some(0.),
+                Capability::Rotation => inner.axes.rotation = Some(0.),
+                Capability::Slider => inner.axes.slider = Some(0.),
+                _ => (),
+            },
+            zwp_tablet_tool_v2::Event::ProximityIn {
+                serial,
+                tablet,
+                surface,
+            } => {
+                let tablet = tablet_device_id(&tablet);
+                inner.tablet = Some(tablet);
+                set_tool_cursor(
+                    state,
+                    conn,
+                    queue_handle,
+                    tool,
+                    &mut inner,
+                    serial,
+                    &surface,
+                    tablet.seat,
+                );
+                inner.surface = Some(surface);
+                inner.pending.proximity_in = true;
+            }
+            zwp_tablet_tool_v2::Event::ProximityOut => inner.pending.proximity_out = true,
+            zwp_tablet_tool_v2::Event::Down { .. } => inner.pending.down = true,
+            zwp_tablet_tool_v2::Event::Up => inner.pending.up = true,
+            zwp_tablet_tool_v2::Event::Motion { x, y } => {
+                inner.location = LogicalPosition::new(x, y);
+                inner.pending.moved = true;
+            }
+            zwp_tablet_tool_v2::Event::Pressure { pressure } => {
+                inner.axes.pressure = Some(pressure as f64 / AXIS_MAX);
+                inner.pending.moved = true;
+            }
+            zwp_tablet_tool_v2::Event::Distance { distance } => {
+                inner.axes.distance = Some(distance as f64 / AXIS_MAX);
+                inner.pending.moved = true;
+            }
+            zwp_tablet_tool_v2::Event::Tilt { tilt_x, tilt_y } => {
+                inner.axes.tilt = Some((tilt_x, tilt_y));
+                inner.pending.moved = true;
+            }
+            zwp_tablet_tool_v2::Event::Rotation { degrees } => {
+                inner.axes.rotation = Some(degrees);
+                inner.pending.moved = true;
+            }
+            zwp_tablet_tool_v2::Event::Slider { position } => {
+                inner.axes.slider = Some(position as f64 / AXIS_MAX);
+                inner.pending.moved = true;
+            }
+            zwp_tablet_tool_v2::Event::Button {
+                button,
+                state: WEnum::Value(button_state),
+                ..
+            } => {
+                let button_state = match button_state {
+                    zwp_tablet_tool_v2::ButtonState::Pressed => ElementState::Pressed,
+                    _ => ElementState::Released,
+                };
+                let button = inner.button_id(button);
+                inner.pending.buttons.push((button, button_state));
+            }
+            zwp_tablet_tool_v2::Event::Frame { .. } => {
+                let pending = mem::take(&mut inner.pending);
+                let proximity_out = pending.proximity_out;
+                if let (Some(surface), Some(tablet)) = (inner.surface.clone(), inner.tablet) {
+                    tool_frame(state, &inner, &surface, tablet, pending);
+                }
+                if proximity_out {
+                    inner.surface = None;
+                }
+            }
+            zwp_tablet_tool_v2::Event::Removed => {
+                tool.destroy();
+                if let Some(cursor_surface) = inner.cursor_surface.take() {
+                    cursor_surface.destroy();
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Show the cursor of the window under the tool, like for the pointers of the seat.
+#[allow(clippy::too_many_arguments)]
+fn set_tool_cursor(
+    state: &mut WinitState,
+    conn: &Connection,
+    queue_handle: &QueueHandle<WinitState>,
+    tool: &ZwpTabletToolV2,
+    inner: &mut TabletToolInner,
+    serial: u32,
+    surface: &WlSurface,
+    seat: u32,
+) {
+    let window_id = wayland::make_wid(surface);
+    let (cursor_icon, scale) = match state.windows.get_mut().get(&window_id) {
+        Some(window) => {
+            let window = window.lock().unwrap();
+            (
+                window.seat_cursor_icon(seat),
+                window.scale_factor().ceil() as u32,
+            )
+        }
+        None => return,
+    };
+
+    let cursor_icon = match cursor_icon {
+        Some(cursor_icon) => cursor_icon,
+        None => {
+            tool.set_cursor(serial, None, 0, 0);
+            return;
+        }
+    };
+
+    let tablet_manager = match state.tablet_manager.as_mut() {
+        Some(tablet_manager) => tablet_manager,
+        None => return,
+    };
+    let theme = match tablet_manager.cursor_themes.entry(scale) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            // Follow the system theme, like the themed pointers of SCTK.
+            let name = env::var("XCURSOR_THEME").unwrap_or_else(|_| "default".into());
+            let size = env::var("XCURSOR_SIZE")
+                .ok()
+                .and_then(|size| size.parse().ok())
+                .unwrap_or(24u32);
+            match CursorTheme::load_from_name(conn, state.shm.wl_shm().clone(), &name, size * scale)
+            {
+                Ok(theme) => entry.insert(theme),
+                Err(_) => return,
+            }
+        }
+    };
+
+    let image = match theme.get_cursor(cursor_icon.name()) {
+        Some(cursor) => &cursor[0],
+        None => {
+            warn!("Failed to set cursor to {:?}", cursor_icon);
+            return;
+        }
+    };
+    let (width, height) = image.dimensions();
+    let (hotspot_x, hotspot_y) = image.hotspot();
+
+    let cursor_surface = inner
+        .cursor_surface
+        .get_or_insert_with(|| state.compositor_state.create_surface(queue_handle));
+    cursor_surface.set_buffer_scale(scale as i32);
+    cursor_surface.attach(Some(image), 0, 0);
+    if cursor_surface.version() >= 4 {
+        cursor_surface.damage_buffer(0, 0, width as i32, height as i32);
+    } else {
+        cursor_surface.damage(0, 0, (width / scale) as i32, (height / scale) as i32);
+    }
+    cursor_surface.commit();
+
+    tool.set_cursor(
+        serial,
+        Some(cursor_surface),
+        (hotspot_x / scale) as i32,
+        (hotspot_y / scale) as i32,
+    );
+}
+
+/// Deliver the events of the tool accumulated during the frame.
+fn tool_frame(
+    state: &mut WinitState,
+    inner: &TabletToolInner,
+    surface: &WlSurface,
+    tablet: DeviceId,
+    pending: PendingToolFrame,
+) {
+    let window_id = wayland::make_wid(surface);
+    let location = match state.windows.get_mut().get(&window_id) {
+        Some(window) => window.lock().unwrap().surface_to_physical(inner.location),
+        None => return,
+    };
+
+    let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(tablet));
+    let tool = inner.tool();
+    let push_pen_event = |state: &mut WinitState, event: PenEvent| {
+        state
+            .events_sink
+            .push_window_event(WindowEvent::PenInput { device_id, event }, window_id);
+    };
+
+    // The tools aren't reported as pointers anymore, so emulate them for the applications not
+    // handling the tablets.
+    if pending.proximity_in {
+        push_pen_event(state, PenEvent::ProximityIn { tool });
+        state
+            .events_sink
+            .push_window_event(WindowEvent::CursorEntered { device_id }, window_id);
+    }
+
+    if pending.moved || pending.proximity_in {
+        push_pen_event(
+            state,
+            PenEvent::Motion {
+                tool,
+                location,
+                axes: inner.axes,
+            },
+        );
+        state.events_sink.push_window_event(
+            WindowEvent::CursorMoved {
+                device_id,
+                position: location,
+            },
+            window_id,
+        );
+    }
+
+    if pending.down {
+        push_pen_event(state, PenEvent::Down { tool });
+        push_mouse_input(
+            state,
+            window_id,
+            device_id,
+            ElementState::Pressed,
+            MouseButton::Left,
+        );
+    }
+
+    for (button, button_state) in pending.buttons {
+        let mouse_button = match button {
+            1 => MouseButton::Middle,
+            2 => MouseButton::Right,
+            3 => MouseButton::Back,
+            button => MouseButton::Other(button as u16),
+        };
+        push_pen_event(
+            state,
+            PenEvent::Button {
+                tool,
+                button,
+                state: button_state,
+            },
+        );
+        push_mouse_input(state, window_id, device_id, button_state, mouse_button);
+    }
+
+    if pending.up {
+        push_pen_event(state, PenEvent::Up { tool });
+        push_mouse_input(
+            state,
+            window_id,
+            device_id,
+            ElementState::Released,
+            MouseButton::Left,
+        );
+    }
+
+    if pending.proximity_out {
+        push_pen_event(state, PenEvent::ProximityOut { tool });
+        state
+            .events_sink
+            .push_window_event(WindowEvent::CursorLeft { device_id }, window_id);
+    }
+}
+
+fn push_mouse_input(
+    state: &mut WinitState,
+    window_id: WindowId,
+    device_id: crate::event::DeviceId,
+    button_state: ElementState,
+    button: MouseButton,
+) {
+    state.events_sink.push_window_event(
+        WindowEvent::MouseInput {
+            device_id,
+            state: button_state,
+            button,
+        },
+        window_id,
+    );
+}
+
+/// The state of a tablet pad.
+#[derive(Debug, Default)]
+pub struct TabletPadData {
+    /// The surface which has the focus of the pad, with the tablet the pad belongs to.
+    focus: Mutex<Option<(WlSurface, DeviceId)>>,
+
+    /// The number of rings of the pad, across its groups.
+    rings: AtomicU32,
+
+    /// The number of strips of the pad, across its groups.
+    strips: AtomicU32,
+}
+
+impl Dispatch<ZwpTabletPadV2, TabletPadData, WinitState> for TabletManagerState {
+    fn event(
+        state: &mut WinitState,
+        pad: &ZwpTabletPadV2,
+        event: <ZwpTabletPadV2 as Proxy>::Event,
+        data: &TabletPadData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        match event {
+            zwp_tablet_pad_v2::Event::Group { pad_group } => {
+                if let Some(group_data) = pad_group.data::<TabletPadGroupData>() {
+                    *group_data.pad.lock().unwrap() = Some(pad.clone());
+                }
+            }
+            zwp_tablet_pad_v2::Event::Enter {
+                tablet, surface, ..
+            } => {
+                *data.focus.lock().unwrap() = Some((surface, tablet_device_id(&tablet)));
+            }
+            zwp_tablet_pad_v2::Event::Leave { .. } => {
+                *data.focus.lock().unwrap() = None;
+            }
+            zwp_tablet_pad_v2::Event::Button {
+                button,
+                state: WEnum::Value(button_state),
+                ..
+            } => {
+                let button_state = match button_state {
+                    zwp_tablet_pad_v2::ButtonState::Pressed => ElementState::Pressed,
+                    _ => ElementState::Released,
+                };
+                push_pad_event(
+                    state,
+                    data,
+                    PenEvent::PadButton {
+                        button,
+                        state: button_state,
+                    },
+                );
+            }
+            zwp_tablet_pad_v2::Event::Removed => pad.destroy(),
+            _ => (),
+        }
+    }
+
+    event_created_child!(WinitState, ZwpTabletPadV2, [
+        zwp_tablet_pad_v2::EVT_GROUP_OPCODE => (ZwpTabletPadGroupV2, TabletPadGroupData::default()),
+    ]);
+}
+
+/// Send the event to the window having the focus of the pad.
+fn push_pad_event(state: &mut WinitState, data: &TabletPadData, event: PenEvent) {
+    let (window_id, device_id) = match data.focus.lock().unwrap().as_ref() {
+        Some((surface, device_id)) => (wayland::make_wid(surface), *device_id),
+        None => return,
+    };
+
+    let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(device_id));
+    state
+        .events_sink
+        .push_window_event(WindowEvent::PenInput { device_id, event }, window_id);
+}
+
+/// The state of a group of controls of a tablet pad.
+#[derive(Debug, Default)]
+pub struct TabletPadGroupData {
+    pad: Mutex<Option<ZwpTabletPadV2>>,
+}
+
+impl Dispatch<ZwpTabletPadGroupV2, TabletPadGroupData, WinitState> for TabletManagerState {
+    fn event(
+        _state: &mut WinitState,
+        _group: &ZwpTabletPadGroupV2,
+        event: <ZwpTabletPadGroupV2 as Proxy>::Event,
+        data: &TabletPadGroupData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        let pad = match data.pad.lock().unwrap().clone() {
+            Some(pad) => pad,
+            None => return,
+        };
+        let pad_data = pad.data::<TabletPadData>().unwrap();
+
+        let (control, index) = match &event {
+            zwp_tablet_pad_group_v2::Event::Ring { ring } => (
+                ring.data::<PadControlData>(),
+                pad_data.rings.fetch_add(1, Ordering::Relaxed),
+            ),
+            zwp_tablet_pad_group_v2::Event::Strip { strip } => (
+                strip.data::<PadControlData>(),
+                pad_data.strips.fetch_add(1, Ordering::Relaxed),
+            ),
+            _ => return,
+        };
+
+        if let Some(control) = control {
+            let mut control = control.inner.lock().unwrap();
+            control.pad = Some(pad.clone());
+            control.index = index;
+        }
+    }
+
+    event_created_child!(WinitState, ZwpTabletPadGroupV2, [
+        zwp_tablet_pad_group_v2::EVT_RING_OPCODE => (ZwpTabletPadRingV2, PadControlData::default()),
+        zwp_tablet_pad_group_v2::EVT_STRIP_OPCODE => (ZwpTabletPadStripV2, PadControlData::default()),
+    ]);
+}
+
+/// The state of a ring or a strip of a tablet pad.
+#[derive(Debug, Default)]
+pub struct PadControlData {
+    inner: Mutex<PadControlInner>,
+}
+
+#[derive(Debug, Default)]
+struct PadControlInner {
+    pad: Option<ZwpTabletPadV2>,
+
+    /// The index of the control among the controls of the same kind of the pad.
+    index: u32,
+
+    /// The position accumulated until the next frame, the inner `None` means that the finger
+    /// left the control.
+    pending: Option<Option<f64>>,
+}
+
+impl PadControlInner {
+    /// Take the pending position at the end of the frame.
+    fn frame(&mut self) -> Option<(&TabletPadData, Option<f64>)> {
+        let position = self.pending.take()?;
+        let pad_data = self.pad.as_ref()?.data::<TabletPadData>()?;
+        Some((pad_data, position))
+    }
+}
+
+impl Dispatch<ZwpTabletPadRingV2, PadControlData, WinitState> for TabletManagerState {
+    fn event(
+        state: &mut WinitState,
+        _ring: &ZwpTabletPadRingV2,
+        event: <ZwpTabletPadRingV2 as Proxy>::Event,
+        data: &PadControlData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        let mut inner = data.inner.lock().unwrap();
+        match event {
+            zwp_tablet_pad_ring_v2::Event::Angle { degrees } => inner.pending = Some(Some(degrees)),
+            zwp_tablet_pad_ring_v2::Event::Stop => inner.pending = Some(None),
+            zwp_tablet_pad_ring_v2::Event::Frame { .. } => {
+                let ring = inner.index;
+                if let Some((pad_data, angle)) = inner.frame() {
+                    push_pad_event(state, pad_data, PenEvent::PadRing { ring, angle });
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Dispatch<ZwpTabletPadStripV2, PadControlData, WinitState> for TabletManagerState {
+    fn event(
+        state: &mut WinitState,
+        _strip: &ZwpTabletPadStripV2,
+        event: <ZwpTabletPadStripV2 as Proxy>::Event,
+        data: &PadControlData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        let mut inner = data.inner.lock().unwrap();
+        match event {
+            zwp_tablet_pad_strip_v2::Event::Position { position } => {
+                inner.pending = Some(Some(position as f64 / AXIS_MAX));
+            }
+            zwp_tablet_pad_strip_v2::Event::Stop => inner.pending = Some(None),
+            zwp_tablet_pad_strip_v2::Event::Frame { .. } => {
+                let strip = inner.index;
+                if let Some((pad_data, position)) = inner.frame() {
+                    push_pad_event(state, pad_data, PenEvent::PadStrip { strip, position });
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+delegate_dispatch!(WinitState: [ZwpTabletManagerV2: GlobalData] => TabletManagerState);
+delegate_dispatch!(WinitState: [ZwpTabletSeatV2: GlobalData] => TabletManagerState);
+delegate_dispatch!(WinitState: [ZwpTabletV2: TabletData] => TabletManagerState);
+delegate_dispatch!(WinitState: [ZwpTabletToolV2: TabletToolData] => TabletManagerState);
+delegate_dispatch!(WinitState: [ZwpTabletPadV2: TabletPadData] => TabletManagerState);
+delegate_dispatch!(WinitState: [ZwpTabletPadGroupV2: TabletPadGroupData] => TabletManagerState);
+delegate_dispatch!(WinitState: [ZwpTabletPadRingV2: PadControlData] => TabletManagerState);
+delegate_dispatch!(WinitState: [ZwpTabletPadStripV2: PadControlData] => TabletManagerState);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_unknown_buttons_after_known_ones() {
+        let mut inner = TabletToolInner::default();
+        assert_eq!(inner.button_id(BTN_STYLUS2), 2);
+        assert_eq!(inner.button_id(0x110), 4);
+        assert_eq!(inner.button_id(0x100), 5);
+        assert_eq!(inner.button_id(0x110), 4);
+        assert_eq!(inner.button_id(BTN_STYLUS3), 3);
+    }
+}
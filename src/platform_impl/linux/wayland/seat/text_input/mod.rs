@@ -5,6 +5,7 @@ use sctk::reexports::client::{Connection, Proxy, QueueHandle};
 
 use sctk::reexports::client::delegate_dispatch;
 use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::protocol::wl_surface::WlSurface;
 use sctk::reexports::client::Dispatch;
 use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_manager_v3::ZwpTextInputManagerV3;
@@ -13,7 +14,7 @@ use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_v3::
     ContentHint, ContentPurpose, ZwpTextInputV3,
 };
 
-use crate::event::{Ime, WindowEvent};
+use crate::event::{Ime, SeatId, WindowEvent};
 use crate::platform_impl::wayland;
 use crate::platform_impl::wayland::state::WinitState;
 use crate::window::ImePurpose;
@@ -77,9 +78,13 @@ impl Dispatch<ZwpTextInputV3, TextInputData, WinitState> for TextInputState {
                     text_input.enable();
                     text_input.set_content_type_by_purpose(window.ime_purpose());
                     text_input.commit();
-                    state
-                        .events_sink
-                        .push_window_event(WindowEvent::Ime(Ime::Enabled), window_id);
+                    state.events_sink.push_window_event(
+                        WindowEvent::Ime {
+                            seat_id: Some(data.seat_id),
+                            event: Ime::Enabled,
+                        },
+                        window_id,
+                    );
                 }
 
                 window.text_input_entered(text_input);
@@ -102,9 +107,13 @@ impl Dispatch<ZwpTextInputV3, TextInputData, WinitState> for TextInputState {
 
                 window.text_input_left(text_input);
 
-                state
-                    .events_sink
-                    .push_window_event(WindowEvent::Ime(Ime::Disabled), window_id);
+                state.events_sink.push_window_event(
+                    WindowEvent::Ime {
+                        seat_id: Some(data.seat_id),
+                        event: Ime::Disabled,
+                    },
+                    window_id,
+                );
             }
             TextInputEvent::PreeditString {
                 text,
@@ -137,15 +146,22 @@ impl Dispatch<ZwpTextInputV3, TextInputData, WinitState> for TextInputState {
 
                 // Clear preedit at the start of `Done`.
                 state.events_sink.push_window_event(
-                    WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                    WindowEvent::Ime {
+                        seat_id: Some(data.seat_id),
+                        event: Ime::Preedit(String::new(), None),
+                    },
                     window_id,
                 );
 
                 // Send `Commit`.
                 if let Some(text) = text_input_data.pending_commit.take() {
-                    state
-                        .events_sink
-                        .push_window_event(WindowEvent::Ime(Ime::Commit(text)), window_id);
+                    state.events_sink.push_window_event(
+                        WindowEvent::Ime {
+                            seat_id: Some(data.seat_id),
+                            event: Ime::Commit(text),
+                        },
+                        window_id,
+                    );
                 }
 
                 // Send preedit.
@@ -155,7 +171,10 @@ impl Dispatch<ZwpTextInputV3, TextInputData, WinitState> for TextInputState {
                         .map(|b| (b, preedit.cursor_end.unwrap_or(b)));
 
                     state.events_sink.push_window_event(
-                        WindowEvent::Ime(Ime::Preedit(preedit.text, cursor_range)),
+                        WindowEvent::Ime {
+                            seat_id: Some(data.seat_id),
+                            event: Ime::Preedit(preedit.text, cursor_range),
+                        },
                         window_id,
                     );
                 }
@@ -184,9 +203,20 @@ impl ZwpTextInputV3Ext for ZwpTextInputV3 {
 }
 
 /// The Data associated with the text input.
-#[derive(Default)]
 pub struct TextInputData {
     inner: std::sync::Mutex<TextInputDataInner>,
+
+    /// The seat of the text input.
+    seat_id: SeatId,
+}
+
+impl TextInputData {
+    pub fn new(seat: &WlSeat) -> Self {
+        Self {
+            inner: Default::default(),
+            seat_id: wayland::make_seat_id(seat),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -8,7 +8,7 @@ use sctk::reexports::client::{Connection, Proxy, QueueHandle};
 use sctk::seat::touch::{TouchData, TouchHandler};
 
 use crate::dpi::LogicalPosition;
-use crate::event::{Touch, TouchPhase, WindowEvent};
+use crate::event::{Event, Touch, TouchContact, TouchPhase, WindowEvent};
 
 use crate::platform_impl::wayland::state::WinitState;
 use crate::platform_impl::wayland::{self, DeviceId};
@@ -26,28 +26,34 @@ impl TouchHandler for WinitState {
         position: (f64, f64),
     ) {
         let window_id = wayland::make_wid(&surface);
-        let scale_factor = match self.windows.get_mut().get(&window_id) {
-            Some(window) => window.lock().unwrap().scale_factor(),
+        let location = LogicalPosition::<f64>::from(position);
+        let physical_location = match self.windows.get_mut().get(&window_id) {
+            Some(window) => window.lock().unwrap().surface_to_physical(location),
             None => return,
         };
 
-        let location = LogicalPosition::<f64>::from(position);
-
         let seat_state = self.seats.get_mut(&touch.seat().id()).unwrap();
 
         // Update the state of the point.
-        seat_state
-            .touch_map
-            .insert(id, TouchPoint { surface, location });
+        seat_state.touch_map.insert(
+            id,
+            TouchPoint {
+                surface,
+                location,
+                shape: None,
+                orientation: 0.,
+            },
+        );
 
         self.events_sink.push_window_event(
             WindowEvent::Touch(Touch {
                 device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                    DeviceId,
+                    DeviceId::from_proxy(touch, touch.seat()),
                 )),
                 phase: TouchPhase::Started,
-                location: location.to_physical(scale_factor),
+                location: physical_location,
                 force: None,
+                contact: None,
                 id: id as u64,
             }),
             window_id,
@@ -72,19 +78,26 @@ impl TouchHandler for WinitState {
         };
 
         let window_id = wayland::make_wid(&touch_point.surface);
-        let scale_factor = match self.windows.get_mut().get(&window_id) {
-            Some(window) => window.lock().unwrap().scale_factor(),
+        let (location, contact) = match self.windows.get_mut().get(&window_id) {
+            Some(window) => {
+                let window = window.lock().unwrap();
+                (
+                    window.surface_to_physical(touch_point.location),
+                    touch_point.contact(window.scale_factor()),
+                )
+            }
             None => return,
         };
 
         self.events_sink.push_window_event(
             WindowEvent::Touch(Touch {
                 device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                    DeviceId,
+                    DeviceId::from_proxy(touch, touch.seat()),
                 )),
                 phase: TouchPhase::Ended,
-                location: touch_point.location.to_physical(scale_factor),
+                location,
                 force: None,
+                contact,
                 id: id as u64,
             }),
             window_id,
@@ -109,21 +122,27 @@ impl TouchHandler for WinitState {
         };
 
         let window_id = wayland::make_wid(&touch_point.surface);
-        let scale_factor = match self.windows.get_mut().get(&window_id) {
-            Some(window) => window.lock().unwrap().scale_factor(),
+        touch_point.location = LogicalPosition::<f64>::from(position);
+        let (location, contact) = match self.windows.get_mut().get(&window_id) {
+            Some(window) => {
+                let window = window.lock().unwrap();
+                (
+                    window.surface_to_physical(touch_point.location),
+                    touch_point.contact(window.scale_factor()),
+                )
+            }
             None => return,
         };
 
-        touch_point.location = LogicalPosition::<f64>::from(position);
-
         self.events_sink.push_window_event(
             WindowEvent::Touch(Touch {
                 device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                    DeviceId,
+                    DeviceId::from_proxy(touch, touch.seat()),
                 )),
-                phase: TouchPhase::Cancelled,
-                location: touch_point.location.to_physical(scale_factor),
+                phase: TouchPhase::Moved,
+                location,
                 force: None,
+                contact,
                 id: id as u64,
             }),
             window_id,
@@ -135,21 +154,26 @@ impl TouchHandler for WinitState {
 
         for (id, touch_point) in seat_state.touch_map.drain() {
             let window_id = wayland::make_wid(&touch_point.surface);
-            let scale_factor = match self.windows.get_mut().get(&window_id) {
-                Some(window) => window.lock().unwrap().scale_factor(),
+            let (location, contact) = match self.windows.get_mut().get(&window_id) {
+                Some(window) => {
+                    let window = window.lock().unwrap();
+                    (
+                        window.surface_to_physical(touch_point.location),
+                        touch_point.contact(window.scale_factor()),
+                    )
+                }
                 None => return,
             };
 
-            let location = touch_point.location.to_physical(scale_factor);
-
             self.events_sink.push_window_event(
                 WindowEvent::Touch(Touch {
                     device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                        DeviceId,
+                        DeviceId::from_proxy(touch, touch.seat()),
                     )),
                     phase: TouchPhase::Cancelled,
                     location,
                     force: None,
+                    contact,
                     id: id as u64,
                 }),
                 window_id,
@@ -161,16 +185,72 @@ impl TouchHandler for WinitState {
         &mut self,
         _: &Connection,
         _: &QueueHandle<Self>,
-        _: &WlTouch,
-        _: i32,
-        _: f64,
-        _: f64,
+        touch: &WlTouch,
+        id: i32,
+        major: f64,
+        minor: f64,
+    ) {
+        update_queued_contact(self, touch, id, |touch_point| {
+            touch_point.shape = Some((major, minor));
+        });
+    }
+
+    fn orientation(
+        &mut self,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+        touch: &WlTouch,
+        id: i32,
+        orientation: f64,
     ) {
-        // Blank.
+        update_queued_contact(self, touch, id, |touch_point| {
+            touch_point.orientation = orientation;
+        });
     }
+}
+
+/// Update the contact of a point, and of its last queued event.
+///
+/// The shape and the orientation are sent after the down or motion events of the same frame,
+/// whose events were already queued.
+fn update_queued_contact<F: FnOnce(&mut TouchPoint)>(
+    state: &mut WinitState,
+    touch: &WlTouch,
+    id: i32,
+    update: F,
+) {
+    let seat_state = state.seats.get_mut(&touch.seat().id()).unwrap();
+    let touch_point = match seat_state.touch_map.get_mut(&id) {
+        Some(touch_point) => touch_point,
+        None => return,
+    };
+    update(touch_point);
 
-    fn orientation(&mut self, _: &Connection, _: &QueueHandle<Self>, _: &WlTouch, _: i32, _: f64) {
-        // Blank.
+    let window_id = wayland::make_wid(&touch_point.surface);
+    let contact = match state.windows.get_mut().get(&window_id) {
+        Some(window) => touch_point.contact(window.lock().unwrap().scale_factor()),
+        None => return,
+    };
+
+    // The ids of the points are only unique within the seat.
+    let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
+        DeviceId::from_proxy(touch, touch.seat()),
+    ));
+    let queued_touch =
+        state
+            .events_sink
+            .window_events
+            .iter_mut()
+            .rev()
+            .find_map(|event| match event {
+                Event::WindowEvent {
+                    event: WindowEvent::Touch(touch),
+                    ..
+                } if touch.id == id as u64 && touch.device_id == device_id => Some(touch),
+                _ => None,
+            });
+    if let Some(touch) = queued_touch {
+        touch.contact = contact;
     }
 }
 
@@ -182,6 +262,24 @@ pub struct TouchPoint {
 
     /// The location of the point on the surface.
     pub location: LogicalPosition<f64>,
+
+    /// The major and minor axes of the contact on the surface.
+    pub shape: Option<(f64, f64)>,
+
+    /// The orientation of the contact.
+    pub orientation: f64,
+}
+
+impl TouchPoint {
+    /// The contact of the point, in physical pixels.
+    fn contact(&self, scale_factor: f64) -> Option<TouchContact> {
+        let (major, minor) = self.shape?;
+        Some(TouchContact {
+            major: major * scale_factor,
+            minor: minor * scale_factor,
+            orientation: self.orientation,
+        })
+    }
 }
 
 pub trait TouchDataExt {
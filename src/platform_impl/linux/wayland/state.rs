@@ -23,12 +23,19 @@ use sctk::shm::{Shm, ShmHandler};
 use sctk::subcompositor::SubcompositorState;
 
 use crate::dpi::LogicalSize;
+use crate::event::{Event, WindowEvent};
+use crate::window::Theme;
+
+#[cfg(gamepad_platform)]
+use crate::event_loop::DeviceEvents;
+#[cfg(gamepad_platform)]
+use crate::platform_impl::platform::common::gamepad::Gamepads;
 
 use super::event_loop::sink::EventSink;
-use super::output::MonitorHandle;
+use super::output::{MonitorHandle, MonitorState};
 use super::seat::{
-    PointerConstraintsState, RelativePointerState, TextInputState, WinitPointerData,
-    WinitPointerDataExt, WinitSeatState,
+    PointerConstraintsState, PointerGesturesState, RelativePointerState, TabletManagerState,
+    TextInputState, WinitPointerData, WinitPointerDataExt, WinitSeatState,
 };
 use super::types::wp_fractional_scaling::FractionalScalingManager;
 use super::types::wp_viewporter::ViewporterState;
@@ -83,6 +90,9 @@ pub struct WinitState {
     /// Observed monitors.
     pub monitors: Arc<Mutex<Vec<MonitorHandle>>>,
 
+    /// The state of the monitors when last reported, to only report the actual changes.
+    pub monitor_states: FnvHashMap<u32, MonitorState>,
+
     /// Sink to accumulate window events from the compositor, which is latter dispatched in
     /// event loop run.
     pub events_sink: EventSink,
@@ -93,6 +103,12 @@ pub struct WinitState {
     /// Relative pointer.
     pub relative_pointer: Option<RelativePointerState>,
 
+    /// Pointer gestures.
+    pub pointer_gestures: Option<PointerGesturesState>,
+
+    /// The manager of the drawing tablets.
+    pub tablet_manager: Option<TabletManagerState>,
+
     /// Pointer constraints to handle pointer locking and confining.
     pub pointer_constraints: Option<Arc<PointerConstraintsState>>,
 
@@ -102,6 +118,17 @@ pub struct WinitState {
     /// Fractional scaling manager.
     pub fractional_scaling_manager: Option<FractionalScalingManager>,
 
+    /// The theme of the system.
+    pub system_theme: Option<Theme>,
+
+    /// The gamepads of the system.
+    #[cfg(gamepad_platform)]
+    pub gamepads: Option<Gamepads>,
+
+    /// When the input of the gamepads is reported.
+    #[cfg(gamepad_platform)]
+    pub device_events: DeviceEvents,
+
     /// Loop handle to re-register event sources, such as keyboard repeat.
     pub loop_handle: LoopHandle<'static, Self>,
 }
@@ -121,7 +148,11 @@ impl WinitState {
         )?;
 
         let output_state = OutputState::new(globals, queue_handle);
-        let monitors = output_state.outputs().map(MonitorHandle::new).collect();
+        let monitors: Vec<_> = output_state.outputs().map(MonitorHandle::new).collect();
+        let monitor_states = monitors
+            .iter()
+            .map(|monitor| (monitor.native_identifier(), monitor.state()))
+            .collect();
 
         let seat_state = SeatState::new(globals, queue_handle);
 
@@ -159,17 +190,42 @@ impl WinitState {
             text_input_state: TextInputState::new(globals, queue_handle).ok(),
 
             relative_pointer: RelativePointerState::new(globals, queue_handle).ok(),
+            pointer_gestures: PointerGesturesState::new(globals, queue_handle).ok(),
+            tablet_manager: TabletManagerState::new(globals, queue_handle).ok(),
             pointer_constraints: PointerConstraintsState::new(globals, queue_handle)
                 .map(Arc::new)
                 .ok(),
             pointer_surfaces: Default::default(),
 
             monitors: Arc::new(Mutex::new(monitors)),
+            monitor_states,
             events_sink: EventSink::new(),
+            system_theme: None,
+            #[cfg(gamepad_platform)]
+            gamepads: None,
+            #[cfg(gamepad_platform)]
+            device_events: Default::default(),
             loop_handle,
         })
     }
 
+    /// Update the windows following the theme of the system.
+    #[cfg(feature = "xdg-portal")]
+    pub fn system_theme_changed(&mut self, system_theme: Option<Theme>) {
+        self.system_theme = system_theme;
+        for (window_id, window) in self.windows.get_mut() {
+            let mut window = window.lock().unwrap();
+            if !window.set_system_theme(system_theme) {
+                continue;
+            }
+
+            // Without preference, the default frame is light.
+            let theme = window.theme().unwrap_or(Theme::Light);
+            self.events_sink
+                .push_window_event(WindowEvent::ThemeChanged(theme), *window_id);
+        }
+    }
+
     pub fn scale_factor_changed(
         &mut self,
         surface: &WlSurface,
@@ -263,19 +319,37 @@ impl WindowHandler for WinitState {
             self.window_compositor_updates.len() - 1
         };
 
-        // Populate the configure to the window.
-        //
-        // XXX the size on the window will be updated right before dispatching the size to the user.
-        let new_size = self
+        let mut window = self
             .windows
             .get_mut()
             .get_mut(&window_id)
             .expect("got configure for dead window.")
             .lock()
-            .unwrap()
-            .configure(configure, &self.shm, &self.subcompositor_state);
+            .unwrap();
+
+        // Populate the configure to the window.
+        //
+        // XXX the size on the window will be updated right before dispatching the size to the user.
+        let old_state = window.window_state_event();
+        let old_buttons = window.enabled_buttons();
+        let new_size = window.configure(configure, &self.shm, &self.subcompositor_state);
+        let new_state = window.window_state_event();
+        let new_buttons = window.enabled_buttons();
+        drop(window);
 
         self.window_compositor_updates[pos].size = Some(new_size);
+
+        // Notify the user about the state change.
+        if new_state != old_state {
+            if let Some(new_state) = new_state {
+                self.events_sink.push_window_event(new_state, window_id);
+            }
+        }
+
+        if new_buttons != old_buttons {
+            let event = WindowEvent::EnabledButtonsChanged(new_buttons);
+            self.events_sink.push_window_event(event, window_id);
+        }
     }
 }
 
@@ -285,27 +359,44 @@ impl OutputHandler for WinitState {
     }
 
     fn new_output(&mut self, _: &Connection, _: &QueueHandle<Self>, output: WlOutput) {
-        self.monitors
-            .lock()
-            .unwrap()
-            .push(MonitorHandle::new(output));
+        let monitor = MonitorHandle::new(output);
+        self.monitor_states
+            .insert(monitor.native_identifier(), monitor.state());
+        self.monitors.lock().unwrap().push(monitor.clone());
+        self.events_sink
+            .push_monitor_event(Event::MonitorAdded, monitor);
     }
 
     fn update_output(&mut self, _: &Connection, _: &QueueHandle<Self>, updated: WlOutput) {
         let mut monitors = self.monitors.lock().unwrap();
         let updated = MonitorHandle::new(updated);
+        let state = updated.state();
+        let changed = self
+            .monitor_states
+            .insert(updated.native_identifier(), state)
+            != Some(state);
         if let Some(pos) = monitors.iter().position(|output| output == &updated) {
-            monitors[pos] = updated
+            monitors[pos] = updated.clone();
+            // The outputs are updated on each `done`, even when nothing changed.
+            if changed {
+                self.events_sink
+                    .push_monitor_event(Event::MonitorChanged, updated);
+            }
         } else {
-            monitors.push(updated)
+            monitors.push(updated.clone());
+            self.events_sink
+                .push_monitor_event(Event::MonitorAdded, updated);
         }
     }
 
     fn output_destroyed(&mut self, _: &Connection, _: &QueueHandle<Self>, removed: WlOutput) {
         let mut monitors = self.monitors.lock().unwrap();
         let removed = MonitorHandle::new(removed);
+        self.monitor_states.remove(&removed.native_identifier());
         if let Some(pos) = monitors.iter().position(|output| output == &removed) {
             monitors.remove(pos);
+            self.events_sink
+                .push_monitor_event(Event::MonitorRemoved, removed);
         }
     }
 }
@@ -0,0 +1,288 @@
+//! Handling of the wlr-gamma-control.
+//!
+//! Monitors don't have access to the state of the event loop, so the gamma controls live on
+//! their own event queue, which is only dispatched when the gamma ramps are accessed.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::os::unix::io::AsRawFd;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use sctk::reexports::client::backend::ObjectId;
+use sctk::reexports::client::globals::{registry_queue_init, GlobalListContents};
+use sctk::reexports::client::protocol::wl_output::WlOutput;
+use sctk::reexports::client::protocol::wl_registry::{self, WlRegistry};
+use sctk::reexports::client::{Connection, Dispatch, EventQueue, Proxy, QueueHandle};
+use sctk::reexports::protocols_wlr::gamma_control::v1::client::zwlr_gamma_control_manager_v1::ZwlrGammaControlManagerV1;
+use sctk::reexports::protocols_wlr::gamma_control::v1::client::zwlr_gamma_control_v1::{
+    Event as GammaControlEvent, ZwlrGammaControlV1,
+};
+
+use crate::error::{ExternalError, NotSupportedError};
+use crate::monitor::GammaRamp;
+use crate::platform_impl::OsError;
+
+static GAMMA_CONTROL: Lazy<Mutex<Option<GammaControlState>>> = Lazy::new(Default::default);
+
+/// The gamma ramp of the `output`.
+///
+/// The compositor doesn't expose the current ramps, so the ramp set by the application, or a
+/// linear ramp, is returned.
+pub fn gamma_ramp(output: &WlOutput) -> Result<GammaRamp, ExternalError> {
+    with_output_gamma(output, |state, id| {
+        let gamma = &state.controls.outputs[id];
+        match gamma.ramp.clone() {
+            Some(ramp) => Ok(ramp),
+            None => {
+                let ramp = GammaRamp::linear(gamma.size as usize);
+                // Don't hold the exclusive control of the output just for reading.
+                state.remove(id);
+                Ok(ramp)
+            }
+        }
+    })
+}
+
+/// Set the gamma ramp of the `output`, which stays in effect until [`reset`] is called.
+pub fn set_gamma_ramp(
+    output: &WlOutput,
+    red: &[u16],
+    green: &[u16],
+    blue: &[u16],
+) -> Result<(), ExternalError> {
+    with_output_gamma(output, |state, id| {
+        let gamma = state.controls.outputs.get_mut(id).unwrap();
+        if [red, green, blue]
+            .iter()
+            .any(|table| table.len() != gamma.size as usize)
+        {
+            return Err(misc_error(
+                "the size of the gamma ramp doesn't match the output",
+            ));
+        }
+
+        let file = gamma_table_file(red, green, blue)
+            .map_err(|_| misc_error("failed to create the gamma table"))?;
+        gamma.control.set_gamma(file.as_raw_fd());
+        gamma.ramp = Some(GammaRamp {
+            red: red.to_vec(),
+            green: green.to_vec(),
+            blue: blue.to_vec(),
+        });
+
+        // The file descriptor must be sent before the file is closed.
+        state
+            .connection
+            .flush()
+            .map_err(|_| misc_error("failed to send the gamma table"))
+    })
+}
+
+/// Drop all the gamma controls, which makes the compositor restore the original ramps.
+pub fn reset() {
+    if let Some(mut state) = GAMMA_CONTROL.lock().unwrap().take() {
+        let ids: Vec<_> = state.controls.outputs.keys().cloned().collect();
+        for id in ids {
+            state.remove(&id);
+        }
+        let _ = state.connection.flush();
+    }
+}
+
+fn with_output_gamma<R>(
+    output: &WlOutput,
+    f: impl FnOnce(&mut GammaControlState, &ObjectId) -> Result<R, ExternalError>,
+) -> Result<R, ExternalError> {
+    let mut state = GAMMA_CONTROL.lock().unwrap();
+    if state.is_none() {
+        *state = Some(GammaControlState::new(output)?);
+    }
+    let state = state.as_mut().unwrap();
+
+    let id = output.id();
+    state.ensure_control(output)?;
+    f(state, &id)
+}
+
+fn misc_error(error: &'static str) -> ExternalError {
+    ExternalError::Os(os_error!(OsError::WaylandMisc(error)))
+}
+
+/// Write the successive gamma ramps into a file which can be shared with the compositor.
+fn gamma_table_file(red: &[u16], green: &[u16], blue: &[u16]) -> io::Result<File> {
+    let mut file = anonymous_file()?;
+    let data: Vec<u8> = red
+        .iter()
+        .chain(green)
+        .chain(blue)
+        .flat_map(|value| value.to_ne_bytes())
+        .collect();
+    file.write_all(&data)?;
+    file.seek(SeekFrom::Start(0))?;
+    Ok(file)
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn anonymous_file() -> io::Result<File> {
+    use std::os::unix::io::FromRawFd;
+
+    let fd = unsafe { libc::memfd_create(b"winit-gamma\0".as_ptr() as _, libc::MFD_CLOEXEC) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(unsafe { File::from_raw_fd(fd) })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "freebsd")))]
+fn anonymous_file() -> io::Result<File> {
+    let path = std::env::temp_dir().join(format!("winit-gamma-{}", std::process::id()));
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    std::fs::remove_file(&path)?;
+    Ok(file)
+}
+
+/// The gamma controls with the queue dispatching their events.
+struct GammaControlState {
+    connection: Connection,
+    queue: EventQueue<GammaControls>,
+    controls: GammaControls,
+}
+
+impl GammaControlState {
+    fn new(output: &WlOutput) -> Result<Self, ExternalError> {
+        let backend = output
+            .backend()
+            .upgrade()
+            .ok_or_else(|| misc_error("the Wayland connection is closed"))?;
+        let connection = Connection::from_backend(backend);
+        let (globals, queue) = registry_queue_init::<GammaControls>(&connection)
+            .map_err(|_| misc_error("failed to list the Wayland globals"))?;
+        let manager = globals
+            .bind(&queue.handle(), 1..=1, ())
+            .map_err(|_| ExternalError::NotSupported(NotSupportedError::new()))?;
+
+        Ok(Self {
+            connection,
+            queue,
+            controls: GammaControls {
+                manager,
+                outputs: HashMap::new(),
+            },
+        })
+    }
+
+    /// Create the gamma control of the `output` when there's none, and check that it's usable.
+    fn ensure_control(&mut self, output: &WlOutput) -> Result<(), ExternalError> {
+        let id = output.id();
+        if !self.controls.outputs.contains_key(&id) {
+            let control =
+                self.controls
+                    .manager
+                    .get_gamma_control(output, &self.queue.handle(), id.clone());
+            self.controls.outputs.insert(
+                id.clone(),
+                OutputGamma {
+                    control,
+                    size: 0,
+                    failed: false,
+                    ramp: None,
+                },
+            );
+
+            // Wait for the size of the ramps.
+            self.queue
+                .roundtrip(&mut self.controls)
+                .map_err(|_| misc_error("failed to get the gamma control of the output"))?;
+        } else {
+            self.queue
+                .dispatch_pending(&mut self.controls)
+                .map_err(|_| misc_error("failed to get the gamma control of the output"))?;
+        }
+
+        let gamma = &self.controls.outputs[&id];
+        if gamma.failed || gamma.size == 0 {
+            self.remove(&id);
+            return Err(misc_error(
+                "the gamma of the output can't be controlled, it may be used by another client",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn remove(&mut self, id: &ObjectId) {
+        if let Some(gamma) = self.controls.outputs.remove(id) {
+            gamma.control.destroy();
+        }
+    }
+}
+
+struct GammaControls {
+    manager: ZwlrGammaControlManagerV1,
+    outputs: HashMap<ObjectId, OutputGamma>,
+}
+
+struct OutputGamma {
+    control: ZwlrGammaControlV1,
+    /// The number of entries in each ramp.
+    size: u32,
+    /// Whether the compositor revoked the control.
+    failed: bool,
+    /// The ramp set by the application.
+    ramp: Option<GammaRamp>,
+}
+
+impl Dispatch<WlRegistry, GlobalListContents> for GammaControls {
+    fn event(
+        _: &mut Self,
+        _: &WlRegistry,
+        _: wl_registry::Event,
+        _: &GlobalListContents,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        // Only the globals advertised on creation are used.
+    }
+}
+
+impl Dispatch<ZwlrGammaControlManagerV1, ()> for GammaControls {
+    fn event(
+        _: &mut Self,
+        _: &ZwlrGammaControlManagerV1,
+        _: <ZwlrGammaControlManagerV1 as Proxy>::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        // No events.
+    }
+}
+
+impl Dispatch<ZwlrGammaControlV1, ObjectId> for GammaControls {
+    fn event(
+        state: &mut Self,
+        _: &ZwlrGammaControlV1,
+        event: GammaControlEvent,
+        output_id: &ObjectId,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        let gamma = match state.outputs.get_mut(output_id) {
+            Some(gamma) => gamma,
+            None => return,
+        };
+
+        match event {
+            GammaControlEvent::GammaSize { size } => gamma.size = size,
+            GammaControlEvent::Failed => gamma.failed = true,
+            _ => (),
+        }
+    }
+}
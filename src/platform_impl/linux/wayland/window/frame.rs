@@ -0,0 +1,589 @@
+//! The client side decorations frame used by the window.
+
+use std::error::Error;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+use sctk::reexports::client::protocol::wl_shm;
+use sctk::reexports::client::protocol::wl_subsurface::WlSubsurface;
+use sctk::reexports::client::protocol::wl_surface::WlSurface;
+use sctk::reexports::client::{Proxy, QueueHandle};
+use sctk::reexports::protocols::xdg::shell::client::xdg_toplevel::ResizeEdge;
+
+use sctk::compositor::SurfaceData;
+use sctk::shell::xdg::frame::{DecorationsFrame, FrameAction, FrameClick};
+use sctk::shell::xdg::window::{Window, WindowManagerCapabilities, WindowState};
+use sctk::shell::WaylandSurface;
+use sctk::shm::slot::SlotPool;
+use sctk::shm::Shm;
+use sctk::subcompositor::SubcompositorState;
+
+use crate::platform::wayland::{
+    DecorationFrame, FrameAction as UserFrameAction, FrameCanvas, FrameClick as UserFrameClick,
+    FramePart, FrameState, FrameTheme,
+};
+use crate::platform_impl::wayland::state::WinitState;
+use crate::window::{Theme, WindowButtons};
+
+/// The factory to create user provided frames.
+pub type DecorationFrameFactory = Arc<dyn Fn() -> Box<dyn DecorationFrame> + Send + Sync>;
+
+#[cfg(feature = "sctk-adwaita")]
+pub type DefaultFrame = sctk_adwaita::AdwaitaFrame<WinitState>;
+#[cfg(not(feature = "sctk-adwaita"))]
+pub type DefaultFrame = sctk::shell::xdg::frame::fallback_frame::FallbackFrame<WinitState>;
+
+/// The frame used by the window.
+pub enum WinitFrame {
+    /// The frame bundled with winit.
+    Default(Box<DefaultFrame>),
+
+    /// The frame provided by the user.
+    Custom(Box<CustomFrame>),
+}
+
+impl WinitFrame {
+    pub fn new(
+        window: &Window,
+        shm: &Shm,
+        subcompositor: Arc<SubcompositorState>,
+        queue_handle: QueueHandle<WinitState>,
+        custom_frame: Option<&DecorationFrameFactory>,
+        theme: Option<Theme>,
+        frame_theme: Option<FrameTheme>,
+    ) -> Result<Self, Box<dyn Error>> {
+        if let Some(custom_frame) = custom_frame {
+            let mut frame = custom_frame();
+            frame.set_theme(theme);
+            let frame = CustomFrame::new(window, shm, subcompositor, queue_handle, frame)?;
+            return Ok(Self::Custom(Box::new(frame)));
+        }
+
+        #[cfg(not(feature = "sctk-adwaita"))]
+        let _ = (theme, frame_theme);
+
+        DefaultFrame::new(
+            window,
+            shm,
+            subcompositor,
+            queue_handle,
+            #[cfg(feature = "sctk-adwaita")]
+            into_sctk_adwaita_config(theme, frame_theme),
+        )
+        .map(|frame| Self::Default(Box::new(frame)))
+    }
+
+    /// Update the buttons shown by the frame.
+    ///
+    /// The `buttons` must be already limited to the `wm_capabilities`.
+    pub fn set_buttons(
+        &mut self,
+        buttons: WindowButtons,
+        mut wm_capabilities: WindowManagerCapabilities,
+    ) {
+        match self {
+            Self::Default(frame) => {
+                // The default frames pick the buttons from the capabilities.
+                wm_capabilities.set(
+                    WindowManagerCapabilities::MINIMIZE,
+                    buttons.contains(WindowButtons::MINIMIZE),
+                );
+                wm_capabilities.set(
+                    WindowManagerCapabilities::MAXIMIZE,
+                    buttons.contains(WindowButtons::MAXIMIZE),
+                );
+                frame.update_wm_capabilities(wm_capabilities);
+            }
+            Self::Custom(frame) => frame.set_buttons(buttons),
+        }
+    }
+
+    /// Update the theme of the frame.
+    pub fn set_theme(&mut self, theme: Option<Theme>, frame_theme: Option<FrameTheme>) {
+        match self {
+            #[cfg(feature = "sctk-adwaita")]
+            Self::Default(frame) => frame.set_config(into_sctk_adwaita_config(theme, frame_theme)),
+            #[cfg(not(feature = "sctk-adwaita"))]
+            Self::Default(_) => (),
+            Self::Custom(frame) => {
+                let _ = frame_theme;
+                frame.set_theme(theme);
+            }
+        }
+    }
+}
+
+impl DecorationsFrame for WinitFrame {
+    fn on_click(&mut self, click: FrameClick, pressed: bool) -> Option<FrameAction> {
+        match self {
+            Self::Default(frame) => frame.on_click(click, pressed),
+            Self::Custom(frame) => frame.on_click(click, pressed),
+        }
+    }
+
+    fn click_point_moved(&mut self, surface: &WlSurface, x: f64, y: f64) -> Option<&str> {
+        match self {
+            Self::Default(frame) => frame.click_point_moved(surface, x, y),
+            Self::Custom(frame) => frame.click_point_moved(surface, x, y),
+        }
+    }
+
+    fn click_point_left(&mut self) {
+        match self {
+            Self::Default(frame) => frame.click_point_left(),
+            Self::Custom(frame) => frame.click_point_left(),
+        }
+    }
+
+    fn update_state(&mut self, state: WindowState) {
+        match self {
+            Self::Default(frame) => frame.update_state(state),
+            Self::Custom(frame) => frame.update_state(state),
+        }
+    }
+
+    fn update_wm_capabilities(&mut self, wm_capabilities: WindowManagerCapabilities) {
+        match self {
+            Self::Default(frame) => frame.update_wm_capabilities(wm_capabilities),
+            Self::Custom(frame) => frame.update_wm_capabilities(wm_capabilities),
+        }
+    }
+
+    fn resize(&mut self, width: NonZeroU32, height: NonZeroU32) {
+        match self {
+            Self::Default(frame) => frame.resize(width, height),
+            Self::Custom(frame) => frame.resize(width, height),
+        }
+    }
+
+    fn location(&self) -> (i32, i32) {
+        match self {
+            Self::Default(frame) => frame.location(),
+            Self::Custom(frame) => frame.location(),
+        }
+    }
+
+    fn subtract_borders(
+        &self,
+        width: NonZeroU32,
+        height: NonZeroU32,
+    ) -> (Option<NonZeroU32>, Option<NonZeroU32>) {
+        match self {
+            Self::Default(frame) => frame.subtract_borders(width, height),
+            Self::Custom(frame) => frame.subtract_borders(width, height),
+        }
+    }
+
+    fn add_borders(&self, width: u32, height: u32) -> (u32, u32) {
+        match self {
+            Self::Default(frame) => frame.add_borders(width, height),
+            Self::Custom(frame) => frame.add_borders(width, height),
+        }
+    }
+
+    fn is_dirty(&self) -> bool {
+        match self {
+            Self::Default(frame) => frame.is_dirty(),
+            Self::Custom(frame) => frame.is_dirty(),
+        }
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        match self {
+            Self::Default(frame) => frame.set_hidden(hidden),
+            Self::Custom(frame) => frame.set_hidden(hidden),
+        }
+    }
+
+    fn is_hidden(&self) -> bool {
+        match self {
+            Self::Default(frame) => frame.is_hidden(),
+            Self::Custom(frame) => frame.is_hidden(),
+        }
+    }
+
+    fn set_resizable(&mut self, resizable: bool) {
+        match self {
+            Self::Default(frame) => frame.set_resizable(resizable),
+            Self::Custom(frame) => frame.set_resizable(resizable),
+        }
+    }
+
+    fn draw(&mut self) {
+        match self {
+            Self::Default(frame) => frame.draw(),
+            Self::Custom(frame) => frame.draw(),
+        }
+    }
+
+    fn set_title(&mut self, title: impl Into<String>) {
+        match self {
+            Self::Default(frame) => frame.set_title(title),
+            Self::Custom(frame) => frame.set_title(title),
+        }
+    }
+}
+
+/// The adapter driving the user provided [`DecorationFrame`].
+pub struct CustomFrame {
+    /// The user frame.
+    frame: Box<dyn DecorationFrame>,
+
+    /// The parent surface.
+    parent: WlSurface,
+
+    /// The subcompositor to create subsurfaces for the frame parts.
+    subcompositor: Arc<SubcompositorState>,
+
+    /// The queue handle for the subsurfaces.
+    queue_handle: QueueHandle<WinitState>,
+
+    /// The memory pool to use for drawing.
+    pool: SlotPool,
+
+    /// The frame parts and their surfaces. When `None` the frame is hidden.
+    parts: Option<Vec<(FramePart, CustomFrameSurface)>>,
+
+    /// The latest window state.
+    state: WindowState,
+
+    /// Whether the frame is waiting for redraw.
+    dirty: bool,
+}
+
+impl CustomFrame {
+    fn new(
+        window: &Window,
+        shm: &Shm,
+        subcompositor: Arc<SubcompositorState>,
+        queue_handle: QueueHandle<WinitState>,
+        frame: Box<dyn DecorationFrame>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let pool = SlotPool::new(1, shm)?;
+        Ok(Self {
+            frame,
+            parent: window.wl_surface().clone(),
+            subcompositor,
+            queue_handle,
+            pool,
+            parts: Some(Vec::new()),
+            state: WindowState::empty(),
+            dirty: true,
+        })
+    }
+
+    fn set_theme(&mut self, theme: Option<Theme>) {
+        self.frame.set_theme(theme);
+        self.dirty = true;
+    }
+
+    fn set_buttons(&mut self, buttons: WindowButtons) {
+        self.frame.set_buttons(buttons);
+        self.dirty = true;
+    }
+
+    /// Whether the frame doesn't have any borders at the moment.
+    #[inline]
+    fn is_borderless(&self) -> bool {
+        self.state.contains(WindowState::FULLSCREEN) || self.parts.is_none()
+    }
+}
+
+impl DecorationsFrame for CustomFrame {
+    fn on_click(&mut self, click: FrameClick, pressed: bool) -> Option<FrameAction> {
+        let click = match click {
+            FrameClick::Normal => UserFrameClick::Normal,
+            FrameClick::Alternate => UserFrameClick::Alternate,
+        };
+
+        Some(match self.frame.on_click(click, pressed)? {
+            UserFrameAction::Minimize => FrameAction::Minimize,
+            UserFrameAction::Maximize => FrameAction::Maximize,
+            UserFrameAction::UnMaximize => FrameAction::UnMaximize,
+            UserFrameAction::Close => FrameAction::Close,
+            UserFrameAction::Move => FrameAction::Move,
+            UserFrameAction::Resize(direction) => FrameAction::Resize(ResizeEdge::from(direction)),
+            UserFrameAction::ShowMenu(x, y) => FrameAction::ShowMenu(x, y),
+        })
+    }
+
+    fn click_point_moved(&mut self, surface: &WlSurface, x: f64, y: f64) -> Option<&str> {
+        let parts = self.parts.as_ref()?;
+        let index = parts
+            .iter()
+            .position(|(_, part)| &part.surface == surface)?;
+        let part = &parts[index].0;
+        let x = x + part.x as f64;
+        let y = y + part.y as f64;
+        Some(self.frame.pointer_moved(index, x, y).name())
+    }
+
+    fn click_point_left(&mut self) {
+        self.frame.pointer_left();
+    }
+
+    fn update_state(&mut self, state: WindowState) {
+        if self.state == state {
+            return;
+        }
+
+        self.state = state;
+        self.frame.set_state(frame_state(state));
+        self.dirty = true;
+    }
+
+    fn update_wm_capabilities(&mut self, wm_capabilities: WindowManagerCapabilities) {
+        self.set_buttons(supported_buttons(wm_capabilities));
+    }
+
+    fn resize(&mut self, width: NonZeroU32, height: NonZeroU32) {
+        let parts = match self.parts.as_mut() {
+            Some(parts) => parts,
+            None => {
+                warn!("trying to resize the hidden frame");
+                return;
+            }
+        };
+        let layout = self.frame.layout(width, height);
+
+        // Reuse the surfaces of the old layout and create the missing ones.
+        parts.truncate(layout.len());
+        for (index, frame_part) in layout.into_iter().enumerate() {
+            match parts.get_mut(index) {
+                Some((part, _)) => *part = frame_part,
+                None => {
+                    let surface = CustomFrameSurface::new(
+                        &self.parent,
+                        &self.subcompositor,
+                        &self.queue_handle,
+                    );
+                    parts.push((frame_part, surface));
+                }
+            }
+        }
+
+        self.dirty = true;
+    }
+
+    fn location(&self) -> (i32, i32) {
+        if self.is_borderless() {
+            (0, 0)
+        } else {
+            let borders = self.frame.borders();
+            (-(borders.left as i32), -(borders.top as i32))
+        }
+    }
+
+    fn subtract_borders(
+        &self,
+        width: NonZeroU32,
+        height: NonZeroU32,
+    ) -> (Option<NonZeroU32>, Option<NonZeroU32>) {
+        if self.is_borderless() {
+            (Some(width), Some(height))
+        } else {
+            let borders = self.frame.borders();
+            (
+                NonZeroU32::new(width.get().saturating_sub(borders.left + borders.right)),
+                NonZeroU32::new(height.get().saturating_sub(borders.top + borders.bottom)),
+            )
+        }
+    }
+
+    fn add_borders(&self, width: u32, height: u32) -> (u32, u32) {
+        if self.is_borderless() {
+            (width, height)
+        } else {
+            let borders = self.frame.borders();
+            (
+                width + borders.left + borders.right,
+                height + borders.top + borders.bottom,
+            )
+        }
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty || self.frame.is_dirty()
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        if self.is_hidden() == hidden {
+            return;
+        }
+
+        if hidden {
+            self.parts = None;
+        } else {
+            let _ = self.pool.resize(1);
+            self.parts = Some(Vec::new());
+            self.dirty = true;
+        }
+    }
+
+    fn is_hidden(&self) -> bool {
+        self.parts.is_none()
+    }
+
+    fn set_resizable(&mut self, resizable: bool) {
+        self.frame.set_resizable(resizable);
+        self.dirty = true;
+    }
+
+    fn draw(&mut self) {
+        let parts = match self.parts.as_ref() {
+            Some(parts) => parts,
+            None => return,
+        };
+
+        // Reset the dirty bit.
+        self.dirty = false;
+
+        if self.state.contains(WindowState::FULLSCREEN) {
+            // Don't draw the decorations for the full screen surface.
+            for (_, part) in parts {
+                part.surface.attach(None, 0, 0);
+                part.surface.commit();
+            }
+            return;
+        }
+
+        for (index, (frame_part, part)) in parts.iter().enumerate() {
+            let scale = part.surface.data::<SurfaceData>().unwrap().scale_factor();
+            let width = frame_part.width as i32 * scale;
+            let height = frame_part.height as i32 * scale;
+            if width == 0 || height == 0 {
+                part.surface.attach(None, 0, 0);
+                part.surface.commit();
+                continue;
+            }
+
+            let (buffer, canvas) =
+                match self
+                    .pool
+                    .create_buffer(width, height, width * 4, wl_shm::Format::Argb8888)
+                {
+                    Ok((buffer, canvas)) => (buffer, canvas),
+                    Err(_) => continue,
+                };
+
+            canvas.fill(0);
+            let mut canvas = FrameCanvas {
+                buffer: canvas,
+                width: width as u32,
+                height: height as u32,
+                scale: scale as u32,
+            };
+            self.frame.draw(index, &mut canvas);
+
+            part.surface.set_buffer_scale(scale);
+
+            // Update the subsurface position.
+            part.subsurface.set_position(frame_part.x, frame_part.y);
+
+            buffer
+                .attach_to(&part.surface)
+                .expect("failed to attach the buffer");
+            if part.surface.version() >= 4 {
+                part.surface.damage_buffer(0, 0, i32::MAX, i32::MAX);
+            } else {
+                part.surface.damage(0, 0, i32::MAX, i32::MAX);
+            }
+
+            part.surface.commit();
+        }
+    }
+
+    fn set_title(&mut self, title: impl Into<String>) {
+        self.frame.set_title(&title.into());
+        self.dirty = true;
+    }
+}
+
+/// The surface the frame part is drawn into.
+struct CustomFrameSurface {
+    subsurface: WlSubsurface,
+    surface: WlSurface,
+}
+
+impl CustomFrameSurface {
+    fn new(
+        parent: &WlSurface,
+        subcompositor: &SubcompositorState,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> Self {
+        let (subsurface, surface) = subcompositor.create_subsurface(parent.clone(), queue_handle);
+        // XXX sync subsurfaces with the main surface.
+        subsurface.set_sync();
+        Self {
+            subsurface,
+            surface,
+        }
+    }
+}
+
+impl Drop for CustomFrameSurface {
+    fn drop(&mut self) {
+        self.subsurface.destroy();
+        self.surface.destroy();
+    }
+}
+
+/// The buttons backed by the given capabilities.
+pub fn supported_buttons(wm_capabilities: WindowManagerCapabilities) -> WindowButtons {
+    let mut buttons = WindowButtons::CLOSE;
+    buttons.set(
+        WindowButtons::MINIMIZE,
+        wm_capabilities.contains(WindowManagerCapabilities::MINIMIZE),
+    );
+    buttons.set(
+        WindowButtons::MAXIMIZE,
+        wm_capabilities.contains(WindowManagerCapabilities::MAXIMIZE),
+    );
+    buttons
+}
+
+fn frame_state(state: WindowState) -> FrameState {
+    let mut frame_state = FrameState::empty();
+    for (state_flag, frame_flag) in [
+        (WindowState::ACTIVATED, FrameState::ACTIVATED),
+        (WindowState::MAXIMIZED, FrameState::MAXIMIZED),
+        (WindowState::FULLSCREEN, FrameState::FULLSCREEN),
+        (WindowState::RESIZING, FrameState::RESIZING),
+        (WindowState::TILED_LEFT, FrameState::TILED_LEFT),
+        (WindowState::TILED_RIGHT, FrameState::TILED_RIGHT),
+        (WindowState::TILED_TOP, FrameState::TILED_TOP),
+        (WindowState::TILED_BOTTOM, FrameState::TILED_BOTTOM),
+    ] {
+        frame_state.set(frame_flag, state.contains(state_flag));
+    }
+    frame_state
+}
+
+// XXX rust doesn't allow from `Option`.
+#[cfg(feature = "sctk-adwaita")]
+fn into_sctk_adwaita_config(
+    theme: Option<Theme>,
+    frame_theme: Option<FrameTheme>,
+) -> sctk_adwaita::FrameConfig {
+    use sctk_adwaita::theme::{Color, ColorMap, ColorTheme};
+
+    let into_color = |[r, g, b, a]: [u8; 4]| Color::from_rgba8(r, g, b, a);
+    let into_color_map = |colors: crate::platform::wayland::FrameColors| ColorMap {
+        headerbar: into_color(colors.headerbar),
+        button_idle: into_color(colors.button_idle),
+        button_hover: into_color(colors.button_hover),
+        button_icon: into_color(colors.button_icon),
+        border_color: into_color(colors.border),
+        font_color: into_color(colors.font),
+    };
+
+    match (frame_theme, theme) {
+        (Some(frame_theme), _) => sctk_adwaita::FrameConfig::new(ColorTheme {
+            active: into_color_map(frame_theme.active),
+            inactive: into_color_map(frame_theme.inactive),
+        }),
+        (None, Some(Theme::Light)) => sctk_adwaita::FrameConfig::light(),
+        (None, Some(Theme::Dark)) => sctk_adwaita::FrameConfig::dark(),
+        (None, None) => sctk_adwaita::FrameConfig::auto(),
+    }
+}
@@ -21,14 +21,15 @@ use sctk::shell::WaylandSurface;
 
 use crate::dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Position, Size};
 use crate::error::{ExternalError, NotSupportedError, OsError as RootOsError};
-use crate::event::{Ime, WindowEvent};
+use crate::event::{Ime, SeatId, WindowEvent};
 use crate::event_loop::AsyncRequestSerial;
+use crate::platform::wayland::{FrameTheme, WmCapabilities};
 use crate::platform_impl::{
     Fullscreen, MonitorHandle as PlatformMonitorHandle, OsError,
-    PlatformSpecificWindowBuilderAttributes as PlatformAttributes,
+    PlatformSpecificWindowBuilderAttributes as PlatformAttributes, VideoMode as PlatformVideoMode,
 };
 use crate::window::{
-    CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
+    CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, TiledEdges, UserAttentionType,
     WindowAttributes, WindowButtons,
 };
 
@@ -38,8 +39,10 @@ use super::state::WinitState;
 use super::types::xdg_activation::XdgActivationTokenData;
 use super::{EventLoopWindowTarget, WindowId};
 
+mod frame;
 mod state;
 
+pub use frame::DecorationFrameFactory;
 pub use state::WindowState;
 
 /// The Wayland window.
@@ -126,6 +129,8 @@ impl Window {
             size,
             window.clone(),
             attributes.preferred_theme,
+            platform_attributes.frame_theme,
+            platform_attributes.decoration_frame,
         );
 
         // Set transparency hint.
@@ -151,20 +156,17 @@ impl Window {
         // Non-resizable implies that the min and max sizes are set to the same value.
         window_state.set_resizable(attributes.resizable);
 
+        // Set the buttons shown on the frame.
+        window_state.set_enabled_buttons(attributes.enabled_buttons);
+
+        // Set the resize increments emulation.
+        window_state.set_resize_increments(attributes.resize_increments);
+        window_state.set_base_size(platform_attributes.base_size);
+        window_state.request_inner_size(size);
+
         // Set startup mode.
         match attributes.fullscreen.map(Into::into) {
-            Some(Fullscreen::Exclusive(_)) => {
-                warn!("`Fullscreen::Exclusive` is ignored on Wayland");
-            }
-            Some(Fullscreen::Borderless(monitor)) => {
-                let output = monitor.and_then(|monitor| match monitor {
-                    PlatformMonitorHandle::Wayland(monitor) => Some(monitor.proxy),
-                    #[cfg(x11_platform)]
-                    PlatformMonitorHandle::X(_) => None,
-                });
-
-                window.set_fullscreen(output.as_ref())
-            }
+            Some(fullscreen) => apply_fullscreen(&mut window_state, Some(fullscreen)),
             _ if attributes.maximized => window.set_maximized(),
             _ => (),
         };
@@ -281,10 +283,26 @@ impl Window {
     #[inline]
     pub fn inner_size(&self) -> PhysicalSize<u32> {
         let window_state = self.window_state.lock().unwrap();
+        if let Some(mode) = window_state.emulated_mode() {
+            return mode.size();
+        }
+
         let scale_factor = window_state.scale_factor();
         window_state.inner_size().to_physical(scale_factor)
     }
 
+    #[inline]
+    pub fn restored_outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn restored_inner_size(&self) -> PhysicalSize<u32> {
+        let window_state = self.window_state.lock().unwrap();
+        let scale_factor = window_state.scale_factor();
+        window_state.restored_inner_size().to_physical(scale_factor)
+    }
+
     #[inline]
     pub fn request_redraw(&self) {
         self.window_requests
@@ -296,6 +314,10 @@ impl Window {
     #[inline]
     pub fn outer_size(&self) -> PhysicalSize<u32> {
         let window_state = self.window_state.lock().unwrap();
+        if let Some(mode) = window_state.emulated_mode() {
+            return mode.size();
+        }
+
         let scale_factor = window_state.scale_factor();
         window_state.outer_size().to_physical(scale_factor)
     }
@@ -304,7 +326,7 @@ impl Window {
     pub fn request_inner_size(&self, size: Size) -> Option<PhysicalSize<u32>> {
         let mut window_state = self.window_state.lock().unwrap();
         let scale_factor = window_state.scale_factor();
-        window_state.resize(size.to_logical::<u32>(scale_factor));
+        window_state.request_inner_size(size.to_logical::<u32>(scale_factor));
 
         self.request_redraw();
 
@@ -335,12 +357,19 @@ impl Window {
 
     #[inline]
     pub fn resize_increments(&self) -> Option<PhysicalSize<u32>> {
-        None
+        let window_state = self.window_state.lock().unwrap();
+        let scale_factor = window_state.scale_factor();
+        window_state
+            .resize_increments()
+            .map(|increments| increments.to_physical(scale_factor))
     }
 
     #[inline]
-    pub fn set_resize_increments(&self, _increments: Option<Size>) {
-        warn!("`set_resize_increments` is not implemented for Wayland");
+    pub fn set_resize_increments(&self, increments: Option<Size>) {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_resize_increments(increments);
     }
 
     #[inline]
@@ -370,6 +399,13 @@ impl Window {
             .drag_resize_window(direction)
     }
 
+    #[inline]
+    pub fn show_window_menu(&self, position: Position) {
+        let scale_factor = self.scale_factor();
+        let position = position.to_logical(scale_factor);
+        self.window_state.lock().unwrap().show_window_menu(position);
+    }
+
     #[inline]
     pub fn set_resizable(&self, resizable: bool) {
         self.window_state.lock().unwrap().set_resizable(resizable);
@@ -381,14 +417,22 @@ impl Window {
     }
 
     #[inline]
-    pub fn set_enabled_buttons(&self, _buttons: WindowButtons) {
-        // TODO(kchibisov) v5 of the xdg_shell allows that.
+    pub fn set_enabled_buttons(&self, buttons: WindowButtons) {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_enabled_buttons(buttons);
+        self.request_redraw();
     }
 
     #[inline]
     pub fn enabled_buttons(&self) -> WindowButtons {
-        // TODO(kchibisov) v5 of the xdg_shell allows that.
-        WindowButtons::all()
+        self.window_state.lock().unwrap().enabled_buttons()
+    }
+
+    #[inline]
+    pub fn wm_capabilities(&self) -> WmCapabilities {
+        self.window_state.lock().unwrap().wm_capabilities()
     }
 
     #[inline]
@@ -428,6 +472,11 @@ impl Window {
             .unwrap_or_default()
     }
 
+    #[inline]
+    pub fn tiled_edges(&self) -> TiledEdges {
+        self.window_state.lock().unwrap().tiled_edges()
+    }
+
     #[inline]
     pub fn set_maximized(&self, maximized: bool) {
         if maximized {
@@ -439,14 +488,19 @@ impl Window {
 
     #[inline]
     pub(crate) fn fullscreen(&self) -> Option<Fullscreen> {
-        let is_fullscreen = self
-            .window_state
-            .lock()
-            .unwrap()
+        let window_state = self.window_state.lock().unwrap();
+        if let Some(mode) = window_state.emulated_mode() {
+            return Some(Fullscreen::Exclusive(PlatformVideoMode::Wayland(
+                mode.clone(),
+            )));
+        }
+
+        let is_fullscreen = window_state
             .last_configure
             .as_ref()
             .map(|last_configure| last_configure.is_fullscreen())
             .unwrap_or_default();
+        drop(window_state);
 
         if is_fullscreen {
             let current_monitor = self.current_monitor().map(PlatformMonitorHandle::Wayland);
@@ -458,21 +512,7 @@ impl Window {
 
     #[inline]
     pub(crate) fn set_fullscreen(&self, fullscreen: Option<Fullscreen>) {
-        match fullscreen {
-            Some(Fullscreen::Exclusive(_)) => {
-                warn!("`Fullscreen::Exclusive` is ignored on Wayland");
-            }
-            Some(Fullscreen::Borderless(monitor)) => {
-                let output = monitor.and_then(|monitor| match monitor {
-                    PlatformMonitorHandle::Wayland(monitor) => Some(monitor.proxy),
-                    #[cfg(x11_platform)]
-                    PlatformMonitorHandle::X(_) => None,
-                });
-
-                self.window.set_fullscreen(output.as_ref())
-            }
-            None => self.window.unset_fullscreen(),
-        }
+        apply_fullscreen(&mut self.window_state.lock().unwrap(), fullscreen);
     }
 
     #[inline]
@@ -535,6 +575,31 @@ impl Window {
         self.window_state.lock().unwrap().set_cursor_grab(mode)
     }
 
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        seat_id: SeatId,
+        cursor: CursorIcon,
+    ) -> Result<(), ExternalError> {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_seat_cursor(seat_id, cursor);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn set_seat_cursor_grab(
+        &self,
+        seat_id: SeatId,
+        mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_seat_cursor_grab(seat_id, mode)
+    }
+
     #[inline]
     pub fn set_cursor_position(&self, position: Position) -> Result<(), ExternalError> {
         let scale_factor = self.scale_factor();
@@ -587,7 +652,10 @@ impl Window {
         let mut window_state = self.window_state.lock().unwrap();
 
         if window_state.ime_allowed() != allowed && window_state.set_ime_allowed(allowed) {
-            let event = WindowEvent::Ime(if allowed { Ime::Enabled } else { Ime::Disabled });
+            let event = WindowEvent::Ime {
+                seat_id: None,
+                event: if allowed { Ime::Enabled } else { Ime::Disabled },
+            };
             self.window_events_sink
                 .lock()
                 .unwrap()
@@ -652,6 +720,14 @@ impl Window {
         self.window_state.lock().unwrap().theme()
     }
 
+    #[inline]
+    pub fn set_frame_theme(&self, frame_theme: Option<FrameTheme>) {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_frame_theme(frame_theme)
+    }
+
     #[inline]
     pub fn title(&self) -> String {
         self.window_state.lock().unwrap().title().to_owned()
@@ -704,3 +780,47 @@ impl TryFrom<&str> for Theme {
         }
     }
 }
+
+/// Request the `fullscreen` state for the window.
+///
+/// Exclusive fullscreen is emulated by going fullscreen on the output of the video mode and
+/// scaling mode sized buffers to it with the viewport.
+fn apply_fullscreen(window_state: &mut WindowState, fullscreen: Option<Fullscreen>) {
+    let output = match fullscreen {
+        Some(Fullscreen::Exclusive(PlatformVideoMode::Wayland(mode))) => {
+            let output = mode.monitor.proxy.clone();
+            if !window_state.set_fullscreen_mode(Some(mode)) {
+                warn!("`Fullscreen::Exclusive` requires `wp_viewporter`, using borderless instead");
+            }
+            Some(output)
+        }
+        #[cfg(x11_platform)]
+        Some(Fullscreen::Exclusive(PlatformVideoMode::X(_))) => return,
+        Some(Fullscreen::Borderless(monitor)) => {
+            window_state.set_fullscreen_mode(None);
+            monitor.and_then(|monitor| match monitor {
+                PlatformMonitorHandle::Wayland(monitor) => Some(monitor.proxy),
+                #[cfg(x11_platform)]
+                PlatformMonitorHandle::X(_) => None,
+            })
+        }
+        #[cfg(x11_platform)]
+        Some(Fullscreen::BorderlessSpan(monitors)) => {
+            warn!(
+                "`Fullscreen::BorderlessSpan` is not supported on Wayland, using the first monitor"
+            );
+            window_state.set_fullscreen_mode(None);
+            monitors.into_iter().find_map(|monitor| match monitor {
+                PlatformMonitorHandle::Wayland(monitor) => Some(monitor.proxy),
+                PlatformMonitorHandle::X(_) => None,
+            })
+        }
+        None => {
+            window_state.set_fullscreen_mode(None);
+            window_state.window.unset_fullscreen();
+            return;
+        }
+    };
+
+    window_state.window.set_fullscreen(output.as_ref());
+}
@@ -4,6 +4,7 @@ use std::mem::ManuallyDrop;
 use std::num::NonZeroU32;
 use std::sync::{Arc, Weak};
 
+use fnv::FnvHashMap;
 use log::warn;
 
 use sctk::reexports::client::protocol::wl_seat::WlSeat;
@@ -18,26 +19,32 @@ use sctk::reexports::protocols::xdg::shell::client::xdg_toplevel::ResizeEdge;
 use sctk::compositor::{CompositorState, Region, SurfaceData};
 use sctk::seat::pointer::ThemedPointer;
 use sctk::shell::xdg::frame::{DecorationsFrame, FrameAction, FrameClick};
-use sctk::shell::xdg::window::{DecorationMode, Window, WindowConfigure};
+use sctk::shell::xdg::window::{
+    DecorationMode, Window, WindowConfigure, WindowManagerCapabilities,
+    WindowState as XdgWindowState,
+};
 use sctk::shell::xdg::XdgSurface;
 use sctk::shell::WaylandSurface;
 use sctk::shm::Shm;
 use sctk::subcompositor::SubcompositorState;
 
-use crate::dpi::{LogicalPosition, LogicalSize};
+use crate::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, Size};
 use crate::error::{ExternalError, NotSupportedError};
+use crate::event::{SeatId, WindowEvent};
+use crate::platform::wayland::{FrameTheme, WmCapabilities};
+use crate::platform_impl::common::seat_focus::SeatFocus;
+use crate::platform_impl::wayland::VideoMode;
 use crate::platform_impl::WindowId;
-use crate::window::{CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme};
+use crate::window::{
+    CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, TiledEdges, WindowButtons,
+};
 
 use crate::platform_impl::wayland::seat::{
     PointerConstraintsState, WinitPointerData, WinitPointerDataExt, ZwpTextInputV3Ext,
 };
 use crate::platform_impl::wayland::state::{WindowCompositorUpdate, WinitState};
 
-#[cfg(feature = "sctk-adwaita")]
-pub type WinitFrame = sctk_adwaita::AdwaitaFrame<WinitState>;
-#[cfg(not(feature = "sctk-adwaita"))]
-pub type WinitFrame = sctk::shell::xdg::frame::fallback_frame::FallbackFrame<WinitState>;
+use super::frame::{self, DecorationFrameFactory, WinitFrame};
 
 // Minimum window inner size.
 const MIN_WINDOW_SIZE: LogicalSize<u32> = LogicalSize::new(2, 1);
@@ -65,6 +72,9 @@ pub struct WindowState {
     /// Cursor icon.
     pub cursor_icon: CursorIcon,
 
+    /// The cursor icons set for the pointers of a seat, by the protocol id of the seat.
+    seat_cursor_icons: FnvHashMap<u32, CursorIcon>,
+
     /// Wether the cursor is visible.
     pub cursor_visible: bool,
 
@@ -77,14 +87,29 @@ pub struct WindowState {
     /// Theme varaint.
     theme: Option<Theme>,
 
+    /// The theme of the system, used when the theme isn't set.
+    system_theme: Option<Theme>,
+
+    /// The colors for the default frame.
+    frame_theme: Option<FrameTheme>,
+
+    /// The factory for the user provided frame.
+    custom_frame: Option<DecorationFrameFactory>,
+
     /// The current window title.
     title: String,
 
     /// Whether the frame is resizable.
     resizable: bool,
 
-    /// Whether the window has focus.
-    has_focus: bool,
+    /// The buttons requested by the user.
+    requested_buttons: WindowButtons,
+
+    /// The window management capabilities of the compositor.
+    wm_capabilities: WindowManagerCapabilities,
+
+    /// The seats focusing the window, by protocol id.
+    focused_seats: SeatFocus,
 
     /// The scale factor of the window.
     scale_factor: f64,
@@ -120,6 +145,10 @@ pub struct WindowState {
     min_inner_size: LogicalSize<u32>,
     max_inner_size: Option<LogicalSize<u32>>,
 
+    /// The resize increments and the base size they are applied from.
+    resize_increments: Option<Size>,
+    base_size: Option<Size>,
+
     /// The size of the window when no states were applied to it. The primary use for it
     /// is to fallback to original window size, before it was maximized, if the compositor
     /// sends `None` for the new size in the configure.
@@ -128,6 +157,9 @@ pub struct WindowState {
     viewport: Option<WpViewport>,
     fractional_scale: Option<WpFractionalScaleV1>,
 
+    /// The exclusive fullscreen mode emulated by scaling the buffer with the viewport.
+    fullscreen_mode: Option<VideoMode>,
+
     /// Whether the client side decorations have pending move operations.
     ///
     /// The value is the serial of the event triggered moved.
@@ -135,13 +167,16 @@ pub struct WindowState {
 }
 
 /// The state of the cursor grabs.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct GrabState {
     /// The grab mode requested by the user.
     user_grab_mode: CursorGrabMode,
 
     /// The current grab mode.
     current_grab_mode: CursorGrabMode,
+
+    /// The grab modes requested for the pointers of a seat, by the protocol id of the seat.
+    seat_grab_modes: FnvHashMap<u32, CursorGrabMode>,
 }
 
 impl GrabState {
@@ -149,6 +184,7 @@ impl GrabState {
         Self {
             user_grab_mode: CursorGrabMode::None,
             current_grab_mode: CursorGrabMode::None,
+            seat_grab_modes: Default::default(),
         }
     }
 }
@@ -179,15 +215,17 @@ impl WindowState {
             && !self.csd_fails
         {
             match WinitFrame::new(
-                &*self.window,
+                &self.window,
                 shm,
                 subcompositor.clone(),
                 self.queue_handle.clone(),
-                #[cfg(feature = "sctk-adwaita")]
-                into_sctk_adwaita_config(self.theme),
+                self.custom_frame.as_ref(),
+                self.theme(),
+                self.frame_theme,
             ) {
                 Ok(mut frame) => {
                     frame.set_title(&self.title);
+                    frame.set_buttons(self.enabled_buttons(), configure.capabilities);
                     // Hide the frame if we were asked to not decorate.
                     frame.set_hidden(!self.decorate);
                     self.frame = Some(frame);
@@ -202,9 +240,18 @@ impl WindowState {
             self.frame = None;
         }
 
+        // Update the buttons from the capabilities.
+        if self.wm_capabilities != configure.capabilities {
+            self.wm_capabilities = configure.capabilities;
+            let buttons = self.enabled_buttons();
+            if let Some(frame) = self.frame.as_mut() {
+                frame.set_buttons(buttons, self.wm_capabilities);
+            }
+        }
+
         let stateless = Self::is_stateless(&configure);
 
-        let new_size = if let Some(frame) = self.frame.as_mut() {
+        let mut new_size = if let Some(frame) = self.frame.as_mut() {
             // Configure the window states.
             frame.update_state(configure.state);
 
@@ -228,8 +275,19 @@ impl WindowState {
             }
         };
 
+        // Emulate the resize increments for the sizes proposed by the compositor.
+        if stateless && configure.new_size.0.is_some() && configure.new_size.1.is_some() {
+            new_size = self.snap_to_resize_increments(new_size);
+        }
+
+        // The mode emulation ends together with the fullscreen state.
+        if !configure.is_fullscreen() {
+            self.fullscreen_mode = None;
+        }
+
         // XXX Set the configure before doing a resize.
         self.last_configure = Some(configure);
+        self.reload_buffer_scale();
 
         // XXX Update the new size right away.
         self.resize(new_size);
@@ -237,6 +295,38 @@ impl WindowState {
         new_size
     }
 
+    /// The edges of the window tiled by the compositor.
+    pub fn tiled_edges(&self) -> TiledEdges {
+        let state = match self.last_configure.as_ref() {
+            Some(configure) => configure.state,
+            None => return TiledEdges::empty(),
+        };
+
+        let mut tiled_edges = TiledEdges::empty();
+        for (state_flag, edge) in [
+            (XdgWindowState::TILED_LEFT, TiledEdges::LEFT),
+            (XdgWindowState::TILED_RIGHT, TiledEdges::RIGHT),
+            (XdgWindowState::TILED_TOP, TiledEdges::TOP),
+            (XdgWindowState::TILED_BOTTOM, TiledEdges::BOTTOM),
+        ] {
+            tiled_edges.set(edge, state.contains(state_flag));
+        }
+        tiled_edges
+    }
+
+    /// The event describing the current window state, `None` when the window wasn't configured.
+    pub fn window_state_event(&self) -> Option<WindowEvent<'static>> {
+        let configure = self.last_configure.as_ref()?;
+        Some(WindowEvent::WindowStateChanged {
+            maximized: configure.is_maximized(),
+            fullscreen: configure.is_fullscreen(),
+            tiled_edges: self.tiled_edges(),
+            // XXX the suspended state is not supported by the xdg-shell version we bind.
+            suspended: false,
+            activated: configure.is_activated(),
+        })
+    }
+
     #[inline]
     fn is_stateless(configure: &WindowConfigure) -> bool {
         !(configure.is_maximized() || configure.is_fullscreen() || configure.is_tiled())
@@ -269,6 +359,25 @@ impl WindowState {
         Ok(())
     }
 
+    /// Show the window menu at the given position.
+    pub fn show_window_menu(&self, position: LogicalPosition<i32>) {
+        // The compositor shows a single menu, so pick the seat which clicked last, the serials
+        // being shared by all the seats.
+        let latest_click = self
+            .pointers
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|pointer| {
+                let data = pointer.pointer().winit_data();
+                (data.latest_button_serial(), data.seat().clone())
+            })
+            .max_by_key(|(serial, _)| *serial);
+
+        if let Some((serial, seat)) = latest_click {
+            self.window.show_window_menu(&seat, serial, position.into());
+        }
+    }
+
     /// Tells whether the window should be closed.
     pub fn frame_click(
         &mut self,
@@ -352,10 +461,51 @@ impl WindowState {
         }
     }
 
+    /// Get the enabled buttons supported by the compositor.
+    #[inline]
+    pub fn enabled_buttons(&self) -> WindowButtons {
+        self.requested_buttons & frame::supported_buttons(self.wm_capabilities)
+    }
+
+    /// Set the buttons requested by the user.
+    pub fn set_enabled_buttons(&mut self, buttons: WindowButtons) {
+        self.requested_buttons = buttons;
+        let buttons = self.enabled_buttons();
+        if let Some(frame) = self.frame.as_mut() {
+            frame.set_buttons(buttons, self.wm_capabilities);
+        }
+    }
+
+    /// Get the window management capabilities of the compositor.
+    pub fn wm_capabilities(&self) -> WmCapabilities {
+        let mut wm_capabilities = WmCapabilities::empty();
+        for (capability, flag) in [
+            (
+                WindowManagerCapabilities::WINDOW_MENU,
+                WmCapabilities::WINDOW_MENU,
+            ),
+            (
+                WindowManagerCapabilities::MAXIMIZE,
+                WmCapabilities::MAXIMIZE,
+            ),
+            (
+                WindowManagerCapabilities::FULLSCREEN,
+                WmCapabilities::FULLSCREEN,
+            ),
+            (
+                WindowManagerCapabilities::MINIMIZE,
+                WmCapabilities::MINIMIZE,
+            ),
+        ] {
+            wm_capabilities.set(flag, self.wm_capabilities.contains(capability));
+        }
+        wm_capabilities
+    }
+
     /// Whether the window is focused.
     #[inline]
     pub fn has_focus(&self) -> bool {
-        self.has_focus
+        self.focused_seats.has_focus()
     }
 
     /// Whether the IME is allowed.
@@ -392,6 +542,7 @@ impl WindowState {
     }
 
     /// Create new window state.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         connection: Connection,
         queue_handle: &QueueHandle<WinitState>,
@@ -399,6 +550,8 @@ impl WindowState {
         size: LogicalSize<u32>,
         window: Window,
         theme: Option<Theme>,
+        frame_theme: Option<FrameTheme>,
+        custom_frame: Option<DecorationFrameFactory>,
     ) -> Self {
         let compositor = winit_state.compositor_state.clone();
         let pointer_constraints = winit_state.pointer_constraints.clone();
@@ -415,19 +568,26 @@ impl WindowState {
             compositor,
             connection,
             theme,
+            system_theme: winit_state.system_theme,
+            frame_theme,
+            custom_frame,
             csd_fails: false,
             decorate: true,
             cursor_grab_mode: GrabState::new(),
             cursor_icon: CursorIcon::Default,
+            seat_cursor_icons: Default::default(),
             cursor_visible: true,
             fractional_scale,
+            fullscreen_mode: None,
             frame: None,
-            has_focus: false,
+            focused_seats: SeatFocus::default(),
             ime_allowed: false,
             ime_purpose: ImePurpose::Normal,
             last_configure: None,
             max_inner_size: None,
             min_inner_size: MIN_WINDOW_SIZE,
+            resize_increments: None,
+            base_size: None,
             pointer_constraints,
             pointers: Default::default(),
             queue_handle: queue_handle.clone(),
@@ -439,6 +599,8 @@ impl WindowState {
             title: String::default(),
             transparent: false,
             resizable: true,
+            requested_buttons: WindowButtons::all(),
+            wm_capabilities: WindowManagerCapabilities::all(),
             viewport,
             window: ManuallyDrop::new(window),
             has_pending_move: None,
@@ -456,11 +618,20 @@ impl WindowState {
 
     /// Register pointer on the top-level.
     pub fn pointer_entered(&mut self, added: Weak<ThemedPointer<WinitPointerData>>) {
+        let seat = added
+            .upgrade()
+            .map(|pointer| pointer.pointer().winit_data().seat().id().protocol_id());
         self.pointers.push(added);
         self.reload_cursor_style();
 
         let mode = self.cursor_grab_mode.user_grab_mode;
         let _ = self.set_cursor_grab_inner(mode);
+
+        let seat_mode =
+            seat.and_then(|seat| Some((seat, *self.cursor_grab_mode.seat_grab_modes.get(&seat)?)));
+        if let Some((seat, mode)) = seat_mode {
+            self.apply_cursor_grab(Some(seat), mode, mode);
+        }
     }
 
     /// Pointer has left the top-level.
@@ -575,6 +746,12 @@ impl WindowState {
         }
 
         self.apply_on_poiner(|pointer, data| {
+            let seat = data.seat().id().protocol_id();
+            let cursor_icon = self
+                .seat_cursor_icons
+                .get(&seat)
+                .copied()
+                .unwrap_or(cursor_icon);
             let surface = data.cursor_surface();
             let scale_factor = surface.data::<SurfaceData>().unwrap().scale_factor();
 
@@ -593,6 +770,87 @@ impl WindowState {
         })
     }
 
+    /// The cursor icon shown for the pointers of the seat, `None` when the cursor is hidden.
+    pub fn seat_cursor_icon(&self, seat: u32) -> Option<CursorIcon> {
+        if !self.cursor_visible {
+            return None;
+        }
+
+        Some(
+            self.seat_cursor_icons
+                .get(&seat)
+                .copied()
+                .unwrap_or(self.cursor_icon),
+        )
+    }
+
+    /// Set the cursor icon for the pointers of the seat.
+    pub fn set_seat_cursor(&mut self, seat: SeatId, cursor_icon: CursorIcon) {
+        self.seat_cursor_icons.insert(seat.0, cursor_icon);
+        self.reload_cursor_style();
+    }
+
+    /// Get the resize increments in logical pixels.
+    pub fn resize_increments(&self) -> Option<LogicalSize<u32>> {
+        self.resize_increments
+            .map(|increments| increments.to_logical(self.scale_factor))
+    }
+
+    /// Set the resize increments to emulate.
+    #[inline]
+    pub fn set_resize_increments(&mut self, increments: Option<Size>) {
+        self.resize_increments = increments;
+    }
+
+    /// Set the base size for the resize increments.
+    #[inline]
+    pub fn set_base_size(&mut self, base_size: Option<Size>) {
+        self.base_size = base_size;
+    }
+
+    /// Resize the window at the request of the user, following the resize increments.
+    pub fn request_inner_size(&mut self, inner_size: LogicalSize<u32>) {
+        let inner_size = self.snap_to_resize_increments(inner_size);
+        self.resize(inner_size);
+    }
+
+    /// Snap the size down to the resize increments grid, keeping it within the min and max sizes.
+    fn snap_to_resize_increments(&self, size: LogicalSize<u32>) -> LogicalSize<u32> {
+        let increments = match self.resize_increments() {
+            Some(increments) => increments,
+            None => return size,
+        };
+
+        let base_size = self
+            .base_size
+            .map(|base_size| base_size.to_logical(self.scale_factor))
+            .unwrap_or_else(|| (0, 0).into());
+
+        // The min and max sizes include the borders of the frame.
+        let subtract_borders = |size: LogicalSize<u32>| match self.frame.as_ref() {
+            Some(frame) => {
+                let (width, height) = frame.subtract_borders(
+                    NonZeroU32::new(size.width.max(1)).unwrap(),
+                    NonZeroU32::new(size.height.max(1)).unwrap(),
+                );
+                LogicalSize::new(
+                    width.map_or(1, NonZeroU32::get),
+                    height.map_or(1, NonZeroU32::get),
+                )
+            }
+            None => size,
+        };
+        let min_size = subtract_borders(self.min_inner_size);
+        let max_size = self.max_inner_size.map(subtract_borders);
+
+        snap_size(size, base_size, increments, min_size, max_size)
+    }
+
+    /// The inner size of the window the last time it wasn't maximized, fullscreen or tiled.
+    pub fn restored_inner_size(&self) -> LogicalSize<u32> {
+        self.stateless_size
+    }
+
     /// Set maximum inner window size.
     pub fn set_min_inner_size(&mut self, size: Option<LogicalSize<u32>>) {
         // Ensure that the window has the right minimum size.
@@ -627,16 +885,35 @@ impl WindowState {
     /// Set the CSD theme.
     pub fn set_theme(&mut self, theme: Option<Theme>) {
         self.theme = theme;
-        #[cfg(feature = "sctk-adwaita")]
+        self.reload_frame_theme();
+    }
+
+    /// Set the theme of the system, returning whether the theme of the window changed.
+    #[cfg(feature = "xdg-portal")]
+    pub fn set_system_theme(&mut self, system_theme: Option<Theme>) -> bool {
+        let prev_theme = self.theme();
+        self.system_theme = system_theme;
+        self.reload_frame_theme();
+        self.theme() != prev_theme
+    }
+
+    /// Set the colors of the default frame.
+    pub fn set_frame_theme(&mut self, frame_theme: Option<FrameTheme>) {
+        self.frame_theme = frame_theme;
+        self.reload_frame_theme();
+    }
+
+    fn reload_frame_theme(&mut self) {
+        let theme = self.theme();
         if let Some(frame) = self.frame.as_mut() {
-            frame.set_config(into_sctk_adwaita_config(theme))
+            frame.set_theme(theme, self.frame_theme);
         }
     }
 
-    /// The current theme for CSD decorations.
+    /// The current theme for CSD decorations, following the system when it isn't set.
     #[inline]
     pub fn theme(&self) -> Option<Theme> {
-        self.theme
+        self.theme.or(self.system_theme)
     }
 
     /// Set the cursor grabbing state on the top-level.
@@ -652,43 +929,88 @@ impl WindowState {
         self.set_max_inner_size(self.max_inner_size);
     }
 
+    /// Set the cursor grabbing state for the pointers of the seat.
+    pub fn set_seat_cursor_grab(
+        &mut self,
+        seat: SeatId,
+        mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        if self.pointer_constraints.is_none() && mode != CursorGrabMode::None {
+            return Err(ExternalError::NotSupported(NotSupportedError::new()));
+        }
+
+        // Resetting the mode of the seat makes it follow the mode of the window again.
+        let grab_modes = &mut self.cursor_grab_mode.seat_grab_modes;
+        let (old_mode, new_mode) = match mode {
+            CursorGrabMode::None => (
+                grab_modes.remove(&seat.0),
+                self.cursor_grab_mode.current_grab_mode,
+            ),
+            mode => (grab_modes.insert(seat.0, mode), mode),
+        };
+        let old_mode = old_mode.unwrap_or(self.cursor_grab_mode.current_grab_mode);
+        self.apply_cursor_grab(Some(seat.0), old_mode, new_mode);
+
+        Ok(())
+    }
+
     /// Set the grabbing state on the surface.
     fn set_cursor_grab_inner(&mut self, mode: CursorGrabMode) -> Result<(), ExternalError> {
-        let pointer_constraints = match self.pointer_constraints.as_ref() {
-            Some(pointer_constraints) => pointer_constraints,
-            None if mode == CursorGrabMode::None => return Ok(()),
-            None => return Err(ExternalError::NotSupported(NotSupportedError::new())),
-        };
+        if self.pointer_constraints.is_none() {
+            return match mode {
+                CursorGrabMode::None => Ok(()),
+                _ => Err(ExternalError::NotSupported(NotSupportedError::new())),
+            };
+        }
 
         // Replace the current mode.
         let old_mode = std::mem::replace(&mut self.cursor_grab_mode.current_grab_mode, mode);
+        self.apply_cursor_grab(None, old_mode, mode);
 
-        match old_mode {
-            CursorGrabMode::None => (),
-            CursorGrabMode::Confined => self.apply_on_poiner(|_, data| {
-                data.unconfine_pointer();
-            }),
-            CursorGrabMode::Locked => {
-                self.apply_on_poiner(|_, data| data.unlock_pointer());
-            }
-        }
+        Ok(())
+    }
+
+    /// Replace the grab of the pointers of the seat, or of the pointers without a grab mode for
+    /// their seat when `None`.
+    fn apply_cursor_grab(&self, seat: Option<u32>, old_mode: CursorGrabMode, mode: CursorGrabMode) {
+        let pointer_constraints = match self.pointer_constraints.as_ref() {
+            Some(pointer_constraints) => pointer_constraints,
+            None => return,
+        };
 
         let surface = self.window.wl_surface();
-        match mode {
-            CursorGrabMode::Locked => self.apply_on_poiner(|pointer, data| {
-                let pointer = pointer.pointer();
-                data.lock_pointer(pointer_constraints, surface, pointer, &self.queue_handle)
-            }),
-            CursorGrabMode::Confined => self.apply_on_poiner(|pointer, data| {
-                let pointer = pointer.pointer();
-                data.confine_pointer(pointer_constraints, surface, pointer, &self.queue_handle)
-            }),
-            CursorGrabMode::None => {
-                // Current lock/confine was already removed.
+        self.apply_on_poiner(|pointer, data| {
+            let pointer_seat = data.seat().id().protocol_id();
+            let applies = match seat {
+                Some(seat) => seat == pointer_seat,
+                None => !self
+                    .cursor_grab_mode
+                    .seat_grab_modes
+                    .contains_key(&pointer_seat),
+            };
+            if !applies {
+                return;
             }
-        }
 
-        Ok(())
+            match old_mode {
+                CursorGrabMode::None => (),
+                CursorGrabMode::Confined => data.unconfine_pointer(),
+                CursorGrabMode::Locked => data.unlock_pointer(),
+            }
+
+            let pointer = pointer.pointer();
+            match mode {
+                CursorGrabMode::Locked => {
+                    data.lock_pointer(pointer_constraints, surface, pointer, &self.queue_handle)
+                }
+                CursorGrabMode::Confined => {
+                    data.confine_pointer(pointer_constraints, surface, pointer, &self.queue_handle)
+                }
+                CursorGrabMode::None => {
+                    // Current lock/confine was already removed.
+                }
+            }
+        })
     }
 
     /// Set the position of the cursor.
@@ -762,12 +1084,11 @@ impl WindowState {
         }
     }
 
-    /// Mark that the window has focus.
+    /// Mark that the seat focused the window, returning whether the window has focus changed.
     ///
     /// Should be used from routine that sends focused event.
-    #[inline]
-    pub fn set_has_focus(&mut self, has_focus: bool) {
-        self.has_focus = has_focus;
+    pub fn set_seat_focus(&mut self, seat: &WlSeat, focused: bool) -> bool {
+        self.focused_seats.set(seat.id().protocol_id(), focused)
     }
 
     /// Returns `true` if the requested state was applied.
@@ -821,10 +1142,59 @@ impl WindowState {
     #[inline]
     pub fn set_scale_factor(&mut self, scale_factor: f64) {
         self.scale_factor = scale_factor;
+        self.reload_buffer_scale();
+    }
 
-        // XXX when fractional scaling is not used update the buffer scale.
-        if self.fractional_scale.is_none() {
-            let _ = self.window.set_buffer_scale(self.scale_factor as _);
+    /// Update the buffer scale when fractional scaling is not used.
+    fn reload_buffer_scale(&self) {
+        if self.fractional_scale.is_some() {
+            return;
+        }
+
+        // The viewport takes care of scaling the buffers of the emulated mode.
+        let buffer_scale = if self.emulated_mode().is_some() {
+            1
+        } else {
+            self.scale_factor as i32
+        };
+        let _ = self.window.set_buffer_scale(buffer_scale as _);
+    }
+
+    /// Emulate the exclusive fullscreen `mode` by scaling mode sized buffers to the output.
+    ///
+    /// Returns `false` when the compositor doesn't support `wp_viewporter`.
+    pub fn set_fullscreen_mode(&mut self, mode: Option<VideoMode>) -> bool {
+        if mode.is_some() && self.viewport.is_none() {
+            self.fullscreen_mode = None;
+            return false;
+        }
+
+        self.fullscreen_mode = mode;
+        self.reload_buffer_scale();
+        true
+    }
+
+    /// The exclusive fullscreen mode being emulated, if the window is fullscreen.
+    pub fn emulated_mode(&self) -> Option<&VideoMode> {
+        let is_fullscreen = self
+            .last_configure
+            .as_ref()
+            .map(|configure| configure.is_fullscreen())
+            .unwrap_or_default();
+        self.fullscreen_mode.as_ref().filter(|_| is_fullscreen)
+    }
+
+    /// Convert the surface local `position` to the physical pixels of the surface content.
+    pub fn surface_to_physical(&self, position: LogicalPosition<f64>) -> PhysicalPosition<f64> {
+        match self.emulated_mode() {
+            Some(mode) => {
+                let mode_size = mode.size();
+                PhysicalPosition::new(
+                    position.x * mode_size.width as f64 / self.size.width as f64,
+                    position.y * mode_size.height as f64 / self.size.height as f64,
+                )
+            }
+            None => position.to_physical(self.scale_factor),
         }
     }
 
@@ -892,6 +1262,47 @@ impl Drop for WindowState {
     }
 }
 
+/// Snap the size down to the increments grid starting from the base size, then clamp it to the
+/// min and max sizes.
+fn snap_size(
+    size: LogicalSize<u32>,
+    base_size: LogicalSize<u32>,
+    increments: LogicalSize<u32>,
+    min_size: LogicalSize<u32>,
+    max_size: Option<LogicalSize<u32>>,
+) -> LogicalSize<u32> {
+    let snap = |size: u32, base: u32, increment: u32, min: u32, max: Option<u32>| {
+        let size = if increment == 0 || size <= base {
+            size
+        } else {
+            base + (size - base) / increment * increment
+        };
+        let size = max.map_or(size, |max| size.min(max));
+        size.max(min)
+    };
+
+    let min_size = LogicalSize::new(
+        min_size.width.max(MIN_WINDOW_SIZE.width),
+        min_size.height.max(MIN_WINDOW_SIZE.height),
+    );
+    LogicalSize::new(
+        snap(
+            size.width,
+            base_size.width,
+            increments.width,
+            min_size.width,
+            max_size.map(|size| size.width),
+        ),
+        snap(
+            size.height,
+            base_size.height,
+            increments.height,
+            min_size.height,
+            max_size.map(|size| size.height),
+        ),
+    )
+}
+
 impl From<ResizeDirection> for ResizeEdge {
     fn from(value: ResizeDirection) -> Self {
         match value {
@@ -907,12 +1318,70 @@ impl From<ResizeDirection> for ResizeEdge {
     }
 }
 
-// XXX rust doesn't allow from `Option`.
-#[cfg(feature = "sctk-adwaita")]
-fn into_sctk_adwaita_config(theme: Option<Theme>) -> sctk_adwaita::FrameConfig {
-    match theme {
-        Some(Theme::Light) => sctk_adwaita::FrameConfig::light(),
-        Some(Theme::Dark) => sctk_adwaita::FrameConfig::dark(),
-        None => sctk_adwaita::FrameConfig::auto(),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_to_increments_from_base_size() {
+        let size = snap_size(
+            LogicalSize::new(805, 603),
+            LogicalSize::new(4, 2),
+            LogicalSize::new(10, 20),
+            MIN_WINDOW_SIZE,
+            None,
+        );
+        assert_eq!(size, LogicalSize::new(804, 602));
+
+        // Without increments, the size is kept.
+        let size = snap_size(
+            LogicalSize::new(805, 603),
+            LogicalSize::new(4, 2),
+            LogicalSize::new(0, 0),
+            MIN_WINDOW_SIZE,
+            None,
+        );
+        assert_eq!(size, LogicalSize::new(805, 603));
+    }
+
+    #[test]
+    fn clamps_snapped_size() {
+        // Snapping down would go below the min size.
+        let size = snap_size(
+            LogicalSize::new(305, 215),
+            LogicalSize::new(0, 0),
+            LogicalSize::new(100, 100),
+            LogicalSize::new(250, 150),
+            None,
+        );
+        assert_eq!(size, LogicalSize::new(300, 200));
+        let size = snap_size(
+            LogicalSize::new(299, 199),
+            LogicalSize::new(0, 0),
+            LogicalSize::new(100, 100),
+            LogicalSize::new(250, 150),
+            None,
+        );
+        assert_eq!(size, LogicalSize::new(250, 150));
+
+        // The max size wins over the grid.
+        let size = snap_size(
+            LogicalSize::new(905, 905),
+            LogicalSize::new(0, 0),
+            LogicalSize::new(100, 100),
+            LogicalSize::new(1, 1),
+            Some(LogicalSize::new(850, 950)),
+        );
+        assert_eq!(size, LogicalSize::new(850, 900));
+
+        // The size never goes below the minimum of the window.
+        let size = snap_size(
+            LogicalSize::new(1, 1),
+            LogicalSize::new(0, 0),
+            LogicalSize::new(1, 1),
+            LogicalSize::new(0, 0),
+            None,
+        );
+        assert_eq!(size, MIN_WINDOW_SIZE);
     }
 }
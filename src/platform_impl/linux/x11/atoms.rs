@@ -39,6 +39,7 @@ atom_manager! {
     WM_CLIENT_MACHINE,
     WM_DELETE_WINDOW,
     WM_PROTOCOLS,
+    Manager: b"MANAGER",
     WM_STATE,
     XIM_SERVERS,
 
@@ -51,6 +52,7 @@ atom_manager! {
     _NET_WM_STATE,
     _NET_WM_STATE_ABOVE,
     _NET_WM_STATE_BELOW,
+    _NET_WM_STATE_FOCUSED,
     _NET_WM_STATE_FULLSCREEN,
     _NET_WM_STATE_HIDDEN,
     _NET_WM_STATE_MAXIMIZED_HORZ,
@@ -92,14 +94,41 @@ atom_manager! {
     TextUriList: b"text/uri-list",
     None: b"None",
 
+    // XInput2 valuator labels
+    AbsPressure: b"Abs Pressure",
+    AbsTiltX: b"Abs Tilt X",
+    AbsTiltY: b"Abs Tilt Y",
+    AbsDistance: b"Abs Distance",
+    AbsRotaryZ: b"Abs Rotary Z",
+    AbsThrottle: b"Abs Throttle",
+    AbsWheel: b"Abs Wheel",
+    AbsMtTouchMajor: b"Abs MT Touch Major",
+    AbsMtTouchMinor: b"Abs MT Touch Minor",
+    AbsMtOrientation: b"Abs MT Orientation",
+    AbsMtPressure: b"Abs MT Pressure",
+
+    // XInput2 device properties
+    LibinputTappingEnabled: b"libinput Tapping Enabled",
+    SynapticsTapAction: b"Synaptics Tap Action",
+    DeviceProductId: b"Device Product ID",
+
     // Miscellaneous Atoms
+    EdidProperty: b"EDID",
+    _GTK_EDGE_CONSTRAINTS,
+    _GTK_SHOW_WINDOW_MENU,
     _GTK_THEME_VARIANT,
     _MOTIF_WM_HINTS,
     _NET_ACTIVE_WINDOW,
     _NET_CLIENT_LIST,
+    _NET_CURRENT_DESKTOP,
     _NET_FRAME_EXTENTS,
     _NET_SUPPORTED,
-    _NET_SUPPORTING_WM_CHECK
+    _NET_SUPPORTING_WM_CHECK,
+    _NET_WM_FULLSCREEN_MONITORS,
+    _NET_WM_STRUT,
+    _NET_WM_STRUT_PARTIAL,
+    _NET_WORKAREA,
+    _XSETTINGS_SETTINGS
 }
 
 impl Index<AtomName> for Atoms {
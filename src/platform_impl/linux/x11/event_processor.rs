@@ -1,7 +1,8 @@
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::HashMap,
-    os::raw::{c_char, c_int, c_long, c_ulong},
+    mem,
+    os::raw::{c_char, c_int, c_long, c_uint, c_ulong},
     rc::Rc,
     slice,
     sync::Arc,
@@ -11,18 +12,21 @@ use x11rb::protocol::xproto::{self, ConnectionExt as _};
 use x11rb::x11_utils::Serialize;
 
 use super::{
-    atoms::*, ffi, get_xtarget, mkdid, mkwid, monitor, util, CookieResultExt, Device, DeviceId,
-    DeviceInfo, Dnd, DndState, GenericEventCookie, ImeReceiver, ScrollOrientation, UnownedWindow,
-    WindowId, XExtension,
+    atoms::*, device_seat, ffi, get_xtarget, mkdid, mkwid, monitor, tablet, util, CookieResultExt,
+    Device, DeviceInfo, Dnd, DndState, GenericEventCookie, ImeReceiver, ScrollOrientation,
+    TabletDevice, UnownedWindow, WindowId, XExtension,
 };
 
 use crate::platform_impl::platform::x11::ime::{ImeEvent, ImeEventReceiver, ImeRequest};
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
-    event::{DeviceEvent, ElementState, Event, Ime, RawKeyEvent, TouchPhase, WindowEvent},
+    event::{DeviceEvent, ElementState, Event, Ime, RawKeyEvent, SeatId, TouchPhase, WindowEvent},
     event_loop::EventLoopWindowTarget as RootELW,
     keyboard::ModifiersState,
+    monitor::MonitorHandle as RootMonitorHandle,
     platform_impl::platform::common::{keymap, xkb_state::KbdState},
+    platform_impl::platform::MonitorHandle as PlatformMonitorHandle,
+    window::Theme,
 };
 
 /// The X11 documentation states: "Keycodes lie in the inclusive range `[8, 255]`".
@@ -33,7 +37,11 @@ pub(super) struct EventProcessor<T: 'static> {
     pub(super) ime_receiver: ImeReceiver,
     pub(super) ime_event_receiver: ImeEventReceiver,
     pub(super) randr_event_offset: c_int,
-    pub(super) devices: RefCell<HashMap<DeviceId, Device>>,
+    /// The selection owned by the XSETTINGS manager.
+    pub(super) xsettings_selection: xproto::Atom,
+    /// The window of the current XSETTINGS manager.
+    pub(super) xsettings_owner: Cell<Option<xproto::Window>>,
+    pub(super) devices: RefCell<HashMap<c_int, Device>>,
     pub(super) xi2ext: XExtension,
     pub(super) xkbext: XExtension,
     pub(super) target: Rc<RootELW<T>>,
@@ -46,6 +54,8 @@ pub(super) struct EventProcessor<T: 'static> {
     // Used to detect key repeats.
     pub(super) held_key_press: Option<u32>,
     pub(super) first_touch: Option<u64>,
+    // The scale of the last pinch gesture event, since XInput2 sends the total scale
+    pub(super) pinch_scale: f64,
     // Currently focused window belonging to this process
     pub(super) active_window: Option<xproto::Window>,
     pub(super) is_composing: bool,
@@ -57,11 +67,26 @@ impl<T: 'static> EventProcessor<T> {
         let mut devices = self.devices.borrow_mut();
         if let Some(info) = DeviceInfo::get(&wt.xconn, device) {
             for info in info.iter() {
-                devices.insert(DeviceId(info.deviceid), Device::new(info));
+                devices.insert(info.deviceid, Device::new(info, &wt.xconn));
             }
         }
     }
 
+    /// The id of the device, along with its seat.
+    fn device_id(&self, device: c_int) -> crate::event::DeviceId {
+        mkdid(device, self.seat(device))
+    }
+
+    /// The seat of the device, which is the id of the master pointer it's part of.
+    fn seat(&self, device: c_int) -> Option<c_int> {
+        let devices = self.devices.borrow();
+        device_seat(device, |device| {
+            devices
+                .get(&device)
+                .map(|device| (device.device_use, device.attachment))
+        })
+    }
+
     pub(crate) fn with_window<F, Ret>(&self, window_id: xproto::Window, callback: F) -> Option<Ret>
     where
         F: Fn(&Arc<UnownedWindow>) -> Ret,
@@ -124,11 +149,203 @@ impl<T: 'static> EventProcessor<T> {
         result != 0
     }
 
-    pub(super) fn process_event<F>(&mut self, xev: &mut ffi::XEvent, mut callback: F)
+    /// Watch the new owner of the XSETTINGS selection and reload the settings.
+    fn update_xsettings_owner<F>(&self, callback: &mut F)
+    where
+        F: FnMut(Event<'_, T>),
+    {
+        let wt = get_xtarget(&self.target);
+        self.xsettings_owner
+            .set(wt.xconn.xsettings_owner(self.xsettings_selection));
+        self.reload_xsettings(callback);
+    }
+
+    fn reload_xsettings<F>(&self, callback: &mut F)
+    where
+        F: FnMut(Event<'_, T>),
+    {
+        let wt = get_xtarget(&self.target);
+        let settings = self
+            .xsettings_owner
+            .get()
+            .map(|owner| wt.xconn.read_xsettings(owner))
+            .unwrap_or_default();
+
+        let prev_theme = wt.xconn.system_theme();
+        let scale_factor_changed = {
+            let mut xsettings = wt.xconn.xsettings.lock().unwrap();
+            let prev_settings = mem::replace(&mut *xsettings, settings);
+            prev_settings.scale_factor() != xsettings.scale_factor()
+        };
+
+        if scale_factor_changed {
+            // The scale factor of the monitors depends on the settings.
+            self.process_monitor_changes(callback);
+        }
+
+        self.process_theme_change(prev_theme, callback);
+    }
+
+    /// Refresh the keyboard layouts of the event loop, and tell the focused window when they
+    /// changed.
+    fn update_keyboard_layouts<F>(&self, callback: &mut F)
+    where
+        F: FnMut(Event<'_, T>),
+    {
+        let wt = get_xtarget(&self.target);
+        let layouts = self.kb_state.layouts();
+        let active_layout = self.kb_state.active_layout();
+
+        let layouts_changed = *wt.keyboard_layouts.borrow() != layouts;
+        if !layouts_changed && wt.active_keyboard_layout.get() == active_layout {
+            return;
+        }
+        if layouts_changed {
+            *wt.keyboard_layouts.borrow_mut() = layouts.to_vec();
+        }
+        wt.active_keyboard_layout.set(active_layout);
+
+        if let (Some(window), Some(layout)) = (self.active_window, active_layout) {
+            callback(Event::WindowEvent {
+                window_id: mkwid(window),
+                event: WindowEvent::KeyboardLayoutChanged {
+                    device_id: self.device_id(util::VIRTUAL_CORE_KEYBOARD.into()),
+                    layout,
+                },
+            });
+        }
+    }
+
+    /// Update the windows following the system theme when it changed from `prev_theme`.
+    pub(super) fn process_theme_change<F>(&self, prev_theme: Option<Theme>, callback: &mut F)
+    where
+        F: FnMut(Event<'_, T>),
+    {
+        let wt = get_xtarget(&self.target);
+        let theme = wt.xconn.system_theme();
+        if theme == prev_theme {
+            return;
+        }
+
+        let windows: Vec<_> = wt
+            .windows
+            .borrow()
+            .values()
+            .filter_map(|window| window.upgrade())
+            .collect();
+        for window in windows {
+            if window.shared_state_lock().theme.is_some() {
+                continue;
+            }
+
+            window
+                .set_theme_inner(theme)
+                .expect_then_ignore_error("Failed to change window theme");
+            // Without preference, the windows fall back to the dark variant.
+            callback(Event::WindowEvent {
+                window_id: mkwid(window.id().0 as xproto::Window),
+                event: WindowEvent::ThemeChanged(theme.unwrap_or(Theme::Dark)),
+            });
+        }
+
+        wt.xconn
+            .flush_requests()
+            .expect("Failed to change window theme");
+    }
+
+    /// Diff the monitors against the cached list, reporting the changes and updating the scale
+    /// factor of the windows.
+    fn process_monitor_changes<F>(&self, callback: &mut F)
     where
         F: FnMut(Event<'_, T>),
     {
         let wt = get_xtarget(&self.target);
+        let prev_list = monitor::invalidate_cached_monitor_list();
+        if let Some(prev_list) = prev_list {
+            let new_list = wt.xconn.available_monitors();
+
+            for prev_monitor in &prev_list {
+                if !new_list
+                    .iter()
+                    .any(|monitor| monitor.name == prev_monitor.name)
+                {
+                    callback(Event::MonitorRemoved(RootMonitorHandle {
+                        inner: PlatformMonitorHandle::X(prev_monitor.clone()),
+                    }));
+                }
+            }
+
+            for new_monitor in new_list {
+                // Previous list may be empty, in case of disconnecting and
+                // reconnecting the only one monitor. We still need to emit events in
+                // this case.
+                let prev_monitor = prev_list
+                    .iter()
+                    .find(|prev_monitor| prev_monitor.name == new_monitor.name);
+                let maybe_prev_scale_factor =
+                    prev_monitor.map(|prev_monitor| prev_monitor.scale_factor);
+
+                let root_monitor = RootMonitorHandle {
+                    inner: PlatformMonitorHandle::X(new_monitor.clone()),
+                };
+                match prev_monitor {
+                    None => callback(Event::MonitorAdded(root_monitor)),
+                    Some(prev_monitor) if new_monitor.differs_from(prev_monitor) => {
+                        callback(Event::MonitorChanged(root_monitor))
+                    }
+                    Some(_) => (),
+                }
+
+                if Some(new_monitor.scale_factor) != maybe_prev_scale_factor {
+                    for (window_id, window) in wt.windows.borrow().iter() {
+                        if let Some(window) = window.upgrade() {
+                            // Check if the window is on this monitor
+                            let monitor = window.current_monitor();
+                            if monitor.name == new_monitor.name {
+                                let (width, height) = window.inner_size_physical();
+                                let (new_width, new_height) = window.adjust_for_dpi(
+                                    // If we couldn't determine the previous scale
+                                    // factor (e.g., because all monitors were closed
+                                    // before), just pick whatever the current monitor
+                                    // has set as a baseline.
+                                    maybe_prev_scale_factor.unwrap_or(monitor.scale_factor),
+                                    new_monitor.scale_factor,
+                                    width,
+                                    height,
+                                    &window.shared_state_lock(),
+                                );
+
+                                let window_id = crate::window::WindowId(*window_id);
+                                let old_inner_size = PhysicalSize::new(width, height);
+                                let mut new_inner_size = PhysicalSize::new(new_width, new_height);
+
+                                callback(Event::WindowEvent {
+                                    window_id,
+                                    event: WindowEvent::ScaleFactorChanged {
+                                        scale_factor: new_monitor.scale_factor,
+                                        new_inner_size: &mut new_inner_size,
+                                    },
+                                });
+
+                                if new_inner_size != old_inner_size {
+                                    let (new_width, new_height) = new_inner_size.into();
+                                    window.request_inner_size_physical(new_width, new_height);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub(super) fn process_event<F>(&mut self, xev: &mut ffi::XEvent, mut callback: F)
+    where
+        F: FnMut(Event<'_, T>),
+    {
+        // Don't borrow `self` through the target, the key events need it mutably.
+        let target = Rc::clone(&self.target);
+        let wt = get_xtarget(&target);
         let atoms = wt.x_connection().atoms();
         // XFilterEvent tells us when an event has been discarded by the input method.
         // Specifically, this involves all of the KeyPress events in compose/pre-edit sequences,
@@ -150,6 +367,14 @@ impl<T: 'static> EventProcessor<T> {
             ffi::ClientMessage => {
                 let client_msg: &ffi::XClientMessageEvent = xev.as_ref();
 
+                // A new XSETTINGS manager took over.
+                if client_msg.message_type as xproto::Atom == atoms[Manager]
+                    && client_msg.data.get_long(1) as xproto::Atom == self.xsettings_selection
+                {
+                    self.update_xsettings_owner(&mut callback);
+                    return;
+                }
+
                 let window = client_msg.window as xproto::Window;
                 let window_id = mkwid(window);
 
@@ -348,6 +573,9 @@ impl<T: 'static> EventProcessor<T> {
 
                         let resized =
                             util::maybe_change(&mut shared_state_lock.size, new_inner_size);
+                        if shared_state_lock.is_floating() {
+                            shared_state_lock.restored_size = Some(new_inner_size);
+                        }
                         let moved = if is_synthetic {
                             util::maybe_change(
                                 &mut shared_state_lock.inner_position,
@@ -406,6 +634,13 @@ impl<T: 'static> EventProcessor<T> {
                         outer
                     };
 
+                    {
+                        let mut shared_state_lock = window.shared_state_lock();
+                        if shared_state_lock.is_floating() {
+                            shared_state_lock.restored_position = Some(new_outer_position);
+                        }
+                    }
+
                     if is_synthetic {
                         let mut shared_state_lock = window.shared_state_lock();
                         // If we don't use the existing adjusted value when available, then the user can screw up the
@@ -483,6 +718,8 @@ impl<T: 'static> EventProcessor<T> {
                     drop(shared_state_lock);
 
                     if resized {
+                        window.recenter_locked_cursor();
+
                         callback(Event::WindowEvent {
                             window_id,
                             event: WindowEvent::Resized(new_inner_size.into()),
@@ -527,6 +764,11 @@ impl<T: 'static> EventProcessor<T> {
                 let xev: &ffi::XDestroyWindowEvent = xev.as_ref();
 
                 let window = xev.window as xproto::Window;
+
+                if Some(window) == self.xsettings_owner.get() {
+                    self.update_xsettings_owner(&mut callback);
+                    return;
+                }
                 let window_id = mkwid(window);
 
                 // In the event that the window's been destroyed without being dropped first, we
@@ -546,6 +788,31 @@ impl<T: 'static> EventProcessor<T> {
                 });
             }
 
+            ffi::PropertyNotify => {
+                let xev: &ffi::XPropertyEvent = xev.as_ref();
+                let xwindow = xev.window as xproto::Window;
+                let atom = xev.atom as xproto::Atom;
+
+                if Some(xwindow) == self.xsettings_owner.get() {
+                    if atom == atoms[_XSETTINGS_SETTINGS] {
+                        self.reload_xsettings(&mut callback);
+                    }
+                    return;
+                }
+
+                if atom == atoms[_NET_WM_STATE] || atom == atoms[_GTK_EDGE_CONSTRAINTS] {
+                    let event = self
+                        .with_window(xwindow, |window| window.update_window_state())
+                        .flatten();
+                    if let Some(event) = event {
+                        callback(Event::WindowEvent {
+                            window_id: mkwid(xwindow),
+                            event,
+                        });
+                    }
+                }
+            }
+
             ffi::VisibilityNotify => {
                 let xev: &ffi::XVisibilityEvent = xev.as_ref();
                 let xwindow = xev.window as xproto::Window;
@@ -571,8 +838,11 @@ impl<T: 'static> EventProcessor<T> {
                 }
             }
 
+            // The key events are reported by XInput2, only the ones forwarded by the input method
+            // come through the core protocol.
+            //
             // Note that in compose/pre-edit sequences, we'll always receive KeyRelease events
-            ty @ ffi::KeyPress | ty @ ffi::KeyRelease => {
+            ffi::KeyPress | ffi::KeyRelease => {
                 let xkev: &mut ffi::XKeyEvent = xev.as_mut();
 
                 // Set the timestamp.
@@ -583,74 +853,8 @@ impl<T: 'static> EventProcessor<T> {
                     None => return,
                 };
 
-                let window_id = mkwid(window);
-                let device_id = mkdid(util::VIRTUAL_CORE_KEYBOARD.into());
-
-                let keycode = xkev.keycode as _;
-
-                // Update state to track key repeats and determine whether this key was a repeat.
-                //
-                // Note, when a key is held before focusing on this window the first
-                // (non-synthetic) event will not be flagged as a repeat (also note that the
-                // synthetic press event that is generated before this when the window gains focus
-                // will also not be flagged as a repeat).
-                //
-                // Only keys that can repeat should change the held_key_press state since a
-                // continuously held repeatable key may continue repeating after the press of a
-                // non-repeatable key.
-                let repeat = if self.kb_state.key_repeats(keycode) {
-                    let is_latest_held = self.held_key_press == Some(keycode);
-
-                    if ty == ffi::KeyPress {
-                        self.held_key_press = Some(keycode);
-                        is_latest_held
-                    } else {
-                        // Check that the released key is the latest repeatable key that has been
-                        // pressed, since repeats will continue for the latest key press if a
-                        // different previously pressed key is released.
-                        if is_latest_held {
-                            self.held_key_press = None;
-                        }
-                        false
-                    }
-                } else {
-                    false
-                };
-
-                let state = if ty == ffi::KeyPress {
-                    ElementState::Pressed
-                } else {
-                    ElementState::Released
-                };
-
-                if keycode != 0 && !self.is_composing {
-                    let event = self.kb_state.process_key_event(keycode, state, repeat);
-                    callback(Event::WindowEvent {
-                        window_id,
-                        event: WindowEvent::KeyboardInput {
-                            device_id,
-                            event,
-                            is_synthetic: false,
-                        },
-                    });
-                } else if let Some(ic) = wt.ime.borrow().get_context(window as ffi::Window) {
-                    let written = wt.xconn.lookup_utf8(ic, xkev);
-                    if !written.is_empty() {
-                        let event = Event::WindowEvent {
-                            window_id,
-                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
-                        };
-                        callback(event);
-
-                        let event = Event::WindowEvent {
-                            window_id,
-                            event: WindowEvent::Ime(Ime::Commit(written)),
-                        };
-
-                        self.is_composing = false;
-                        callback(event);
-                    }
-                }
+                let device_id = self.device_id(util::VIRTUAL_CORE_KEYBOARD.into());
+                self.handle_key_event(window, xkev, device_id, &mut callback);
             }
 
             ffi::GenericEvent => {
@@ -668,10 +872,10 @@ impl<T: 'static> EventProcessor<T> {
                     ElementState::{Pressed, Released},
                     MouseButton::{Back, Forward, Left, Middle, Other, Right},
                     MouseScrollDelta::LineDelta,
-                    Touch,
+                    PenEvent, ScrollSource, Touch,
                     WindowEvent::{
                         AxisMotion, CursorEntered, CursorLeft, CursorMoved, Focused, MouseInput,
-                        MouseWheel,
+                        MouseWheel, PenInput,
                     },
                 };
 
@@ -679,7 +883,7 @@ impl<T: 'static> EventProcessor<T> {
                     ffi::XI_ButtonPress | ffi::XI_ButtonRelease => {
                         let xev: &ffi::XIDeviceEvent = unsafe { &*(xev.data as *const _) };
                         let window_id = mkwid(xev.event as xproto::Window);
-                        let device_id = mkdid(xev.deviceid);
+                        let device_id = self.device_id(xev.deviceid);
 
                         // Set the timestamp.
                         wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
@@ -694,6 +898,39 @@ impl<T: 'static> EventProcessor<T> {
                         } else {
                             Released
                         };
+
+                        let pen_event = match self
+                            .devices
+                            .borrow()
+                            .get(&xev.sourceid)
+                            .and_then(|device| device.tablet.as_ref())
+                        {
+                            Some(TabletDevice::Tool(tool)) => {
+                                let tool = tool.tool();
+                                match (xev.detail as u32, state) {
+                                    (ffi::Button1, Pressed) => Some(PenEvent::Down { tool }),
+                                    (ffi::Button1, Released) => Some(PenEvent::Up { tool }),
+                                    (button @ (ffi::Button2 | ffi::Button3), _) => {
+                                        Some(PenEvent::Button {
+                                            tool,
+                                            button: button - 1,
+                                            state,
+                                        })
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            Some(TabletDevice::Pad) => tablet::pad_button(xev.detail as u32)
+                                .map(|button| PenEvent::PadButton { button, state }),
+                            None => None,
+                        };
+                        if let Some(event) = pen_event {
+                            callback(Event::WindowEvent {
+                                window_id,
+                                event: PenInput { device_id, event },
+                            });
+                        }
+
                         match xev.detail as u32 {
                             ffi::Button1 => callback(Event::WindowEvent {
                                 window_id,
@@ -737,6 +974,16 @@ impl<T: 'static> EventProcessor<T> {
                                                 _ => unreachable!(),
                                             },
                                             phase: TouchPhase::Moved,
+                                            source: match xev.detail {
+                                                4 | 5 => ScrollSource::Wheel,
+                                                _ => ScrollSource::WheelTilt,
+                                            },
+                                            value120: match xev.detail {
+                                                4 => Some((0, 120)),
+                                                5 => Some((0, -120)),
+                                                6 => Some((120, 0)),
+                                                _ => Some((-120, 0)),
+                                            },
                                         },
                                     });
                                 }
@@ -775,15 +1022,42 @@ impl<T: 'static> EventProcessor<T> {
                         // Set the timestamp.
                         wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
 
-                        let device_id = mkdid(xev.deviceid);
+                        let device_id = self.device_id(xev.deviceid);
                         let window = xev.event as xproto::Window;
                         let window_id = mkwid(window);
                         let new_cursor_pos = (xev.event_x, xev.event_y);
 
-                        let cursor_moved = self.with_window(window, |window| {
-                            let mut shared_state_lock = window.shared_state_lock();
-                            util::maybe_change(&mut shared_state_lock.cursor_pos, new_cursor_pos)
-                        });
+                        let cursor_moved = self
+                            .with_window(window, |window| {
+                                let mut shared_state_lock = window.shared_state_lock();
+                                // The other master pointers move freely over the window.
+                                let locked_pos = shared_state_lock.locked_cursor_pos.filter(|_| {
+                                    shared_state_lock.locked_pointer.map(c_int::from)
+                                        == Some(xev.deviceid)
+                                });
+                                match locked_pos {
+                                    // The motion caused by warping the locked pointer back.
+                                    Some(locked_pos) if locked_pos == new_cursor_pos => None,
+                                    // The pointer is warped back to where it's locked, and its motion
+                                    // only reported by the raw events.
+                                    Some((x, y)) => {
+                                        drop(shared_state_lock);
+                                        if let Err(err) =
+                                            window.set_cursor_position_physical(x as i32, y as i32)
+                                        {
+                                            log::warn!(
+                                                "Failed to warp the locked pointer back: {err}"
+                                            );
+                                        }
+                                        Some(false)
+                                    }
+                                    None => Some(util::maybe_change(
+                                        &mut shared_state_lock.cursor_pos,
+                                        new_cursor_pos,
+                                    )),
+                                }
+                            })
+                            .flatten();
                         if cursor_moved == Some(true) {
                             let position = PhysicalPosition::new(xev.event_x, xev.event_y);
 
@@ -808,7 +1082,7 @@ impl<T: 'static> EventProcessor<T> {
                                 )
                             };
                             let mut devices = self.devices.borrow_mut();
-                            let physical_device = match devices.get_mut(&DeviceId(xev.sourceid)) {
+                            let physical_device = match devices.get_mut(&xev.sourceid) {
                                 Some(device) => device,
                                 None => return,
                             };
@@ -817,6 +1091,11 @@ impl<T: 'static> EventProcessor<T> {
                             for i in 0..xev.valuators.mask_len * 8 {
                                 if ffi::XIMaskIsSet(mask, i) {
                                     let x = unsafe { *value };
+                                    if let Some(TabletDevice::Tool(tool)) =
+                                        &mut physical_device.tablet
+                                    {
+                                        tool.update_axis(i, x);
+                                    }
                                     if let Some(&mut (_, ref mut info)) = physical_device
                                         .scroll_axes
                                         .iter_mut()
@@ -824,6 +1103,13 @@ impl<T: 'static> EventProcessor<T> {
                                     {
                                         let delta = (x - info.position) / info.increment;
                                         info.position = x;
+                                        let source = physical_device.scroll_source;
+                                        // The increment is a step of the wheel.
+                                        let value120 = (-delta * 120.).round() as i32;
+                                        let value120 = match info.orientation {
+                                            ScrollOrientation::Horizontal => (value120, 0),
+                                            ScrollOrientation::Vertical => (0, value120),
+                                        };
                                         events.push(Event::WindowEvent {
                                             window_id,
                                             event: MouseWheel {
@@ -838,6 +1124,9 @@ impl<T: 'static> EventProcessor<T> {
                                                     }
                                                 },
                                                 phase: TouchPhase::Moved,
+                                                source,
+                                                value120: (source == ScrollSource::Wheel)
+                                                    .then_some(value120),
                                             },
                                         });
                                     } else {
@@ -853,6 +1142,34 @@ impl<T: 'static> EventProcessor<T> {
                                     value = unsafe { value.offset(1) };
                                 }
                             }
+
+                            if let Some(TabletDevice::Tool(tool)) = &mut physical_device.tablet {
+                                let pen_tool = tool.tool();
+                                if tool.window != Some(window) {
+                                    tool.window = Some(window);
+                                    events.push(Event::WindowEvent {
+                                        window_id,
+                                        event: PenInput {
+                                            device_id,
+                                            event: PenEvent::ProximityIn { tool: pen_tool },
+                                        },
+                                    });
+                                }
+                                events.push(Event::WindowEvent {
+                                    window_id,
+                                    event: PenInput {
+                                        device_id,
+                                        event: PenEvent::Motion {
+                                            tool: pen_tool,
+                                            location: PhysicalPosition::new(
+                                                xev.event_x,
+                                                xev.event_y,
+                                            ),
+                                            axes: tool.axes,
+                                        },
+                                    },
+                                });
+                            }
                         }
                         for event in events {
                             callback(event);
@@ -867,7 +1184,7 @@ impl<T: 'static> EventProcessor<T> {
 
                         let window = xev.event as xproto::Window;
                         let window_id = mkwid(window);
-                        let device_id = mkdid(xev.deviceid);
+                        let device_id = self.device_id(xev.deviceid);
 
                         if let Some(all_info) = DeviceInfo::get(&wt.xconn, ffi::XIAllDevices) {
                             let mut devices = self.devices.borrow_mut();
@@ -879,8 +1196,7 @@ impl<T: 'static> EventProcessor<T> {
                                 // the virtual device.
                                 || device_info.attachment == xev.sourceid
                                 {
-                                    let device_id = DeviceId(device_info.deviceid);
-                                    if let Some(device) = devices.get_mut(&device_id) {
+                                    if let Some(device) = devices.get_mut(&device_info.deviceid) {
                                         device.reset_scroll_position(device_info);
                                     }
                                 }
@@ -915,14 +1231,85 @@ impl<T: 'static> EventProcessor<T> {
                         // been destroyed, which the user presumably doesn't want to deal with.
                         let window_closed = !self.window_exists(window);
                         if !window_closed {
+                            let pen_tool = match self
+                                .devices
+                                .borrow_mut()
+                                .get_mut(&xev.sourceid)
+                                .and_then(|device| device.tablet.as_mut())
+                            {
+                                Some(TabletDevice::Tool(tool)) if tool.window == Some(window) => {
+                                    tool.window = None;
+                                    Some(tool.tool())
+                                }
+                                _ => None,
+                            };
+                            if let Some(tool) = pen_tool {
+                                callback(Event::WindowEvent {
+                                    window_id: mkwid(window),
+                                    event: PenInput {
+                                        device_id: self.device_id(xev.deviceid),
+                                        event: PenEvent::ProximityOut { tool },
+                                    },
+                                });
+                            }
+
                             callback(Event::WindowEvent {
                                 window_id: mkwid(window),
                                 event: CursorLeft {
-                                    device_id: mkdid(xev.deviceid),
+                                    device_id: self.device_id(xev.deviceid),
                                 },
                             });
                         }
                     }
+                    ffi::XI_KeyPress | ffi::XI_KeyRelease => {
+                        let xev: &ffi::XIDeviceEvent = unsafe { &*(xev.data as *const _) };
+                        let window = xev.event as xproto::Window;
+
+                        // Set the timestamp.
+                        wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
+
+                        if !self.window_exists(window) {
+                            return;
+                        }
+
+                        // The input method only understands the core events, so convert it.
+                        let mut core_event = ffi::XEvent::from(ffi::XKeyEvent {
+                            type_: if xev.evtype == ffi::XI_KeyPress {
+                                ffi::KeyPress
+                            } else {
+                                ffi::KeyRelease
+                            },
+                            serial: xev.serial,
+                            send_event: xev.send_event,
+                            display: xev.display,
+                            window: xev.event,
+                            root: xev.root,
+                            subwindow: xev.child,
+                            time: xev.time,
+                            x: xev.event_x as c_int,
+                            y: xev.event_y as c_int,
+                            x_root: xev.root_x as c_int,
+                            y_root: xev.root_y as c_int,
+                            state: (xev.mods.effective as c_uint)
+                                | ((xev.group.effective as c_uint & 0x3) << 13),
+                            keycode: xev.detail as c_uint,
+                            same_screen: ffi::True,
+                        });
+                        let filtered =
+                            unsafe { (wt.xconn.xlib.XFilterEvent)(&mut core_event, xev.event) };
+                        if filtered == ffi::True {
+                            return;
+                        }
+
+                        // The device is the master keyboard of the seat.
+                        let device_id = self.device_id(xev.deviceid);
+                        self.handle_key_event(
+                            window,
+                            core_event.as_mut(),
+                            device_id,
+                            &mut callback,
+                        );
+                    }
                     ffi::XI_FocusIn => {
                         let xev: &ffi::XIFocusInEvent = unsafe { &*(xev.data as *const _) };
                         let window = xev.event as xproto::Window;
@@ -935,18 +1322,36 @@ impl<T: 'static> EventProcessor<T> {
                             .focus(xev.event)
                             .expect("Failed to focus input context");
 
-                        if self.active_window != Some(window) {
-                            self.active_window = Some(window);
+                        // The device is the master keyboard of the seat.
+                        let seat = self.seat(xev.deviceid);
+                        let keyboard_id = mkdid(xev.deviceid, seat);
+                        if let Some(seat) = seat {
+                            if self.window_exists(window) {
+                                callback(Event::WindowEvent {
+                                    window_id: mkwid(window),
+                                    event: WindowEvent::SeatFocused {
+                                        seat_id: SeatId(seat as u32),
+                                        focused: true,
+                                    },
+                                });
+                            }
+                        }
+
+                        // The window has focus as long as any of the seats focuses it.
+                        let focus_changed = self
+                            .with_window(window, |window| {
+                                let seat = seat.unwrap_or(xev.deviceid) as u32;
+                                window.shared_state_lock().focused_seats.set(seat, true)
+                            })
+                            .unwrap_or(false);
+                        self.active_window = Some(window);
 
+                        if focus_changed {
                             wt.update_listen_device_events(true);
 
                             let window_id = mkwid(window);
                             let position = PhysicalPosition::new(xev.event_x, xev.event_y);
 
-                            if let Some(window) = self.with_window(window, Arc::clone) {
-                                window.shared_state_lock().has_focus = true;
-                            }
-
                             callback(Event::WindowEvent {
                                 window_id,
                                 event: Focused(true),
@@ -966,14 +1371,14 @@ impl<T: 'static> EventProcessor<T> {
                             let pointer_id = self
                                 .devices
                                 .borrow()
-                                .get(&DeviceId(xev.deviceid))
+                                .get(&xev.deviceid)
                                 .map(|device| device.attachment)
                                 .unwrap_or(2);
 
                             callback(Event::WindowEvent {
                                 window_id,
                                 event: CursorMoved {
-                                    device_id: mkdid(pointer_id),
+                                    device_id: self.device_id(pointer_id),
                                     position,
                                 },
                             });
@@ -984,6 +1389,7 @@ impl<T: 'static> EventProcessor<T> {
                                 window_id,
                                 ElementState::Pressed,
                                 &mut self.kb_state,
+                                keyboard_id,
                                 &mut callback,
                             );
                         }
@@ -1004,8 +1410,30 @@ impl<T: 'static> EventProcessor<T> {
                             .unfocus(xev.event)
                             .expect("Failed to unfocus input context");
 
-                        if self.active_window.take() == Some(window) {
+                        let seat = self.seat(xev.deviceid);
+                        let keyboard_id = mkdid(xev.deviceid, seat);
+                        if let Some(seat) = seat {
+                            callback(Event::WindowEvent {
+                                window_id: mkwid(window),
+                                event: WindowEvent::SeatFocused {
+                                    seat_id: SeatId(seat as u32),
+                                    focused: false,
+                                },
+                            });
+                        }
+
+                        let focus_changed = self
+                            .with_window(window, |window| {
+                                let seat = seat.unwrap_or(xev.deviceid) as u32;
+                                window.shared_state_lock().focused_seats.set(seat, false)
+                            })
+                            .unwrap_or(false);
+
+                        if focus_changed {
                             let window_id = mkwid(window);
+                            if self.active_window == Some(window) {
+                                self.active_window = None;
+                            }
 
                             wt.update_listen_device_events(false);
 
@@ -1015,6 +1443,7 @@ impl<T: 'static> EventProcessor<T> {
                                 window_id,
                                 ElementState::Released,
                                 &mut self.kb_state,
+                                keyboard_id,
                                 &mut callback,
                             );
                             // Clear this so detecting key repeats is consistently handled when the
@@ -1028,10 +1457,6 @@ impl<T: 'static> EventProcessor<T> {
                                 ),
                             });
 
-                            if let Some(window) = self.with_window(window, Arc::clone) {
-                                window.shared_state_lock().has_focus = false;
-                            }
-
                             callback(Event::WindowEvent {
                                 window_id,
                                 event: Focused(false),
@@ -1064,25 +1489,141 @@ impl<T: 'static> EventProcessor<T> {
                                 callback(Event::WindowEvent {
                                     window_id,
                                     event: WindowEvent::CursorMoved {
-                                        device_id: mkdid(util::VIRTUAL_CORE_POINTER.into()),
+                                        device_id: self
+                                            .device_id(util::VIRTUAL_CORE_POINTER.into()),
                                         position: location.cast(),
                                     },
                                 });
                             }
 
+                            // The server sends the values of all the valuators of the touch.
+                            let (force, contact) = match self
+                                .devices
+                                .borrow()
+                                .get(&xev.sourceid)
+                                .and_then(|device| device.touch_axes.as_ref())
+                            {
+                                Some(touch_axes) => {
+                                    let mask = unsafe {
+                                        slice::from_raw_parts(
+                                            xev.valuators.mask,
+                                            xev.valuators.mask_len as usize,
+                                        )
+                                    };
+                                    let mut values = Vec::new();
+                                    let mut value = xev.valuators.values;
+                                    for i in 0..xev.valuators.mask_len * 8 {
+                                        if ffi::XIMaskIsSet(mask, i) {
+                                            values.push((i, unsafe { *value }));
+                                            value = unsafe { value.offset(1) };
+                                        }
+                                    }
+                                    touch_axes.read(|number| {
+                                        values
+                                            .iter()
+                                            .find(|&&(i, _)| i == number)
+                                            .map(|&(_, value)| value)
+                                    })
+                                }
+                                None => (None, None),
+                            };
+
                             callback(Event::WindowEvent {
                                 window_id,
                                 event: WindowEvent::Touch(Touch {
-                                    device_id: mkdid(xev.deviceid),
+                                    device_id: self.device_id(xev.deviceid),
                                     phase,
                                     location,
-                                    force: None, // TODO
+                                    force,
+                                    contact,
                                     id,
                                 }),
                             })
                         }
                     }
 
+                    ffi::XI_GesturePinchBegin
+                    | ffi::XI_GesturePinchUpdate
+                    | ffi::XI_GesturePinchEnd => {
+                        let xev: &ffi::XIGesturePinchEvent = unsafe { &*(xev.data as *const _) };
+
+                        // Set the timestamp.
+                        wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
+
+                        let window = xev.event as xproto::Window;
+                        if !self.window_exists(window) {
+                            return;
+                        }
+
+                        let (magnify, rotate, phase) = match xev.evtype {
+                            ffi::XI_GesturePinchBegin => {
+                                self.pinch_scale = xev.scale;
+                                (0., 0., TouchPhase::Started)
+                            }
+                            ffi::XI_GesturePinchUpdate => {
+                                let magnify = xev.scale - self.pinch_scale;
+                                self.pinch_scale = xev.scale;
+                                // The angle is clockwise, in degrees.
+                                (magnify, -xev.delta_angle as f32, TouchPhase::Moved)
+                            }
+                            _ if xev.flags & ffi::XIGesturePinchEventCancelled != 0 => {
+                                (0., 0., TouchPhase::Cancelled)
+                            }
+                            _ => (0., 0., TouchPhase::Ended),
+                        };
+
+                        let window_id = mkwid(window);
+                        let device_id = self.device_id(xev.deviceid);
+                        callback(Event::WindowEvent {
+                            window_id,
+                            event: WindowEvent::TouchpadMagnify {
+                                device_id,
+                                delta: magnify,
+                                phase,
+                            },
+                        });
+                        callback(Event::WindowEvent {
+                            window_id,
+                            event: WindowEvent::TouchpadRotate {
+                                device_id,
+                                delta: rotate,
+                                phase,
+                            },
+                        });
+                    }
+                    ffi::XI_GestureSwipeBegin
+                    | ffi::XI_GestureSwipeUpdate
+                    | ffi::XI_GestureSwipeEnd => {
+                        let xev: &ffi::XIGestureSwipeEvent = unsafe { &*(xev.data as *const _) };
+
+                        // Set the timestamp.
+                        wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
+
+                        let window = xev.event as xproto::Window;
+                        if !self.window_exists(window) {
+                            return;
+                        }
+
+                        let phase = match xev.evtype {
+                            ffi::XI_GestureSwipeBegin => TouchPhase::Started,
+                            ffi::XI_GestureSwipeUpdate => TouchPhase::Moved,
+                            _ if xev.flags & ffi::XIGestureSwipeEventCancelled != 0 => {
+                                TouchPhase::Cancelled
+                            }
+                            _ => TouchPhase::Ended,
+                        };
+
+                        callback(Event::WindowEvent {
+                            window_id: mkwid(window),
+                            event: WindowEvent::SwipeGesture {
+                                device_id: self.device_id(xev.deviceid),
+                                fingers: xev.detail as u32,
+                                delta: PhysicalPosition::new(xev.delta_x, xev.delta_y),
+                                phase,
+                            },
+                        });
+                    }
+
                     ffi::XI_RawButtonPress | ffi::XI_RawButtonRelease => {
                         let xev: &ffi::XIRawEvent = unsafe { &*(xev.data as *const _) };
 
@@ -1091,7 +1632,7 @@ impl<T: 'static> EventProcessor<T> {
 
                         if xev.flags & ffi::XIPointerEmulated == 0 {
                             callback(Event::DeviceEvent {
-                                device_id: mkdid(xev.deviceid),
+                                device_id: self.device_id(xev.deviceid),
                                 event: DeviceEvent::Button {
                                     button: xev.detail as u32,
                                     state: match xev.evtype {
@@ -1110,7 +1651,7 @@ impl<T: 'static> EventProcessor<T> {
                         // Set the timestamp.
                         wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
 
-                        let did = mkdid(xev.deviceid);
+                        let did = self.device_id(xev.deviceid);
 
                         let mask = unsafe {
                             slice::from_raw_parts(
@@ -1118,17 +1659,22 @@ impl<T: 'static> EventProcessor<T> {
                                 xev.valuators.mask_len as usize,
                             )
                         };
+                        // The valuators hold the values after the pointer acceleration, and the raw
+                        // values the ones reported by the device.
                         let mut value = xev.raw_values;
+                        let mut accel_value = xev.valuators.values;
                         let mut mouse_delta = (0.0, 0.0);
+                        let mut unaccel_delta = (0.0, 0.0);
                         let mut scroll_delta = (0.0, 0.0);
                         for i in 0..xev.valuators.mask_len * 8 {
                             if ffi::XIMaskIsSet(mask, i) {
                                 let x = unsafe { *value };
+                                let accel_x = unsafe { *accel_value };
                                 // We assume that every XInput2 device with analog axes is a pointing device emitting
                                 // relative coordinates.
                                 match i {
-                                    0 => mouse_delta.0 = x,
-                                    1 => mouse_delta.1 = x,
+                                    0 => (mouse_delta.0, unaccel_delta.0) = (accel_x, x),
+                                    1 => (mouse_delta.1, unaccel_delta.1) = (accel_x, x),
                                     2 => scroll_delta.0 = x as f32,
                                     3 => scroll_delta.1 = x as f32,
                                     _ => {}
@@ -1141,12 +1687,16 @@ impl<T: 'static> EventProcessor<T> {
                                     },
                                 });
                                 value = unsafe { value.offset(1) };
+                                accel_value = unsafe { accel_value.offset(1) };
                             }
                         }
-                        if mouse_delta != (0.0, 0.0) {
+                        if mouse_delta != (0.0, 0.0) || unaccel_delta != (0.0, 0.0) {
                             callback(Event::DeviceEvent {
                                 device_id: did,
-                                event: DeviceEvent::MouseMotion { delta: mouse_delta },
+                                event: DeviceEvent::MouseMotion {
+                                    delta: mouse_delta,
+                                    unaccelerated_delta: Some(unaccel_delta),
+                                },
                             });
                         }
                         if scroll_delta != (0.0, 0.0) {
@@ -1170,7 +1720,7 @@ impl<T: 'static> EventProcessor<T> {
                             _ => unreachable!(),
                         };
 
-                        let device_id = mkdid(xev.sourceid);
+                        let device_id = self.device_id(xev.sourceid);
                         let keycode = xev.detail as u32;
                         if keycode < KEYCODE_OFFSET as u32 {
                             return;
@@ -1198,17 +1748,17 @@ impl<T: 'static> EventProcessor<T> {
                             if 0 != info.flags & (ffi::XISlaveAdded | ffi::XIMasterAdded) {
                                 self.init_device(info.deviceid);
                                 callback(Event::DeviceEvent {
-                                    device_id: mkdid(info.deviceid),
+                                    device_id: self.device_id(info.deviceid),
                                     event: DeviceEvent::Added,
                                 });
                             } else if 0 != info.flags & (ffi::XISlaveRemoved | ffi::XIMasterRemoved)
                             {
                                 callback(Event::DeviceEvent {
-                                    device_id: mkdid(info.deviceid),
+                                    device_id: self.device_id(info.deviceid),
                                     event: DeviceEvent::Removed,
                                 });
                                 let mut devices = self.devices.borrow_mut();
-                                devices.remove(&DeviceId(info.deviceid));
+                                devices.remove(&info.deviceid);
                             }
                         }
                     }
@@ -1240,6 +1790,20 @@ impl<T: 'static> EventProcessor<T> {
                                 && (keycodes_changed || geometry_changed)
                             {
                                 unsafe { self.kb_state.init_with_x11_keymap() };
+                                self.update_keyboard_layouts(&mut callback);
+                            }
+                        }
+                        // The keymap of the same keyboard changed, e.g. `setxkbmap -layout us,ru`.
+                        ffi::XkbMapNotify => {
+                            let xev =
+                                unsafe { &*(xev as *const _ as *const ffi::XkbMapNotifyEvent) };
+
+                            // Set the timestamp.
+                            wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
+
+                            if xev.device == self.kb_state.core_keyboard_id {
+                                unsafe { self.kb_state.init_with_x11_keymap() };
+                                self.update_keyboard_layouts(&mut callback);
                             }
                         }
                         ffi::XkbStateNotify => {
@@ -1269,68 +1833,14 @@ impl<T: 'static> EventProcessor<T> {
                                     });
                                 }
                             }
+
+                            self.update_keyboard_layouts(&mut callback);
                         }
                         _ => {}
                     }
                 }
                 if event_type == self.randr_event_offset {
-                    // In the future, it would be quite easy to emit monitor hotplug events.
-                    let prev_list = monitor::invalidate_cached_monitor_list();
-                    if let Some(prev_list) = prev_list {
-                        let new_list = wt.xconn.available_monitors();
-                        for new_monitor in new_list {
-                            // Previous list may be empty, in case of disconnecting and
-                            // reconnecting the only one monitor. We still need to emit events in
-                            // this case.
-                            let maybe_prev_scale_factor = prev_list
-                                .iter()
-                                .find(|prev_monitor| prev_monitor.name == new_monitor.name)
-                                .map(|prev_monitor| prev_monitor.scale_factor);
-                            if Some(new_monitor.scale_factor) != maybe_prev_scale_factor {
-                                for (window_id, window) in wt.windows.borrow().iter() {
-                                    if let Some(window) = window.upgrade() {
-                                        // Check if the window is on this monitor
-                                        let monitor = window.current_monitor();
-                                        if monitor.name == new_monitor.name {
-                                            let (width, height) = window.inner_size_physical();
-                                            let (new_width, new_height) = window.adjust_for_dpi(
-                                                // If we couldn't determine the previous scale
-                                                // factor (e.g., because all monitors were closed
-                                                // before), just pick whatever the current monitor
-                                                // has set as a baseline.
-                                                maybe_prev_scale_factor
-                                                    .unwrap_or(monitor.scale_factor),
-                                                new_monitor.scale_factor,
-                                                width,
-                                                height,
-                                                &window.shared_state_lock(),
-                                            );
-
-                                            let window_id = crate::window::WindowId(*window_id);
-                                            let old_inner_size = PhysicalSize::new(width, height);
-                                            let mut new_inner_size =
-                                                PhysicalSize::new(new_width, new_height);
-
-                                            callback(Event::WindowEvent {
-                                                window_id,
-                                                event: WindowEvent::ScaleFactorChanged {
-                                                    scale_factor: new_monitor.scale_factor,
-                                                    new_inner_size: &mut new_inner_size,
-                                                },
-                                            });
-
-                                            if new_inner_size != old_inner_size {
-                                                let (new_width, new_height) = new_inner_size.into();
-                                                window.request_inner_size_physical(
-                                                    new_width, new_height,
-                                                );
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+                    self.process_monitor_changes(&mut callback);
                 }
             }
         }
@@ -1353,25 +1863,36 @@ impl<T: 'static> EventProcessor<T> {
             Err(_) => return,
         };
 
+        // XIM only handles the core keyboard.
+        let seat_id = Some(SeatId(util::VIRTUAL_CORE_POINTER.into()));
         match event {
             ImeEvent::Enabled => {
                 callback(Event::WindowEvent {
                     window_id: mkwid(window),
-                    event: WindowEvent::Ime(Ime::Enabled),
+                    event: WindowEvent::Ime {
+                        seat_id,
+                        event: Ime::Enabled,
+                    },
                 });
             }
             ImeEvent::Start => {
                 self.is_composing = true;
                 callback(Event::WindowEvent {
                     window_id: mkwid(window),
-                    event: WindowEvent::Ime(Ime::Preedit("".to_owned(), None)),
+                    event: WindowEvent::Ime {
+                        seat_id,
+                        event: Ime::Preedit("".to_owned(), None),
+                    },
                 });
             }
             ImeEvent::Update(text, position) => {
                 if self.is_composing {
                     callback(Event::WindowEvent {
                         window_id: mkwid(window),
-                        event: WindowEvent::Ime(Ime::Preedit(text, Some((position, position)))),
+                        event: WindowEvent::Ime {
+                            seat_id,
+                            event: Ime::Preedit(text, Some((position, position))),
+                        },
                     });
                 }
             }
@@ -1380,30 +1901,121 @@ impl<T: 'static> EventProcessor<T> {
                 // Issue empty preedit on `Done`.
                 callback(Event::WindowEvent {
                     window_id: mkwid(window),
-                    event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                    event: WindowEvent::Ime {
+                        seat_id,
+                        event: Ime::Preedit(String::new(), None),
+                    },
                 });
             }
             ImeEvent::Disabled => {
                 self.is_composing = false;
                 callback(Event::WindowEvent {
                     window_id: mkwid(window),
-                    event: WindowEvent::Ime(Ime::Disabled),
+                    event: WindowEvent::Ime {
+                        seat_id,
+                        event: Ime::Disabled,
+                    },
                 });
             }
         }
     }
 
+    /// Process a key event of the `device_id` keyboard on the window.
+    fn handle_key_event<F>(
+        &mut self,
+        window: xproto::Window,
+        xkev: &mut ffi::XKeyEvent,
+        device_id: crate::event::DeviceId,
+        callback: &mut F,
+    ) where
+        F: FnMut(Event<'_, T>),
+    {
+        let wt = get_xtarget(&self.target);
+        let window_id = mkwid(window);
+        let ty = xkev.type_;
+        let keycode = xkev.keycode as _;
+
+        // Update state to track key repeats and determine whether this key was a repeat.
+        //
+        // Note, when a key is held before focusing on this window the first
+        // (non-synthetic) event will not be flagged as a repeat (also note that the
+        // synthetic press event that is generated before this when the window gains focus
+        // will also not be flagged as a repeat).
+        //
+        // Only keys that can repeat should change the held_key_press state since a
+        // continuously held repeatable key may continue repeating after the press of a
+        // non-repeatable key.
+        let repeat = if self.kb_state.key_repeats(keycode) {
+            let is_latest_held = self.held_key_press == Some(keycode);
+
+            if ty == ffi::KeyPress {
+                self.held_key_press = Some(keycode);
+                is_latest_held
+            } else {
+                // Check that the released key is the latest repeatable key that has been
+                // pressed, since repeats will continue for the latest key press if a
+                // different previously pressed key is released.
+                if is_latest_held {
+                    self.held_key_press = None;
+                }
+                false
+            }
+        } else {
+            false
+        };
+
+        let state = if ty == ffi::KeyPress {
+            ElementState::Pressed
+        } else {
+            ElementState::Released
+        };
+
+        if keycode != 0 && !self.is_composing {
+            let event = self.kb_state.process_key_event(keycode, state, repeat);
+            callback(Event::WindowEvent {
+                window_id,
+                event: WindowEvent::KeyboardInput {
+                    device_id,
+                    event,
+                    is_synthetic: false,
+                },
+            });
+        } else if let Some(ic) = wt.ime.borrow().get_context(window as ffi::Window) {
+            let written = wt.xconn.lookup_utf8(ic, xkev);
+            if !written.is_empty() {
+                let event = Event::WindowEvent {
+                    window_id,
+                    event: WindowEvent::Ime {
+                        seat_id: device_id.seat_id(),
+                        event: Ime::Preedit(String::new(), None),
+                    },
+                };
+                callback(event);
+
+                let event = Event::WindowEvent {
+                    window_id,
+                    event: WindowEvent::Ime {
+                        seat_id: device_id.seat_id(),
+                        event: Ime::Commit(written),
+                    },
+                };
+
+                self.is_composing = false;
+                callback(event);
+            }
+        }
+    }
+
     fn handle_pressed_keys<F>(
         wt: &super::EventLoopWindowTarget<T>,
         window_id: crate::window::WindowId,
         state: ElementState,
         kb_state: &mut KbdState,
+        device_id: crate::event::DeviceId,
         callback: &mut F,
     ) where
         F: FnMut(Event<'_, T>),
     {
-        let device_id = mkdid(util::VIRTUAL_CORE_KEYBOARD.into());
-
         // Update modifiers state and emit key events based on which keys are currently pressed.
         for keycode in wt
             .xconn
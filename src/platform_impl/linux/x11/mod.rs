@@ -7,6 +7,7 @@ mod event_processor;
 pub mod ffi;
 mod ime;
 mod monitor;
+mod tablet;
 pub mod util;
 mod window;
 mod xdisplay;
@@ -47,7 +48,7 @@ use atoms::*;
 use raw_window_handle::{RawDisplayHandle, XlibDisplayHandle};
 
 use x11rb::protocol::{
-    xinput,
+    xinput::{self, ConnectionExt as _},
     xproto::{self, ConnectionExt},
 };
 use x11rb::x11_utils::X11Error as LogicalError;
@@ -60,17 +61,23 @@ use self::{
     dnd::{Dnd, DndState},
     event_processor::EventProcessor,
     ime::{Ime, ImeCreationError, ImeReceiver, ImeRequest, ImeSender},
+    tablet::TabletDevice,
 };
+#[cfg(gamepad_platform)]
+use super::common::gamepad::{self, Gamepads};
+#[cfg(feature = "xdg-portal")]
+use super::common::xdg_portal;
 use super::common::xkb_state::KbdState;
 use crate::{
     error::OsError as RootOsError,
-    event::{Event, StartCause},
+    event::{DeviceKind, Event, Force, ScrollSource, SeatId, StartCause, TouchContact},
     event_loop::{ControlFlow, DeviceEvents, EventLoopClosed, EventLoopWindowTarget as RootELW},
+    keyboard::LayoutInfo,
     platform_impl::{
         platform::{sticky_exit_callback, WindowId},
         PlatformSpecificWindowBuilderAttributes,
     },
-    window::WindowAttributes,
+    window::{Theme, WindowAttributes},
 };
 
 type X11Source = Generic<RawFd>;
@@ -86,6 +93,13 @@ pub struct EventLoopWindowTarget<T> {
     redraw_sender: Sender<WindowId>,
     activation_sender: Sender<ActivationToken>,
     device_events: Cell<DeviceEvents>,
+    // Whether the server sends the touchpad gestures of XInput 2.4.
+    xi2_gestures: bool,
+    // The keyboard layouts, kept up to date by the event processor.
+    keyboard_layouts: RefCell<Vec<LayoutInfo>>,
+    active_keyboard_layout: Cell<Option<usize>>,
+    #[cfg(gamepad_platform)]
+    gamepads: RefCell<Option<Gamepads>>,
     _marker: ::std::marker::PhantomData<T>,
 }
 
@@ -113,6 +127,9 @@ struct EventLoopState<T> {
 
     /// Incoming activation tokens.
     activation_tokens: VecDeque<ActivationToken>,
+
+    /// The latest color scheme reported by the XDG desktop portal.
+    portal_theme: Option<Option<Theme>>,
 }
 
 pub struct EventLoopProxy<T: 'static> {
@@ -175,6 +192,35 @@ impl<T: 'static> EventLoop<T> {
             .select_xrandr_input(root as ffi::Window)
             .expect("Failed to query XRandR extension");
 
+        // Watch the XSETTINGS manager, which is announced on the root window, before computing
+        // the scale factors of the monitors.
+        let xsettings_selection = xconn
+            .xsettings_selection()
+            .expect("Failed to intern the XSETTINGS selection atom");
+        // The event mask is per client, so keep the events already selected on the root window.
+        let root_event_mask = xconn
+            .xcb_connection()
+            .get_window_attributes(root)
+            .expect("Failed to query the root window attributes")
+            .reply()
+            .expect("Failed to query the root window attributes")
+            .your_event_mask;
+        xconn
+            .xcb_connection()
+            .change_window_attributes(
+                root,
+                &xproto::ChangeWindowAttributesAux::new()
+                    .event_mask(root_event_mask | xproto::EventMask::STRUCTURE_NOTIFY),
+            )
+            .expect_then_ignore_error("Failed to select events on the root window");
+        let xsettings_owner = xconn.xsettings_owner(xsettings_selection);
+        if let Some(owner) = xsettings_owner {
+            *xconn.xsettings.lock().unwrap() = xconn.read_xsettings(owner);
+        }
+
+        // Populate the monitor cache, so RandR notifications can be diffed against it.
+        xconn.available_monitors();
+
         let xi2ext = unsafe {
             let mut ext = XExtension::default();
 
@@ -223,9 +269,11 @@ impl<T: 'static> EventLoop<T> {
             ext
         };
 
-        unsafe {
+        // Ask for XInput 2.4 for the touchpad gestures, the server answers with the version it
+        // supports.
+        let xi2_gestures = unsafe {
             let mut xinput_major_ver = ffi::XI_2_Major;
-            let mut xinput_minor_ver = ffi::XI_2_Minor;
+            let mut xinput_minor_ver = 4;
             if (xconn.xinput2.XIQueryVersion)(
                 xconn.display,
                 &mut xinput_major_ver,
@@ -236,7 +284,8 @@ impl<T: 'static> EventLoop<T> {
                     "X server has XInput extension {xinput_major_ver}.{xinput_minor_ver} but does not support XInput2",
                 );
             }
-        }
+            (xinput_major_ver, xinput_minor_ver) >= (2, 4)
+        };
 
         xconn.update_cached_wm_info(root);
 
@@ -295,6 +344,35 @@ impl<T: 'static> EventLoop<T> {
             .register_dispatcher(activation_tokens.clone())
             .expect("Failed to register the activation token channel with the event loop");
 
+        // Follow the color scheme of the desktop.
+        #[cfg(feature = "xdg-portal")]
+        {
+            let (theme_sender, theme_channel) = channel();
+            handle
+                .insert_source(theme_channel, |ev, _, state| {
+                    if let ChanResult::Msg(theme) = ev {
+                        state.portal_theme = Some(theme);
+                    }
+                })
+                .expect("Failed to register the theme channel with the event loop");
+            xdg_portal::watch_session_color_scheme(move |theme| theme_sender.send(theme).is_ok());
+        }
+
+        // Read the gamepads, their events are processed along with the X11 events.
+        #[cfg(gamepad_platform)]
+        let gamepads = gamepad::watch_gamepads();
+        #[cfg(gamepad_platform)]
+        if let Some(gamepads) = gamepads.as_ref() {
+            let source = Generic::new(
+                gamepads.as_raw_fd(),
+                calloop::Interest::READ,
+                calloop::Mode::Level,
+            );
+            handle
+                .insert_source(source, |_, _, _| Ok(calloop::PostAction::Continue))
+                .expect("Failed to register the gamepads with the event loop");
+        }
+
         let kb_state =
             KbdState::from_x11_xkb(xconn.xcb_connection().get_raw_xcb_connection()).unwrap();
 
@@ -310,6 +388,11 @@ impl<T: 'static> EventLoop<T> {
             redraw_sender,
             activation_sender: activation_token_sender,
             device_events: Default::default(),
+            xi2_gestures,
+            keyboard_layouts: RefCell::new(kb_state.layouts().to_vec()),
+            active_keyboard_layout: Cell::new(kb_state.active_layout()),
+            #[cfg(gamepad_platform)]
+            gamepads: RefCell::new(gamepads),
         };
 
         // Set initial device event filter.
@@ -325,6 +408,8 @@ impl<T: 'static> EventLoop<T> {
             dnd,
             devices: Default::default(),
             randr_event_offset,
+            xsettings_selection,
+            xsettings_owner: Cell::new(xsettings_owner),
             ime_receiver,
             ime_event_receiver,
             xi2ext,
@@ -333,6 +418,7 @@ impl<T: 'static> EventLoop<T> {
             num_touch: 0,
             held_key_press: None,
             first_touch: None,
+            pinch_scale: 1.,
             active_window: None,
             is_composing: false,
         };
@@ -352,7 +438,7 @@ impl<T: 'static> EventLoop<T> {
             .xconn
             .select_xkb_events(
                 0x100, // Use the "core keyboard device"
-                ffi::XkbNewKeyboardNotifyMask | ffi::XkbStateNotifyMask,
+                ffi::XkbNewKeyboardNotifyMask | ffi::XkbMapNotifyMask | ffi::XkbStateNotifyMask,
             )
             .unwrap();
 
@@ -368,6 +454,7 @@ impl<T: 'static> EventLoop<T> {
                 user_events: VecDeque::new(),
                 redraw_events: VecDeque::new(),
                 activation_tokens: VecDeque::new(),
+                portal_theme: None,
             },
         }
     }
@@ -421,6 +508,33 @@ impl<T: 'static> EventLoop<T> {
             // Process all pending events
             this.drain_events(callback, control_flow);
 
+            #[cfg(gamepad_platform)]
+            if let Some(gamepads) = get_xtarget(&this.target).gamepads.borrow_mut().as_mut() {
+                let filter = get_xtarget(&this.target).device_events.get();
+                let focused = this.event_processor.active_window.is_some();
+                gamepads.dispatch(|device_id, event| {
+                    if gamepad::is_listened(&event, filter, focused) {
+                        sticky_exit_callback(
+                            crate::event::Event::DeviceEvent { device_id, event },
+                            &this.target,
+                            control_flow,
+                            callback,
+                        )
+                    }
+                });
+            }
+
+            // Update the windows following the color scheme of the desktop.
+            if let Some(theme) = this.state.portal_theme.take() {
+                let xconn = &get_xtarget(&this.target).xconn;
+                let prev_theme = xconn.system_theme();
+                *xconn.portal_theme.lock().unwrap() = theme;
+                this.event_processor
+                    .process_theme_change(prev_theme, &mut |event| {
+                        sticky_exit_callback(event, &this.target, control_flow, callback)
+                    });
+            }
+
             // Empty activation tokens.
             while let Some((window_id, serial)) = this.state.activation_tokens.pop_front() {
                 let token = this
@@ -603,6 +717,10 @@ impl<T: 'static> EventLoop<T> {
             &self.target,
             &mut control_flow,
         );
+
+        // Don't leave the gamma ramps changed by the application behind.
+        get_xtarget(&self.target).xconn.restore_crtc_gamma();
+
         exit_code
     }
 
@@ -657,10 +775,84 @@ impl<T> EventLoopWindowTarget<T> {
         &self.xconn
     }
 
+    #[cfg(gamepad_platform)]
+    pub fn gamepads(&self) -> std::cell::RefMut<'_, Option<Gamepads>> {
+        self.gamepads.borrow_mut()
+    }
+
     pub fn set_listen_device_events(&self, allowed: DeviceEvents) {
         self.device_events.set(allowed);
     }
 
+    #[inline]
+    pub fn keyboard_layouts(&self) -> Vec<LayoutInfo> {
+        self.keyboard_layouts.borrow().clone()
+    }
+
+    #[inline]
+    pub fn active_keyboard_layout(&self) -> Option<usize> {
+        self.active_keyboard_layout.get()
+    }
+
+    /// Lists the input devices, along with the master devices.
+    pub fn devices(&self) -> Vec<crate::event::DeviceInfo> {
+        let all_info = match DeviceInfo::get(&self.xconn, ffi::XIAllDevices) {
+            Some(all_info) => all_info,
+            None => return Vec::new(),
+        };
+
+        let seats: HashMap<c_int, String> = all_info
+            .iter()
+            .filter(|info| info._use == ffi::XIMasterPointer || info._use == ffi::XIMasterKeyboard)
+            .map(|info| (info.deviceid, seat_name(info)))
+            .collect();
+
+        all_info
+            .iter()
+            .map(|info| {
+                let seat = match info._use {
+                    ffi::XIMasterPointer | ffi::XIMasterKeyboard => seats.get(&info.deviceid),
+                    ffi::XIFloatingSlave => None,
+                    _ => seats.get(&info.attachment),
+                };
+                let seat_id = device_seat(info.deviceid, |device| {
+                    all_info
+                        .iter()
+                        .find(|info| info.deviceid == device)
+                        .map(|info| (info._use, info.attachment))
+                });
+                let id = mkdid(info.deviceid, seat_id);
+                Device::new(info, &self.xconn).info(id, info, &self.xconn, seat.cloned())
+            })
+            .collect()
+    }
+
+    /// Describes a single device, querying only it and the master device it's attached to.
+    pub fn device_info(&self, device: c_int) -> Option<crate::event::DeviceInfo> {
+        let info = DeviceInfo::get(&self.xconn, device)?;
+        let info = info.iter().find(|info| info.deviceid == device)?;
+        let master = match info._use {
+            ffi::XISlavePointer | ffi::XISlaveKeyboard => {
+                DeviceInfo::get(&self.xconn, info.attachment)
+            }
+            _ => None,
+        };
+        let master = master.as_ref().and_then(|master| master.first());
+
+        let seat = match info._use {
+            ffi::XIMasterPointer | ffi::XIMasterKeyboard => Some(seat_name(info)),
+            _ => master.map(seat_name),
+        };
+        let seat_id = device_seat(device, |device| {
+            std::iter::once(info)
+                .chain(master)
+                .find(|info| info.deviceid == device)
+                .map(|info| (info._use, info.attachment))
+        });
+        let id = mkdid(device, seat_id);
+        Some(Device::new(info, &self.xconn).info(id, info, &self.xconn, seat))
+    }
+
     /// Update the device event based on window focus.
     pub fn update_listen_device_events(&self, focus: bool) {
         let device_events = self.device_events.get() == DeviceEvents::Always
@@ -736,13 +928,54 @@ impl<'a> Deref for DeviceInfo<'a> {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DeviceId(c_int);
+/// The id of an XInput2 device, along with the master pointer of its seat.
+///
+/// The seat isn't part of the identity of the device, since a slave device can be attached to
+/// another master.
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceId {
+    id: c_int,
+    seat: Option<c_int>,
+}
 
 impl DeviceId {
     #[allow(unused)]
     pub const unsafe fn dummy() -> Self {
-        DeviceId(0)
+        DeviceId { id: 0, seat: None }
+    }
+
+    pub fn seat_id(&self) -> Option<SeatId> {
+        self.seat.map(|seat| SeatId(seat as u32))
+    }
+
+    pub fn id(&self) -> c_int {
+        self.id
+    }
+}
+
+impl PartialEq for DeviceId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for DeviceId {}
+
+impl PartialOrd for DeviceId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DeviceId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl std::hash::Hash for DeviceId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
     }
 }
 
@@ -948,14 +1181,42 @@ struct XExtension {
 fn mkwid(w: xproto::Window) -> crate::window::WindowId {
     crate::window::WindowId(crate::platform_impl::platform::WindowId(w as _))
 }
-fn mkdid(w: c_int) -> crate::event::DeviceId {
-    crate::event::DeviceId(crate::platform_impl::DeviceId::X(DeviceId(w)))
+fn mkdid(w: c_int, seat: Option<c_int>) -> crate::event::DeviceId {
+    crate::event::DeviceId(crate::platform_impl::DeviceId::X(DeviceId { id: w, seat }))
+}
+
+/// The seat of the device, which is the id of the master pointer it's part of, given the use and
+/// attachment of the devices.
+/// The name of the seat of the master device, e.g. `Virtual core pointer` and `Virtual core
+/// keyboard` make up the `Virtual core` seat.
+fn seat_name(master: &ffi::XIDeviceInfo) -> String {
+    let name = unsafe { CStr::from_ptr(master.name).to_string_lossy() };
+    let seat = name
+        .strip_suffix(" pointer")
+        .or_else(|| name.strip_suffix(" keyboard"))
+        .unwrap_or(&name);
+    seat.to_owned()
+}
+
+fn device_seat(device: c_int, devices: impl Fn(c_int) -> Option<(c_int, c_int)>) -> Option<c_int> {
+    let (device_use, attachment) = devices(device)?;
+    match device_use {
+        ffi::XIMasterPointer => Some(device),
+        ffi::XIMasterKeyboard | ffi::XISlavePointer => Some(attachment),
+        ffi::XISlaveKeyboard => devices(attachment).map(|(_, pointer)| pointer),
+        _ => None,
+    }
 }
 
 #[derive(Debug)]
 struct Device {
-    _name: String,
+    name: String,
     scroll_axes: Vec<(i32, ScrollAxis)>,
+    scroll_source: ScrollSource,
+    tablet: Option<TabletDevice>,
+    touch_axes: Option<TouchAxes>,
+    // The use of the device, e.g. `XIMasterPointer` or `XISlaveKeyboard`.
+    device_use: c_int,
     // For master devices, this is the paired device (pointer <-> keyboard).
     // For slave devices, this is the master.
     attachment: c_int,
@@ -974,10 +1235,92 @@ enum ScrollOrientation {
     Horizontal,
 }
 
+/// The valuators describing the contacts of a touchscreen.
+#[derive(Debug)]
+struct TouchAxes {
+    major: Option<TouchValuator>,
+    minor: Option<TouchValuator>,
+    orientation: Option<TouchValuator>,
+    pressure: Option<TouchValuator>,
+    /// The size of a unit of the position valuators, in pixels of the screen.
+    unit_size: f64,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct TouchValuator {
+    number: c_int,
+    min: f64,
+    max: f64,
+}
+
+impl TouchAxes {
+    fn new(valuators: &[&ffi::XIValuatorClassInfo], xconn: &XConnection) -> Self {
+        let atoms = xconn.atoms();
+        let find = |label: AtomName| {
+            valuators
+                .iter()
+                .find(|info| info.label as xproto::Atom == atoms[label])
+                .map(|info| TouchValuator {
+                    number: info.number,
+                    min: info.min,
+                    max: info.max,
+                })
+        };
+
+        // The touchscreens map the range of the first valuator to the width of the screen.
+        let screen_width = xconn.default_root().width_in_pixels as f64;
+        let unit_size = valuators
+            .iter()
+            .find(|info| info.number == 0 && info.max > info.min)
+            .map_or(0.0, |info| screen_width / (info.max - info.min));
+
+        TouchAxes {
+            major: find(AbsMtTouchMajor),
+            minor: find(AbsMtTouchMinor),
+            orientation: find(AbsMtOrientation),
+            pressure: find(AbsMtPressure),
+            unit_size,
+        }
+    }
+
+    /// The force and the contact of a touch, from the values of the valuators.
+    fn read(&self, value: impl Fn(c_int) -> Option<f64>) -> (Option<Force>, Option<TouchContact>) {
+        let read = |valuator: Option<TouchValuator>| {
+            valuator.and_then(|valuator| Some((value(valuator.number)?, valuator)))
+        };
+
+        let force = read(self.pressure).and_then(|(pressure, valuator)| {
+            (valuator.max > valuator.min).then(|| {
+                Force::Normalized((pressure - valuator.min) / (valuator.max - valuator.min))
+            })
+        });
+
+        let contact = read(self.major).map(|(major, _)| {
+            let minor = read(self.minor).map_or(major, |(minor, _)| minor);
+            // The range of the orientation is a quarter turn in each direction.
+            let orientation = read(self.orientation)
+                .filter(|(_, valuator)| valuator.max > 0.0)
+                .map_or(0.0, |(orientation, valuator)| {
+                    orientation / valuator.max.max(-valuator.min) * 90.0
+                });
+            TouchContact {
+                major: major * self.unit_size,
+                minor: minor * self.unit_size,
+                orientation,
+            }
+        });
+
+        (force, contact)
+    }
+}
+
 impl Device {
-    fn new(info: &ffi::XIDeviceInfo) -> Self {
+    fn new(info: &ffi::XIDeviceInfo, xconn: &XConnection) -> Self {
         let name = unsafe { CStr::from_ptr(info.name).to_string_lossy() };
         let mut scroll_axes = Vec::new();
+        let mut scroll_source = ScrollSource::Unknown;
+        let mut tablet = None;
+        let mut touch_axes = None;
 
         if Device::physical_device(info) {
             // Identify scroll axes
@@ -1001,17 +1344,136 @@ impl Device {
                     ));
                 }
             }
+
+            if info._use == ffi::XISlavePointer {
+                let valuators: Vec<_> = Device::classes(info)
+                    .iter()
+                    .filter_map(|&class_ptr| {
+                        let class = unsafe { &*class_ptr };
+                        (class._type == ffi::XIValuatorClass).then(|| unsafe {
+                            mem::transmute::<&ffi::XIAnyClassInfo, &ffi::XIValuatorClassInfo>(class)
+                        })
+                    })
+                    .collect();
+                tablet = TabletDevice::new(&name, valuators.iter().copied(), xconn.atoms());
+
+                let is_touchscreen = Device::classes(info).iter().any(|&class_ptr| {
+                    let class = unsafe { &*class_ptr };
+                    class._type == ffi::XITouchClass && {
+                        let info = unsafe {
+                            mem::transmute::<&ffi::XIAnyClassInfo, &ffi::XITouchClassInfo>(class)
+                        };
+                        info.mode == ffi::XIDirectTouch
+                    }
+                });
+                if is_touchscreen {
+                    touch_axes = Some(TouchAxes::new(&valuators, xconn));
+                }
+
+                if !scroll_axes.is_empty() {
+                    scroll_source = Device::scroll_source(xconn, info.deviceid, &name);
+                }
+            }
         }
 
         let mut device = Device {
-            _name: name.into_owned(),
+            name: name.into_owned(),
             scroll_axes,
+            scroll_source,
+            tablet,
+            touch_axes,
+            device_use: info._use,
             attachment: info.attachment,
         };
         device.reset_scroll_position(info);
         device
     }
 
+    /// Guess the source of the scrolling of a pointer, since XInput2 doesn't report it.
+    fn scroll_source(xconn: &XConnection, device_id: c_int, name: &str) -> ScrollSource {
+        let atoms = xconn.atoms();
+        let properties = xconn
+            .xcb_connection()
+            .xinput_xi_list_properties(device_id as xinput::DeviceId)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .map(|reply| reply.properties)
+            .unwrap_or_default();
+
+        // Only the touchpads can be tapped.
+        let name = name.to_lowercase();
+        if properties.iter().any(|&property| {
+            property == atoms[LibinputTappingEnabled] || property == atoms[SynapticsTapAction]
+        }) {
+            ScrollSource::Finger
+        } else if name.contains("trackpoint") || name.contains("pointing stick") {
+            ScrollSource::Continuous
+        } else {
+            ScrollSource::Wheel
+        }
+    }
+
+    /// Describe the device for [`EventLoopWindowTarget::devices`].
+    fn info(
+        &self,
+        id: crate::event::DeviceId,
+        info: &ffi::XIDeviceInfo,
+        xconn: &XConnection,
+        seat: Option<String>,
+    ) -> crate::event::DeviceInfo {
+        let kind = if self.tablet.is_some() {
+            DeviceKind::Tablet
+        } else if self.touch_axes.is_some() {
+            DeviceKind::Touchscreen
+        } else if self.scroll_source == ScrollSource::Finger {
+            DeviceKind::Touchpad
+        } else if Device::classes(info).iter().any(|&class_ptr| {
+            let class = unsafe { &*class_ptr };
+            class._type == ffi::XIButtonClass
+        }) {
+            DeviceKind::Pointer
+        } else {
+            DeviceKind::Keyboard
+        };
+
+        let ids = Device::physical_device(info)
+            .then(|| Device::product_id(xconn, info.deviceid))
+            .flatten();
+
+        crate::event::DeviceInfo {
+            id,
+            name: self.name.clone(),
+            kind,
+            vendor_id: ids.map(|(vendor_id, _)| vendor_id),
+            product_id: ids.map(|(_, product_id)| product_id),
+            seat,
+        }
+    }
+
+    /// The USB vendor and product ids of the device, as set by the input drivers.
+    fn product_id(xconn: &XConnection, device_id: c_int) -> Option<(u16, u16)> {
+        let reply = xconn
+            .xcb_connection()
+            .xinput_xi_get_property(
+                device_id as xinput::DeviceId,
+                false,
+                xconn.atoms()[DeviceProductId],
+                xproto::AtomEnum::ANY.into(),
+                0,
+                2,
+            )
+            .ok()?
+            .reply()
+            .ok()?;
+
+        match reply.items {
+            xinput::XIGetPropertyItems::Data32(ids) if ids.len() == 2 => {
+                Some((ids[0] as u16, ids[1] as u16))
+            }
+            _ => None,
+        }
+    }
+
     fn reset_scroll_position(&mut self, info: &ffi::XIDeviceInfo) {
         if Device::physical_device(info) {
             for class_ptr in Device::classes(info) {
@@ -1049,3 +1511,53 @@ impl Device {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(device: c_int) -> Option<(c_int, c_int)> {
+        match device {
+            // The `Virtual core` seat.
+            2 => Some((ffi::XIMasterPointer, 3)),
+            3 => Some((ffi::XIMasterKeyboard, 2)),
+            6 => Some((ffi::XISlavePointer, 2)),
+            8 => Some((ffi::XISlaveKeyboard, 3)),
+            // A second MPX seat.
+            12 => Some((ffi::XIMasterPointer, 13)),
+            13 => Some((ffi::XIMasterKeyboard, 12)),
+            14 => Some((ffi::XISlavePointer, 12)),
+            15 => Some((ffi::XISlaveKeyboard, 13)),
+            // A floating device is attached to nothing.
+            20 => Some((ffi::XIFloatingSlave, 0)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn master_devices_seat() {
+        assert_eq!(device_seat(2, lookup), Some(2));
+        assert_eq!(device_seat(3, lookup), Some(2));
+        assert_eq!(device_seat(12, lookup), Some(12));
+        assert_eq!(device_seat(13, lookup), Some(12));
+    }
+
+    #[test]
+    fn slave_devices_seat() {
+        assert_eq!(device_seat(6, lookup), Some(2));
+        assert_eq!(device_seat(8, lookup), Some(2));
+        assert_eq!(device_seat(14, lookup), Some(12));
+        assert_eq!(device_seat(15, lookup), Some(12));
+    }
+
+    #[test]
+    fn floating_and_unknown_devices_have_no_seat() {
+        assert_eq!(device_seat(20, lookup), None);
+        assert_eq!(device_seat(42, lookup), None);
+        // A slave keyboard whose master is gone.
+        assert_eq!(
+            device_seat(15, |device| lookup(device).filter(|_| device != 13)),
+            None
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::os::raw::*;
 use std::slice;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use once_cell::sync::Lazy;
 
@@ -9,11 +9,17 @@ use super::{
         self, RRCrtc, RRCrtcChangeNotifyMask, RRMode, RROutputPropertyNotifyMask,
         RRScreenChangeNotifyMask, True, Window, XRRCrtcInfo, XRRModeInfo, XRRScreenResources,
     },
-    util, X11Error, XConnection,
+    util::{self, edid::Edid},
+    X11Error, XConnection,
 };
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
-    platform_impl::{MonitorHandle as PlatformMonitorHandle, VideoMode as PlatformVideoMode},
+    error::ExternalError,
+    monitor::{GammaRamp, MonitorTransform, SubpixelOrder},
+    platform_impl::{
+        platform::X11_BACKEND, MonitorHandle as PlatformMonitorHandle, OsError,
+        VideoMode as PlatformVideoMode,
+    },
 };
 
 // Used for testing. This should always be committed as false.
@@ -21,6 +27,15 @@ const DISABLE_MONITOR_LIST_CACHING: bool = false;
 
 static MONITORS: Lazy<Mutex<Option<Vec<MonitorHandle>>>> = Lazy::new(Mutex::default);
 
+fn backend() -> Result<Arc<XConnection>, ExternalError> {
+    match X11_BACKEND.lock().unwrap().as_ref() {
+        Ok(xconn) => Ok(xconn.clone()),
+        Err(_) => Err(ExternalError::Os(os_error!(OsError::XMisc(
+            "the X11 connection isn't available"
+        )))),
+    }
+}
+
 pub fn invalidate_cached_monitor_list() -> Option<Vec<MonitorHandle>> {
     // We update this lazily.
     (*MONITORS.lock().unwrap()).take()
@@ -77,6 +92,14 @@ pub struct MonitorHandle {
     pub(crate) rect: util::AaRect,
     /// Supported video modes on this monitor
     video_modes: Vec<VideoMode>,
+    /// The physical size in millimetres
+    physical_size_mm: Option<(u32, u32)>,
+    /// The identification of the connected display
+    edid: Option<Edid>,
+    /// The rotation and reflection of the CRTC
+    transform: MonitorTransform,
+    /// The subpixel layout of the output
+    subpixel_order: SubpixelOrder,
 }
 
 impl PartialEq for MonitorHandle {
@@ -123,7 +146,15 @@ impl MonitorHandle {
         crtc: *mut XRRCrtcInfo,
         primary: bool,
     ) -> Option<Self> {
-        let (name, scale_factor, video_modes) = unsafe { xconn.get_output_info(resources, crtc)? };
+        let util::OutputInfo {
+            name,
+            scale_factor,
+            video_modes,
+            physical_size_mm,
+            subpixel_order,
+            edid,
+        } = unsafe { xconn.get_output_info(resources, crtc)? };
+        let transform = util::monitor_transform(unsafe { (*crtc).rotation });
         let dimensions = unsafe { ((*crtc).width, (*crtc).height) };
         let position = unsafe { ((*crtc).x, (*crtc).y) };
 
@@ -148,9 +179,22 @@ impl MonitorHandle {
             primary,
             rect,
             video_modes,
+            physical_size_mm,
+            edid,
+            transform,
+            subpixel_order,
         })
     }
 
+    /// Whether the video mode, position, scale factor or transform differ from `other`.
+    pub(crate) fn differs_from(&self, other: &Self) -> bool {
+        self.dimensions != other.dimensions
+            || self.transform != other.transform
+            || self.position != other.position
+            || self.refresh_rate_millihertz != other.refresh_rate_millihertz
+            || self.scale_factor != other.scale_factor
+    }
+
     pub fn dummy() -> Self {
         MonitorHandle {
             id: 0,
@@ -162,6 +206,10 @@ impl MonitorHandle {
             primary: true,
             rect: util::AaRect::new((0, 0), (1, 1)),
             video_modes: Vec::new(),
+            physical_size_mm: None,
+            edid: None,
+            transform: MonitorTransform::Normal,
+            subpixel_order: SubpixelOrder::Unknown,
         }
     }
 
@@ -187,15 +235,77 @@ impl MonitorHandle {
         self.position.into()
     }
 
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        let (position, size) = match X11_BACKEND.lock().unwrap().as_ref() {
+            Ok(xconn) => xconn.monitor_work_area(self.position, self.dimensions),
+            Err(_) => (self.position, self.dimensions),
+        };
+        (position.into(), size.into())
+    }
+
     pub fn refresh_rate_millihertz(&self) -> Option<u32> {
         self.refresh_rate_millihertz
     }
 
+    pub fn gamma_ramp(&self) -> Result<GammaRamp, ExternalError> {
+        let xconn = backend()?;
+        xconn.get_crtc_gamma(self.id).ok_or_else(|| {
+            ExternalError::Os(os_error!(OsError::XMisc(
+                "failed to get the gamma ramp of the CRTC"
+            )))
+        })
+    }
+
+    pub fn set_gamma_ramp(
+        &self,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), ExternalError> {
+        backend()?
+            .set_crtc_gamma(self.id, red, green, blue)
+            .map_err(|err| ExternalError::Os(os_error!(OsError::XMisc(err))))
+    }
+
     #[inline]
     pub fn scale_factor(&self) -> f64 {
         self.scale_factor
     }
 
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        self.physical_size_mm
+    }
+
+    #[inline]
+    pub fn manufacturer(&self) -> Option<String> {
+        self.edid.as_ref().map(|edid| edid.manufacturer.clone())
+    }
+
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        self.edid.as_ref().map(|edid| {
+            edid.model
+                .clone()
+                .unwrap_or_else(|| format!("{:04X}", edid.product_code))
+        })
+    }
+
+    #[inline]
+    pub fn serial_number(&self) -> Option<String> {
+        self.edid.as_ref()?.serial_number.clone()
+    }
+
+    #[inline]
+    pub fn transform(&self) -> MonitorTransform {
+        self.transform
+    }
+
+    #[inline]
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        self.subpixel_order
+    }
+
     #[inline]
     pub fn video_modes(&self) -> impl Iterator<Item = PlatformVideoMode> {
         let monitor = self.clone();
@@ -311,6 +421,19 @@ impl XConnection {
             .unwrap_or_else(MonitorHandle::dummy)
     }
 
+    /// The index of the monitor in the Xinerama list, which is used by the window manager hints.
+    ///
+    /// The X server lists the primary monitor first, followed by the others in the order of
+    /// their CRTCs.
+    pub fn xinerama_index(&self, monitor: &MonitorHandle) -> Option<u32> {
+        let mut monitors = self.available_monitors();
+        monitors.sort_by_key(|monitor| !monitor.primary);
+        monitors
+            .iter()
+            .position(|candidate| candidate.id == monitor.id)
+            .map(|index| index as u32)
+    }
+
     pub fn select_xrandr_input(&self, root: Window) -> Result<c_int, X11Error> {
         let has_xrandr = unsafe {
             let mut major = 0;
@@ -0,0 +1,219 @@
+//! Drawing tablets, recognized from the labels of the XInput2 valuators of the devices.
+//!
+//! The X11 tablet drivers expose each tool and pad of a tablet as a separate pointer, with the
+//! axes of the tools as additional valuators.
+
+use std::os::raw::c_int;
+
+use x11rb::protocol::xproto;
+
+use super::atoms::*;
+use super::ffi;
+use crate::event::{PenAxes, PenTool, PenToolKind};
+
+/// A device of a drawing tablet.
+#[derive(Debug)]
+pub enum TabletDevice {
+    Tool(TabletTool),
+    Pad,
+}
+
+impl TabletDevice {
+    /// Recognize the devices of the tablets among the physical pointers.
+    pub fn new<'a>(
+        name: &str,
+        valuators: impl Iterator<Item = &'a ffi::XIValuatorClassInfo>,
+        atoms: &Atoms,
+    ) -> Option<Self> {
+        let name = name.to_lowercase();
+        // The pads of the Wacom and libinput drivers, without matching the touchpads.
+        if name.ends_with(" pad") {
+            return Some(Self::Pad);
+        }
+
+        let valuators: Vec<_> = valuators
+            .filter_map(|info| {
+                let valuator = PenValuator {
+                    axis: PenAxis::from_label(info.label as xproto::Atom, atoms)?,
+                    min: info.min,
+                    max: info.max,
+                };
+                Some((info.number, valuator))
+            })
+            .collect();
+
+        let has_pressure = valuators
+            .iter()
+            .any(|(_, valuator)| valuator.axis == PenAxis::Pressure);
+        if !has_pressure && !name.contains("stylus") && !name.contains("eraser") {
+            return None;
+        }
+
+        let kind = if name.contains("eraser") {
+            PenToolKind::Eraser
+        } else if name.contains("cursor") {
+            PenToolKind::Mouse
+        } else {
+            PenToolKind::Pen
+        };
+        Some(Self::Tool(TabletTool::new(kind, valuators)))
+    }
+}
+
+/// A tool of a tablet, with the latest state of its axes.
+#[derive(Debug)]
+pub struct TabletTool {
+    kind: PenToolKind,
+    valuators: Vec<(c_int, PenValuator)>,
+    pub axes: PenAxes,
+    /// The window the tool is over.
+    ///
+    /// XInput2 doesn't report the proximity of the tools, so it follows the crossing events.
+    pub window: Option<xproto::Window>,
+}
+
+impl TabletTool {
+    fn new(kind: PenToolKind, valuators: Vec<(c_int, PenValuator)>) -> Self {
+        let mut axes = PenAxes::default();
+        for (_, valuator) in &valuators {
+            match valuator.axis {
+                PenAxis::Pressure => axes.pressure = Some(0.),
+                PenAxis::TiltX | PenAxis::TiltY => axes.tilt = Some((0., 0.)),
+                PenAxis::Distance => axes.distance = Some(0.),
+                PenAxis::Rotation => axes.rotation = Some(0.),
+                PenAxis::Slider => axes.slider = Some(0.),
+            }
+        }
+
+        Self {
+            kind,
+            valuators,
+            axes,
+            window: None,
+        }
+    }
+
+    pub fn tool(&self) -> PenTool {
+        PenTool {
+            kind: self.kind,
+            serial: None,
+        }
+    }
+
+    /// Update the axes with the value of the valuator `number`.
+    pub fn update_axis(&mut self, number: c_int, value: f64) {
+        let valuator = match self.valuators.iter().find(|(n, _)| *n == number) {
+            Some((_, valuator)) => valuator,
+            None => return,
+        };
+
+        let normalized = if valuator.max > valuator.min {
+            ((value - valuator.min) / (valuator.max - valuator.min)).clamp(0., 1.)
+        } else {
+            0.
+        };
+        match valuator.axis {
+            PenAxis::Pressure => self.axes.pressure = Some(normalized),
+            // The drivers report the tilt in degrees.
+            PenAxis::TiltX => {
+                let (_, tilt_y) = self.axes.tilt.unwrap_or_default();
+                self.axes.tilt = Some((value.clamp(-90., 90.), tilt_y));
+            }
+            PenAxis::TiltY => {
+                let (tilt_x, _) = self.axes.tilt.unwrap_or_default();
+                self.axes.tilt = Some((tilt_x, value.clamp(-90., 90.)));
+            }
+            PenAxis::Distance => self.axes.distance = Some(normalized),
+            PenAxis::Rotation => self.axes.rotation = Some(normalized * 360.),
+            PenAxis::Slider => self.axes.slider = Some(normalized * 2. - 1.),
+        }
+    }
+}
+
+/// The number of a button of a tablet pad, from the X11 button.
+///
+/// The X11 buttons 4 to 7 are reserved for scrolling, so they're skipped by the drivers.
+pub fn pad_button(detail: u32) -> Option<u32> {
+    match detail {
+        1..=3 => Some(detail - 1),
+        8.. => Some(detail - 5),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PenValuator {
+    axis: PenAxis,
+    min: f64,
+    max: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PenAxis {
+    Pressure,
+    TiltX,
+    TiltY,
+    Distance,
+    Rotation,
+    Slider,
+}
+
+impl PenAxis {
+    fn from_label(label: xproto::Atom, atoms: &Atoms) -> Option<Self> {
+        Some(match label {
+            _ if label == atoms[AbsPressure] => Self::Pressure,
+            _ if label == atoms[AbsTiltX] => Self::TiltX,
+            _ if label == atoms[AbsTiltY] => Self::TiltY,
+            _ if label == atoms[AbsDistance] => Self::Distance,
+            _ if label == atoms[AbsRotaryZ] => Self::Rotation,
+            _ if label == atoms[AbsThrottle] || label == atoms[AbsWheel] => Self::Slider,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valuator(axis: PenAxis, min: f64, max: f64) -> PenValuator {
+        PenValuator { axis, min, max }
+    }
+
+    #[test]
+    fn normalizes_axes() {
+        let mut tool = TabletTool::new(
+            PenToolKind::Pen,
+            vec![
+                (2, valuator(PenAxis::Pressure, 0., 65535.)),
+                (3, valuator(PenAxis::TiltX, -64., 63.)),
+                (4, valuator(PenAxis::TiltY, -64., 63.)),
+                (5, valuator(PenAxis::Rotation, 0., 1000.)),
+            ],
+        );
+        assert_eq!(tool.axes.pressure, Some(0.));
+        assert_eq!(tool.axes.tilt, Some((0., 0.)));
+        assert_eq!(tool.axes.distance, None);
+
+        tool.update_axis(2, 65535.);
+        tool.update_axis(3, -30.);
+        tool.update_axis(4, 12.);
+        tool.update_axis(5, 250.);
+        // Unknown valuators are ignored.
+        tool.update_axis(6, 1.);
+
+        assert_eq!(tool.axes.pressure, Some(1.));
+        assert_eq!(tool.axes.tilt, Some((-30., 12.)));
+        assert_eq!(tool.axes.rotation, Some(90.));
+        assert_eq!(tool.axes.slider, None);
+    }
+
+    #[test]
+    fn numbers_pad_buttons() {
+        assert_eq!(pad_button(1), Some(0));
+        assert_eq!(pad_button(3), Some(2));
+        assert_eq!(pad_button(4), None);
+        assert_eq!(pad_button(7), None);
+        assert_eq!(pad_button(8), Some(3));
+    }
+}
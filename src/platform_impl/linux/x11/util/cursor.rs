@@ -1,6 +1,7 @@
 use std::ffi::CString;
 
 use x11rb::connection::Connection;
+use x11rb::protocol::xinput::{self, ConnectionExt as _};
 
 use crate::window::CursorIcon;
 
@@ -19,6 +20,28 @@ impl XConnection {
             .expect("Failed to set cursor");
     }
 
+    /// Set the cursor of the window for a master pointer.
+    pub fn set_device_cursor_icon(
+        &self,
+        window: xproto::Window,
+        device: xinput::DeviceId,
+        cursor: Option<CursorIcon>,
+    ) -> Result<(), X11Error> {
+        let cursor = *self
+            .cursor_cache
+            .lock()
+            .unwrap()
+            .entry(cursor)
+            .or_insert_with(|| self.get_cursor(cursor));
+
+        self.xcb_connection()
+            .xinput_xi_change_cursor(window, cursor as xproto::Cursor, device)?
+            .ignore_error();
+
+        self.xcb_connection().flush()?;
+        Ok(())
+    }
+
     fn create_empty_cursor(&self) -> ffi::Cursor {
         let data = 0;
         let pixmap = unsafe {
@@ -0,0 +1,149 @@
+//! Minimal parser for the base block of the Extended Display Identification Data.
+//!
+//! Only the identification of the display is extracted, everything describing its
+//! capabilities is left alone.
+
+const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+const BLOCK_SIZE: usize = 128;
+
+const DESCRIPTORS_OFFSET: usize = 54;
+const DESCRIPTOR_SIZE: usize = 18;
+const DESCRIPTOR_SERIAL: u8 = 0xFF;
+const DESCRIPTOR_NAME: u8 = 0xFC;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edid {
+    /// Three letter PNP id of the manufacturer, e.g. `DEL`.
+    pub manufacturer: String,
+    pub product_code: u16,
+    /// The serial number from the display descriptor, falling back to the numeric one.
+    pub serial_number: Option<String>,
+    /// The display product name from the display descriptor.
+    pub model: Option<String>,
+    /// Physical size of the screen in centimetres, `None` for projectors.
+    pub size_cm: Option<(u8, u8)>,
+}
+
+impl Edid {
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let block = data.get(..BLOCK_SIZE)?;
+        if block[..HEADER.len()] != HEADER {
+            return None;
+        }
+
+        if block.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) != 0 {
+            warn!("Ignoring EDID with an invalid checksum");
+            return None;
+        }
+
+        // Three 5-bit letters, where 1 is `A`.
+        let id = u16::from_be_bytes([block[8], block[9]]);
+        let manufacturer = [10, 5, 0]
+            .iter()
+            .map(|shift| (((id >> shift) & 0x1F) as u8 + b'A' - 1) as char)
+            .collect();
+        let product_code = u16::from_le_bytes([block[10], block[11]]);
+        let numeric_serial = u32::from_le_bytes([block[12], block[13], block[14], block[15]]);
+
+        let size_cm = match (block[21], block[22]) {
+            (0, _) | (_, 0) => None,
+            size => Some(size),
+        };
+
+        let mut model = None;
+        let mut serial_number = None;
+        for descriptor in block[DESCRIPTORS_OFFSET..DESCRIPTORS_OFFSET + 4 * DESCRIPTOR_SIZE]
+            .chunks_exact(DESCRIPTOR_SIZE)
+        {
+            // Display descriptors start with a zero pixel clock.
+            if descriptor[..3] != [0, 0, 0] {
+                continue;
+            }
+
+            match descriptor[3] {
+                DESCRIPTOR_NAME => model = descriptor_string(&descriptor[5..]),
+                DESCRIPTOR_SERIAL => serial_number = descriptor_string(&descriptor[5..]),
+                _ => (),
+            }
+        }
+
+        let serial_number =
+            serial_number.or_else(|| (numeric_serial != 0).then(|| numeric_serial.to_string()));
+
+        Some(Edid {
+            manufacturer,
+            product_code,
+            serial_number,
+            model,
+            size_cm,
+        })
+    }
+}
+
+/// Strings in descriptors are terminated by a line feed and padded with spaces.
+fn descriptor_string(data: &[u8]) -> Option<String> {
+    let end = data.iter().position(|&c| c == b'\n').unwrap_or(data.len());
+    let string = String::from_utf8_lossy(&data[..end]).trim().to_owned();
+    (!string.is_empty()).then_some(string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edid(descriptors: &[(u8, &[u8])]) -> Vec<u8> {
+        let mut data = vec![0; BLOCK_SIZE];
+        data[..8].copy_from_slice(&HEADER);
+        // `DEL`
+        data[8..10].copy_from_slice(&0x10ACu16.to_be_bytes());
+        data[10..12].copy_from_slice(&0xA0C4u16.to_le_bytes());
+        data[12..16].copy_from_slice(&0x1234u32.to_le_bytes());
+        data[21] = 60;
+        data[22] = 34;
+
+        for (index, (tag, text)) in descriptors.iter().enumerate() {
+            let offset = DESCRIPTORS_OFFSET + (index + 1) * DESCRIPTOR_SIZE;
+            let descriptor = &mut data[offset..offset + DESCRIPTOR_SIZE];
+            descriptor[3] = *tag;
+            descriptor[5..].fill(b' ');
+            descriptor[5..5 + text.len()].copy_from_slice(text);
+            descriptor[5 + text.len()] = b'\n';
+        }
+
+        let sum = data.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        data[BLOCK_SIZE - 1] = 0u8.wrapping_sub(sum);
+        data
+    }
+
+    #[test]
+    fn parses_identification() {
+        let data = edid(&[
+            (DESCRIPTOR_SERIAL, b"ABC123"),
+            (DESCRIPTOR_NAME, b"DELL U2720Q"),
+        ]);
+        let edid = Edid::parse(&data).unwrap();
+        assert_eq!(edid.manufacturer, "DEL");
+        assert_eq!(edid.product_code, 0xA0C4);
+        assert_eq!(edid.serial_number.as_deref(), Some("ABC123"));
+        assert_eq!(edid.model.as_deref(), Some("DELL U2720Q"));
+        assert_eq!(edid.size_cm, Some((60, 34)));
+    }
+
+    #[test]
+    fn falls_back_to_numeric_serial() {
+        let edid = Edid::parse(&edid(&[])).unwrap();
+        assert_eq!(edid.serial_number.as_deref(), Some("4660"));
+        assert_eq!(edid.model, None);
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let mut data = edid(&[]);
+        assert_eq!(Edid::parse(&data[..64]), None);
+        data[20] ^= 1;
+        assert_eq!(Edid::parse(&data), None);
+        data[20] ^= 1;
+        data[0] = 1;
+        assert_eq!(Edid::parse(&data), None);
+    }
+}
@@ -42,13 +42,24 @@ impl XConnection {
         window: xproto::Window,
         device_id: u16,
         mask: xinput::XIEventMask,
+    ) -> Result<VoidCookie<'_>, X11Error> {
+        self.select_xinput_event_masks(window, device_id, vec![mask])
+    }
+
+    /// Select XInput2 events with a mask spanning several words, for the events past the
+    /// 32 first ones.
+    pub fn select_xinput_event_masks(
+        &self,
+        window: xproto::Window,
+        device_id: u16,
+        mask: Vec<xinput::XIEventMask>,
     ) -> Result<VoidCookie<'_>, X11Error> {
         self.xcb_connection()
             .xinput_xi_select_events(
                 window,
                 &[xinput::EventMask {
                     deviceid: device_id,
-                    mask: vec![mask],
+                    mask,
                 }],
             )
             .map_err(Into::into)
@@ -3,6 +3,7 @@
 
 mod client_msg;
 mod cursor;
+pub mod edid;
 mod geometry;
 mod hint;
 mod icon;
@@ -12,6 +13,8 @@ pub(crate) mod memory;
 mod randr;
 mod window_property;
 mod wm;
+mod work_area;
+pub mod xsettings;
 
 pub use self::{
     client_msg::*, geometry::*, hint::*, icon::*, input::*, randr::*, window_property::*, wm::*,
@@ -1,12 +1,24 @@
-use std::{env, slice, str::FromStr};
+use std::{collections::hash_map::Entry, env, ptr, slice, str::FromStr};
 
 use super::{
-    ffi::{CurrentTime, RRCrtc, RRMode, Success, XRRCrtcInfo, XRRScreenResources},
+    edid::Edid,
+    ffi::{CurrentTime, RRCrtc, RRMode, RROutput, Success, XRRCrtcInfo, XRRScreenResources},
     *,
 };
+use crate::monitor::{GammaRamp, MonitorTransform, SubpixelOrder};
 use crate::platform_impl::platform::x11::monitor;
 use crate::{dpi::validate_scale_factor, platform_impl::platform::x11::VideoMode};
 
+/// Details of the output driven by a CRTC.
+pub struct OutputInfo {
+    pub name: String,
+    pub scale_factor: f64,
+    pub video_modes: Vec<VideoMode>,
+    pub physical_size_mm: Option<(u32, u32)>,
+    pub subpixel_order: SubpixelOrder,
+    pub edid: Option<Edid>,
+}
+
 /// Represents values of `WINIT_HIDPI_FACTOR`.
 pub enum EnvVarDPI {
     Randr,
@@ -35,6 +47,46 @@ pub fn calc_dpi_factor(
     }
 }
 
+pub fn subpixel_order(order: ffi::SubpixelOrder) -> SubpixelOrder {
+    match order as c_int {
+        ffi::SubPixelHorizontalRGB => SubpixelOrder::HorizontalRgb,
+        ffi::SubPixelHorizontalBGR => SubpixelOrder::HorizontalBgr,
+        ffi::SubPixelVerticalRGB => SubpixelOrder::VerticalRgb,
+        ffi::SubPixelVerticalBGR => SubpixelOrder::VerticalBgr,
+        ffi::SubPixelNone => SubpixelOrder::None,
+        _ => SubpixelOrder::Unknown,
+    }
+}
+
+pub fn monitor_transform(rotation: ffi::Rotation) -> MonitorTransform {
+    let rotation = rotation as c_int;
+    let reflect_x = rotation & ffi::RR_Reflect_X != 0;
+    let reflect_y = rotation & ffi::RR_Reflect_Y != 0;
+
+    // Reflecting along the Y axis is the same as reflecting along the X axis and rotating by
+    // 180 degrees, and reflecting along both axes is a 180 degrees rotation.
+    let mut quarter_turns = match rotation & 0xF {
+        ffi::RR_Rotate_90 => 1,
+        ffi::RR_Rotate_180 => 2,
+        ffi::RR_Rotate_270 => 3,
+        _ => 0,
+    };
+    if reflect_y {
+        quarter_turns += 2;
+    }
+
+    match (reflect_x != reflect_y, quarter_turns % 4) {
+        (false, 0) => MonitorTransform::Normal,
+        (false, 1) => MonitorTransform::Rotate90,
+        (false, 2) => MonitorTransform::Rotate180,
+        (false, _) => MonitorTransform::Rotate270,
+        (true, 0) => MonitorTransform::Flipped,
+        (true, 1) => MonitorTransform::Flipped90,
+        (true, 2) => MonitorTransform::Flipped180,
+        (true, _) => MonitorTransform::Flipped270,
+    }
+}
+
 impl XConnection {
     // Retrieve DPI from Xft.dpi property
     pub unsafe fn get_xft_dpi(&self) -> Option<f64> {
@@ -57,9 +109,9 @@ impl XConnection {
         &self,
         resources: *mut XRRScreenResources,
         crtc: *mut XRRCrtcInfo,
-    ) -> Option<(String, f64, Vec<VideoMode>)> {
-        let output_info =
-            (self.xrandr.XRRGetOutputInfo)(self.display, resources, *(*crtc).outputs.offset(0));
+    ) -> Option<OutputInfo> {
+        let output = *(*crtc).outputs.offset(0);
+        let output_info = (self.xrandr.XRRGetOutputInfo)(self.display, resources, output);
         if output_info.is_null() {
             // When calling `XRRGetOutputInfo` on a virtual monitor (versus a physical display)
             // it's possible for it to return null.
@@ -136,7 +188,9 @@ impl XConnection {
                 dpi_override
             }
             EnvVarDPI::NotSet => {
-                if let Some(dpi) = self.get_xft_dpi() {
+                if let Some(scale_factor) = self.xsettings.lock().unwrap().scale_factor() {
+                    scale_factor
+                } else if let Some(dpi) = self.get_xft_dpi() {
                     dpi / 96.
                 } else {
                     calc_dpi_factor(
@@ -147,8 +201,56 @@ impl XConnection {
             }
         };
 
+        let physical_size_mm = match ((*output_info).mm_width, (*output_info).mm_height) {
+            (0, _) | (_, 0) => None,
+            (width, height) => Some((width as u32, height as u32)),
+        };
+        let subpixel_order = subpixel_order((*output_info).subpixel_order);
+
         (self.xrandr.XRRFreeOutputInfo)(output_info);
-        Some((name, scale_factor, modes))
+        Some(OutputInfo {
+            name,
+            scale_factor,
+            video_modes: modes,
+            physical_size_mm,
+            subpixel_order,
+            edid: self.get_output_edid(output),
+        })
+    }
+
+    unsafe fn get_output_edid(&self, output: RROutput) -> Option<Edid> {
+        let mut actual_type = 0;
+        let mut actual_format = 0;
+        let mut nitems = 0;
+        let mut bytes_after = 0;
+        let mut data = ptr::null_mut();
+        let status = (self.xrandr.XRRGetOutputProperty)(
+            self.display,
+            output,
+            self.atoms()[EdidProperty] as ffi::Atom,
+            0,
+            // In 32-bit units, which is enough for the base block and a few extensions.
+            128,
+            ffi::False,
+            ffi::False,
+            ffi::AnyPropertyType as ffi::Atom,
+            &mut actual_type,
+            &mut actual_format,
+            &mut nitems,
+            &mut bytes_after,
+            &mut data,
+        );
+        if status != Success as c_int || data.is_null() {
+            return None;
+        }
+
+        let edid = if actual_format == 8 {
+            Edid::parse(slice::from_raw_parts(data, nitems as usize))
+        } else {
+            None
+        };
+        (self.xlib.XFree)(data as *mut _);
+        edid
     }
 
     #[must_use]
@@ -210,4 +312,85 @@ impl XConnection {
             mode
         }
     }
+
+    pub fn get_crtc_gamma(&self, crtc_id: RRCrtc) -> Option<GammaRamp> {
+        unsafe {
+            let gamma = (self.xrandr.XRRGetCrtcGamma)(self.display, crtc_id);
+            if gamma.is_null() {
+                return None;
+            }
+
+            let size = (*gamma).size.max(0) as usize;
+            let ramp = GammaRamp {
+                red: slice::from_raw_parts((*gamma).red, size).to_vec(),
+                green: slice::from_raw_parts((*gamma).green, size).to_vec(),
+                blue: slice::from_raw_parts((*gamma).blue, size).to_vec(),
+            };
+            (self.xrandr.XRRFreeGamma)(gamma);
+            Some(ramp)
+        }
+    }
+
+    /// Set the gamma ramp of the CRTC, the original ramp is kept to be restored with
+    /// [`XConnection::restore_crtc_gamma`].
+    pub fn set_crtc_gamma(
+        &self,
+        crtc_id: RRCrtc,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), &'static str> {
+        let size = unsafe { (self.xrandr.XRRGetCrtcGammaSize)(self.display, crtc_id) };
+        if size <= 0 {
+            return Err("the CRTC doesn't support gamma ramps");
+        }
+        if [red, green, blue]
+            .iter()
+            .any(|table| table.len() != size as usize)
+        {
+            return Err("the size of the gamma ramp doesn't match the CRTC");
+        }
+
+        if let Entry::Vacant(entry) = self.original_gamma.lock().unwrap().entry(crtc_id) {
+            let ramp = self
+                .get_crtc_gamma(crtc_id)
+                .ok_or("failed to get the gamma ramp of the CRTC")?;
+            entry.insert(ramp);
+        }
+
+        self.apply_crtc_gamma(crtc_id, red, green, blue)
+    }
+
+    /// Restore the gamma ramps changed with [`XConnection::set_crtc_gamma`].
+    pub fn restore_crtc_gamma(&self) {
+        for (crtc_id, ramp) in self.original_gamma.lock().unwrap().drain() {
+            if let Err(err) = self.apply_crtc_gamma(crtc_id, &ramp.red, &ramp.green, &ramp.blue) {
+                warn!("Failed to restore the gamma ramp: {err}");
+            }
+        }
+    }
+
+    fn apply_crtc_gamma(
+        &self,
+        crtc_id: RRCrtc,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), &'static str> {
+        unsafe {
+            let gamma = (self.xrandr.XRRAllocGamma)(red.len() as _);
+            if gamma.is_null() {
+                return Err("failed to allocate the gamma ramp");
+            }
+
+            ptr::copy_nonoverlapping(red.as_ptr(), (*gamma).red, red.len());
+            ptr::copy_nonoverlapping(green.as_ptr(), (*gamma).green, green.len());
+            ptr::copy_nonoverlapping(blue.as_ptr(), (*gamma).blue, blue.len());
+            (self.xrandr.XRRSetCrtcGamma)(self.display, crtc_id, gamma);
+            (self.xrandr.XRRFreeGamma)(gamma);
+        }
+
+        self.flush_requests()
+            .map_err(|_| "failed to set the gamma ramp of the CRTC")
+    }
 }
@@ -0,0 +1,277 @@
+use std::cmp;
+
+use super::*;
+
+// Order of the values in `_NET_WM_STRUT_PARTIAL`, `_NET_WM_STRUT` only has the first four.
+const STRUT_LEFT: usize = 0;
+const STRUT_RIGHT: usize = 1;
+const STRUT_TOP: usize = 2;
+const STRUT_BOTTOM: usize = 3;
+const STRUT_LEFT_START_Y: usize = 4;
+const STRUT_RIGHT_START_Y: usize = 6;
+const STRUT_TOP_START_X: usize = 8;
+const STRUT_BOTTOM_START_X: usize = 10;
+
+/// Rectangle with exclusive right and bottom edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Edges {
+    left: i64,
+    top: i64,
+    right: i64,
+    bottom: i64,
+}
+
+impl Edges {
+    fn new((x, y): (i32, i32), (width, height): (u32, u32)) -> Self {
+        Edges {
+            left: x as i64,
+            top: y as i64,
+            right: x as i64 + width as i64,
+            bottom: y as i64 + height as i64,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.left >= self.right || self.top >= self.bottom
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        Edges {
+            left: cmp::max(self.left, other.left),
+            top: cmp::max(self.top, other.top),
+            right: cmp::min(self.right, other.right),
+            bottom: cmp::min(self.bottom, other.bottom),
+        }
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+}
+
+/// Removes from `monitor` the space reserved by the `struts`, which are relative to the edges
+/// of the X screen of size `(width, height)`.
+fn subtract_struts(monitor: Edges, (width, height): (i64, i64), struts: &[Vec<Cardinal>]) -> Edges {
+    let mut work_area = monitor;
+
+    // A panel on an inner edge of a monitor covers the neighbouring monitors entirely; those
+    // are left untouched.
+    for strut in struts {
+        let span = |index: usize, max: i64| match strut.get(index..index + 2) {
+            Some(&[start, end]) if start <= end => (start as i64, end as i64 + 1),
+            _ => (0, max),
+        };
+
+        let (start, end) = span(STRUT_LEFT_START_Y, height);
+        let left = Edges {
+            left: 0,
+            top: start,
+            right: strut[STRUT_LEFT] as i64,
+            bottom: end,
+        };
+        if left.overlaps(&monitor) && left.right < monitor.right {
+            work_area.left = cmp::max(work_area.left, left.right);
+        }
+
+        let (start, end) = span(STRUT_RIGHT_START_Y, height);
+        let right = Edges {
+            left: width - strut[STRUT_RIGHT] as i64,
+            top: start,
+            right: width,
+            bottom: end,
+        };
+        if right.overlaps(&monitor) && right.left > monitor.left {
+            work_area.right = cmp::min(work_area.right, right.left);
+        }
+
+        let (start, end) = span(STRUT_TOP_START_X, width);
+        let top = Edges {
+            left: start,
+            top: 0,
+            right: end,
+            bottom: strut[STRUT_TOP] as i64,
+        };
+        if top.overlaps(&monitor) && top.bottom < monitor.bottom {
+            work_area.top = cmp::max(work_area.top, top.bottom);
+        }
+
+        let (start, end) = span(STRUT_BOTTOM_START_X, width);
+        let bottom = Edges {
+            left: start,
+            top: height - strut[STRUT_BOTTOM] as i64,
+            right: end,
+            bottom: height,
+        };
+        if bottom.overlaps(&monitor) && bottom.top > monitor.top {
+            work_area.bottom = cmp::min(work_area.bottom, bottom.top);
+        }
+    }
+
+    if work_area.is_empty() {
+        monitor
+    } else {
+        work_area
+    }
+}
+
+impl XConnection {
+    /// Computes the area of the monitor at `position` with `size` that isn't covered by panels
+    /// and docks.
+    ///
+    /// The struts of the clients overlapping the monitor are subtracted from it, since the
+    /// `_NET_WORKAREA` advertised by the window manager spans the whole X screen, and a panel on
+    /// a single monitor would shrink all of them.
+    pub fn monitor_work_area(
+        &self,
+        position: (i32, i32),
+        size: (u32, u32),
+    ) -> ((i32, i32), (u32, u32)) {
+        let monitor = Edges::new(position, size);
+        let root = self.default_root();
+        let screen_size = (root.width_in_pixels as i64, root.height_in_pixels as i64);
+        let struts = self.client_struts();
+        let mut work_area = subtract_struts(monitor, screen_size, &struts);
+
+        // Some window managers don't list the docks in `_NET_CLIENT_LIST`, fallback to
+        // `_NET_WORKAREA` when it can only describe this monitor.
+        if struts.is_empty() && self.available_monitors().len() == 1 {
+            if let Some(net_work_area) = self.net_work_area() {
+                let clipped = monitor.intersection(&net_work_area);
+                // Some window managers don't update the work area when monitors are moved
+                // around, so don't trust it when it doesn't make sense for this monitor.
+                if !clipped.is_empty() {
+                    work_area = clipped;
+                }
+            }
+        }
+
+        (
+            (work_area.left as i32, work_area.top as i32),
+            (
+                (work_area.right - work_area.left) as u32,
+                (work_area.bottom - work_area.top) as u32,
+            ),
+        )
+    }
+
+    /// The `_NET_WORKAREA` of the current desktop.
+    fn net_work_area(&self) -> Option<Edges> {
+        let atoms = self.atoms();
+        let root = self.default_root().root;
+        let cardinal = xproto::Atom::from(xproto::AtomEnum::CARDINAL);
+
+        let desktop = self
+            .get_property::<Cardinal>(root, atoms[_NET_CURRENT_DESKTOP], cardinal)
+            .ok()
+            .and_then(|desktop| desktop.first().copied())
+            .unwrap_or(0) as usize;
+        let work_areas = self
+            .get_property::<Cardinal>(root, atoms[_NET_WORKAREA], cardinal)
+            .ok()?;
+
+        match work_areas.get(desktop * 4..desktop * 4 + 4) {
+            Some(&[x, y, width, height]) => Some(Edges::new((x as i32, y as i32), (width, height))),
+            _ => None,
+        }
+    }
+
+    /// The struts of all the managed windows in the `_NET_WM_STRUT_PARTIAL` layout.
+    ///
+    /// Struts coming from `_NET_WM_STRUT` only have the widths and span the whole edge.
+    fn client_struts(&self) -> Vec<Vec<Cardinal>> {
+        let atoms = self.atoms();
+        let root = self.default_root().root;
+        let cardinal = xproto::Atom::from(xproto::AtomEnum::CARDINAL);
+        let clients = self
+            .get_property::<xproto::Window>(
+                root,
+                atoms[_NET_CLIENT_LIST],
+                xproto::Atom::from(xproto::AtomEnum::WINDOW),
+            )
+            .unwrap_or_default();
+
+        clients
+            .into_iter()
+            .filter_map(|client| {
+                let strut = self
+                    .get_property::<Cardinal>(client, atoms[_NET_WM_STRUT_PARTIAL], cardinal)
+                    .ok()
+                    .filter(|strut| strut.len() >= 12)
+                    .or_else(|| {
+                        self.get_property::<Cardinal>(client, atoms[_NET_WM_STRUT], cardinal)
+                            .ok()
+                            .filter(|strut| strut.len() >= 4)
+                            .map(|strut| strut[..4].to_vec())
+                    })?;
+                Some(strut)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: (i64, i64) = (3840, 1080);
+
+    fn left_monitor() -> Edges {
+        Edges::new((0, 0), (1920, 1080))
+    }
+
+    fn right_monitor() -> Edges {
+        Edges::new((1920, 0), (1920, 1080))
+    }
+
+    fn strut_partial(
+        edge: usize,
+        width: Cardinal,
+        start: Cardinal,
+        end: Cardinal,
+    ) -> Vec<Cardinal> {
+        let mut strut = vec![0; 12];
+        strut[edge] = width;
+        strut[4 + edge * 2] = start;
+        strut[5 + edge * 2] = end;
+        strut
+    }
+
+    #[test]
+    fn panel_only_shrinks_its_monitor() {
+        let struts = [strut_partial(STRUT_BOTTOM, 30, 0, 1919)];
+
+        let left = subtract_struts(left_monitor(), SCREEN, &struts);
+        assert_eq!(left, Edges::new((0, 0), (1920, 1050)));
+        let right = subtract_struts(right_monitor(), SCREEN, &struts);
+        assert_eq!(right, right_monitor());
+    }
+
+    #[test]
+    fn panel_on_inner_edge() {
+        // A panel on the left edge of the right monitor reserves the whole left monitor.
+        let struts = [strut_partial(STRUT_LEFT, 1920 + 48, 0, 1079)];
+
+        let left = subtract_struts(left_monitor(), SCREEN, &struts);
+        assert_eq!(left, left_monitor());
+        let right = subtract_struts(right_monitor(), SCREEN, &struts);
+        assert_eq!(right, Edges::new((1968, 0), (1872, 1080)));
+    }
+
+    #[test]
+    fn strut_without_span_covers_whole_edge() {
+        let struts = [vec![0, 0, 24, 0]];
+
+        let left = subtract_struts(left_monitor(), SCREEN, &struts);
+        assert_eq!(left, Edges::new((0, 24), (1920, 1056)));
+        let right = subtract_struts(right_monitor(), SCREEN, &struts);
+        assert_eq!(right, Edges::new((1920, 24), (1920, 1056)));
+    }
+
+    #[test]
+    fn covering_struts_are_ignored() {
+        let struts = [vec![1920, 0, 0, 0]];
+
+        let left = subtract_struts(left_monitor(), SCREEN, &struts);
+        assert_eq!(left, left_monitor());
+    }
+}
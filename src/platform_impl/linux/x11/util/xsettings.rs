@@ -0,0 +1,322 @@
+//! Client side of the XSETTINGS protocol, which desktop environments use to share their
+//! settings, like the scaling and the theme, with the running applications.
+//!
+//! The settings are stored in the `_XSETTINGS_SETTINGS` property of the window owning the
+//! `_XSETTINGS_S<screen>` selection.
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::window::Theme;
+
+const TYPE_INTEGER: u8 = 0;
+const TYPE_STRING: u8 = 1;
+const TYPE_COLOR: u8 = 2;
+
+const MSB_FIRST: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Setting {
+    Integer(i32),
+    String(String),
+    /// Red, green, blue and alpha, the wire order being red, blue, green and alpha.
+    Color([u16; 4]),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct XSettings {
+    settings: HashMap<String, Setting>,
+}
+
+impl XSettings {
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let mut reader = Reader {
+            data,
+            big_endian: *data.first()? == MSB_FIRST,
+        };
+        reader.skip(4)?;
+        let _serial = reader.u32()?;
+        let count = reader.u32()?;
+
+        let mut settings = HashMap::new();
+        for _ in 0..count {
+            let kind = reader.u8()?;
+            reader.skip(1)?;
+            let name_len = reader.u16()? as usize;
+            let name = String::from_utf8_lossy(reader.padded(name_len)?).into_owned();
+            let _last_change_serial = reader.u32()?;
+
+            let setting = match kind {
+                TYPE_INTEGER => Setting::Integer(reader.u32()? as i32),
+                TYPE_STRING => {
+                    let len = reader.u32()? as usize;
+                    Setting::String(String::from_utf8_lossy(reader.padded(len)?).into_owned())
+                }
+                TYPE_COLOR => {
+                    let (red, blue, green, alpha) =
+                        (reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?);
+                    Setting::Color([red, green, blue, alpha])
+                }
+                _ => return None,
+            };
+            settings.insert(name, setting);
+        }
+
+        Some(Self { settings })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Setting> {
+        self.settings.get(name)
+    }
+
+    fn integer(&self, name: &str) -> Option<i32> {
+        match self.get(name)? {
+            Setting::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The scale factor requested by the desktop.
+    ///
+    /// `Xft/DPI` already includes the window scaling factor, so the latter is only used when
+    /// the DPI isn't set.
+    pub fn scale_factor(&self) -> Option<f64> {
+        match self.integer("Xft/DPI") {
+            // The DPI is stored in 1024ths.
+            Some(dpi) if dpi > 0 => Some(dpi as f64 / 1024. / 96.),
+            _ => self
+                .integer("Gdk/WindowScalingFactor")
+                .filter(|&scale| scale > 0)
+                .map(f64::from),
+        }
+    }
+
+    /// Guess the theme from the name of the GTK theme, since there's no dedicated setting.
+    pub fn theme(&self) -> Option<Theme> {
+        match self.get("Net/ThemeName")? {
+            Setting::String(name) if name.to_lowercase().contains("dark") => Some(Theme::Dark),
+            Setting::String(_) => Some(Theme::Light),
+            _ => None,
+        }
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.data.len() < len {
+            return None;
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(taken)
+    }
+
+    /// Take `len` bytes, skipping the padding to the next multiple of 4.
+    fn padded(&mut self, len: usize) -> Option<&'a [u8]> {
+        let taken = self.take(len)?;
+        self.skip((4 - len % 4) % 4)?;
+        Some(taken)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(drop)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let bytes = self.take(2)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.take(4)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+}
+
+impl XConnection {
+    /// The selection owned by the XSETTINGS manager of the default screen.
+    pub fn xsettings_selection(&self) -> Result<xproto::Atom, X11Error> {
+        let name = format!("_XSETTINGS_S{}", self.default_screen_index());
+        Ok(self
+            .xcb_connection()
+            .intern_atom(false, name.as_bytes())?
+            .reply()?
+            .atom)
+    }
+
+    /// The window of the XSETTINGS manager, which is watched for changes of the settings.
+    pub fn xsettings_owner(&self, selection: xproto::Atom) -> Option<xproto::Window> {
+        let owner = self
+            .xcb_connection()
+            .get_selection_owner(selection)
+            .ok()?
+            .reply()
+            .ok()?
+            .owner;
+        if owner == x11rb::NONE {
+            return None;
+        }
+
+        // The manager may go away at any time, so check that the window still exists.
+        self.xcb_connection()
+            .change_window_attributes(
+                owner,
+                &xproto::ChangeWindowAttributesAux::new().event_mask(
+                    xproto::EventMask::PROPERTY_CHANGE | xproto::EventMask::STRUCTURE_NOTIFY,
+                ),
+            )
+            .ok()?
+            .check()
+            .ok()?;
+
+        Some(owner)
+    }
+
+    /// The theme of the desktop, from the XDG desktop portal, falling back to XSETTINGS.
+    pub fn system_theme(&self) -> Option<Theme> {
+        self.portal_theme
+            .lock()
+            .unwrap()
+            .or_else(|| self.xsettings.lock().unwrap().theme())
+    }
+
+    pub fn read_xsettings(&self, owner: xproto::Window) -> XSettings {
+        let settings_atom = self.atoms()[_XSETTINGS_SETTINGS];
+        self.get_property::<u8>(owner, settings_atom, settings_atom)
+            .ok()
+            .and_then(|data| XSettings::parse(&data))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_padded(data: &mut Vec<u8>, bytes: &[u8]) {
+        data.extend_from_slice(bytes);
+        data.resize(data.len() + (4 - bytes.len() % 4) % 4, 0);
+    }
+
+    fn xsettings(big_endian: bool, settings: &[(&str, Setting)]) -> Vec<u8> {
+        let u16_bytes = |value: u16| {
+            if big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            }
+        };
+        let u32_bytes = |value: u32| {
+            if big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            }
+        };
+
+        let mut data = vec![big_endian as u8, 0, 0, 0];
+        data.extend_from_slice(&u32_bytes(7));
+        data.extend_from_slice(&u32_bytes(settings.len() as u32));
+        for (name, setting) in settings {
+            let kind = match setting {
+                Setting::Integer(_) => TYPE_INTEGER,
+                Setting::String(_) => TYPE_STRING,
+                Setting::Color(_) => TYPE_COLOR,
+            };
+            data.extend_from_slice(&[kind, 0]);
+            data.extend_from_slice(&u16_bytes(name.len() as u16));
+            push_padded(&mut data, name.as_bytes());
+            data.extend_from_slice(&u32_bytes(0));
+            match setting {
+                Setting::Integer(value) => data.extend_from_slice(&u32_bytes(*value as u32)),
+                Setting::String(value) => {
+                    data.extend_from_slice(&u32_bytes(value.len() as u32));
+                    push_padded(&mut data, value.as_bytes());
+                }
+                Setting::Color([red, green, blue, alpha]) => {
+                    for channel in [red, blue, green, alpha] {
+                        data.extend_from_slice(&u16_bytes(*channel));
+                    }
+                }
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn parses_settings() {
+        for big_endian in [false, true] {
+            let data = xsettings(
+                big_endian,
+                &[
+                    ("Xft/DPI", Setting::Integer(147456)),
+                    ("Net/ThemeName", Setting::String("Adwaita-dark".into())),
+                    ("Gtk/Color", Setting::Color([1, 2, 3, 4])),
+                ],
+            );
+            let settings = XSettings::parse(&data).unwrap();
+            assert_eq!(settings.scale_factor(), Some(1.5));
+            assert_eq!(
+                settings.get("Net/ThemeName"),
+                Some(&Setting::String("Adwaita-dark".into()))
+            );
+            assert_eq!(settings.theme(), Some(Theme::Dark));
+            assert_eq!(
+                settings.get("Gtk/Color"),
+                Some(&Setting::Color([1, 2, 3, 4]))
+            );
+        }
+    }
+
+    #[test]
+    fn reads_color_channels_in_wire_order() {
+        let mut data = xsettings(false, &[("Gtk/Color", Setting::Color([0; 4]))]);
+        let len = data.len();
+        data[len - 8..].copy_from_slice(&[1, 0, 2, 0, 3, 0, 4, 0]);
+        assert_eq!(
+            XSettings::parse(&data).unwrap().get("Gtk/Color"),
+            Some(&Setting::Color([1, 3, 2, 4]))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_window_scaling_factor() {
+        let data = xsettings(false, &[("Gdk/WindowScalingFactor", Setting::Integer(2))]);
+        assert_eq!(XSettings::parse(&data).unwrap().scale_factor(), Some(2.));
+        assert_eq!(XSettings::default().scale_factor(), None);
+    }
+
+    #[test]
+    fn guesses_theme_from_theme_name() {
+        let data = xsettings(
+            false,
+            &[("Net/ThemeName", Setting::String("Adwaita".into()))],
+        );
+        assert_eq!(XSettings::parse(&data).unwrap().theme(), Some(Theme::Light));
+        assert_eq!(XSettings::default().theme(), None);
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let data = xsettings(false, &[("Xft/DPI", Setting::Integer(98304))]);
+        assert_eq!(XSettings::parse(&data[..data.len() - 1]), None);
+        assert_eq!(XSettings::parse(&[]), None);
+    }
+}
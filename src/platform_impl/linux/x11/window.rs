@@ -15,21 +15,23 @@ use x11rb::{
 };
 use x11rb::{
     properties::{WmHints, WmSizeHints, WmSizeHintsSpecification},
-    protocol::xinput,
+    protocol::xinput::{self, ConnectionExt as _},
 };
 
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
+    event::{SeatId, WindowEvent},
     event_loop::AsyncRequestSerial,
     platform_impl::{
+        common::seat_focus::SeatFocus,
         x11::{atoms::*, MonitorHandle as X11MonitorHandle, X11Error},
         Fullscreen, MonitorHandle as PlatformMonitorHandle, OsError,
         PlatformSpecificWindowBuilderAttributes, VideoMode as PlatformVideoMode,
     },
     window::{
-        CursorGrabMode, CursorIcon, Icon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-        WindowAttributes, WindowButtons, WindowLevel,
+        CursorGrabMode, CursorIcon, Icon, ImePurpose, ResizeDirection, Theme, TiledEdges,
+        UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
     },
 };
 
@@ -42,8 +44,16 @@ use calloop::channel::Sender;
 #[derive(Debug)]
 pub struct SharedState {
     pub cursor_pos: Option<(f64, f64)>,
+    // The position the pointer is warped back to while it is locked
+    pub locked_cursor_pos: Option<(f64, f64)>,
+    // The client pointer, the only one confined by the core grab and thus warped back
+    pub locked_pointer: Option<xinput::DeviceId>,
     pub size: Option<(u32, u32)>,
+    // The size of the window the last time it wasn't maximized, fullscreen or tiled
+    pub restored_size: Option<(u32, u32)>,
     pub position: Option<(i32, i32)>,
+    // The outer position of the window the last time it wasn't maximized, fullscreen or tiled
+    pub restored_position: Option<(i32, i32)>,
     pub inner_position: Option<(i32, i32)>,
     pub inner_position_rel_parent: Option<(i32, i32)>,
     pub is_resizable: bool,
@@ -63,7 +73,12 @@ pub struct SharedState {
     pub resize_increments: Option<Size>,
     pub base_size: Option<Size>,
     pub visibility: Visibility,
-    pub has_focus: bool,
+    // The master keyboards focusing the window
+    pub focused_seats: SeatFocus,
+    // The latest `WindowStateChanged` event delivered to the user
+    pub window_state: Option<WindowEvent<'static>>,
+    // The theme requested by the application, `None` when following the system
+    pub theme: Option<Theme>,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -89,8 +104,12 @@ impl SharedState {
             is_resizable: window_attributes.resizable,
             is_decorated: window_attributes.decorations,
             cursor_pos: None,
+            locked_cursor_pos: None,
+            locked_pointer: None,
             size: None,
+            restored_size: None,
             position: None,
+            restored_position: None,
             inner_position: None,
             inner_position_rel_parent: None,
             dpi_adjusted: None,
@@ -103,9 +122,24 @@ impl SharedState {
             max_inner_size: None,
             resize_increments: None,
             base_size: None,
-            has_focus: false,
+            focused_seats: SeatFocus::default(),
+            window_state: None,
+            theme: window_attributes.preferred_theme,
         })
     }
+
+    /// Whether the window is neither maximized, fullscreen nor tiled, as last reported.
+    pub fn is_floating(&self) -> bool {
+        match self.window_state {
+            Some(WindowEvent::WindowStateChanged {
+                maximized,
+                fullscreen,
+                tiled_edges,
+                ..
+            }) => !maximized && !fullscreen && tiled_edges.is_empty(),
+            _ => true,
+        }
+    }
 }
 
 unsafe impl Send for UnownedWindow {}
@@ -252,7 +286,8 @@ impl UnownedWindow {
                 | EventMask::KEYMAP_STATE
                 | EventMask::BUTTON_PRESS
                 | EventMask::BUTTON_RELEASE
-                | EventMask::POINTER_MOTION;
+                | EventMask::POINTER_MOTION
+                | EventMask::PROPERTY_CHANGE;
 
             aux = aux.event_mask(event_mask).border_pixel(0);
 
@@ -326,7 +361,10 @@ impl UnownedWindow {
         leap!(window.set_title_inner(&window_attrs.title)).ignore_error();
         leap!(window.set_decorations_inner(window_attrs.decorations)).ignore_error();
 
-        if let Some(theme) = window_attrs.preferred_theme {
+        let theme = window_attrs
+            .preferred_theme
+            .or_else(|| xconn.system_theme());
+        if let Some(theme) = theme {
             leap!(window.set_theme_inner(Some(theme))).ignore_error();
         }
 
@@ -479,6 +517,8 @@ impl UnownedWindow {
 
             // Select XInput2 events
             let mask = xinput::XIEventMask::MOTION
+                | xinput::XIEventMask::KEY_PRESS
+                | xinput::XIEventMask::KEY_RELEASE
                 | xinput::XIEventMask::BUTTON_PRESS
                 | xinput::XIEventMask::BUTTON_RELEASE
                 | xinput::XIEventMask::ENTER
@@ -488,8 +528,30 @@ impl UnownedWindow {
                 | xinput::XIEventMask::TOUCH_BEGIN
                 | xinput::XIEventMask::TOUCH_UPDATE
                 | xinput::XIEventMask::TOUCH_END;
-            leap!(xconn.select_xinput_events(window.xwindow, ffi::XIAllMasterDevices as u16, mask))
-                .ignore_error();
+            let mut mask = vec![mask];
+            if event_loop.xi2_gestures {
+                // The gesture events don't fit in the first word of the mask.
+                let gestures = [
+                    ffi::XI_GesturePinchBegin,
+                    ffi::XI_GesturePinchUpdate,
+                    ffi::XI_GesturePinchEnd,
+                    ffi::XI_GestureSwipeBegin,
+                    ffi::XI_GestureSwipeUpdate,
+                    ffi::XI_GestureSwipeEnd,
+                ];
+                let mut gesture_mask = [0u32; 2];
+                for event in gestures {
+                    gesture_mask[event as usize / 32] |= 1 << (event % 32);
+                }
+                mask[0] |= xinput::XIEventMask::from(gesture_mask[0]);
+                mask.push(gesture_mask[1].into());
+            }
+            leap!(xconn.select_xinput_event_masks(
+                window.xwindow,
+                ffi::XIAllMasterDevices as u16,
+                mask
+            ))
+            .ignore_error();
 
             {
                 let result = event_loop
@@ -588,10 +650,9 @@ impl UnownedWindow {
         let atoms = self.xconn.atoms();
         let hint_atom = atoms[_GTK_THEME_VARIANT];
         let utf8_atom = atoms[UTF8_STRING];
-        let variant = match theme {
-            Some(Theme::Dark) => "dark",
+        let variant = match theme.or_else(|| self.xconn.system_theme()) {
+            Some(Theme::Dark) | None => "dark",
             Some(Theme::Light) => "light",
-            None => "dark",
         };
         let variant = CString::new(variant).expect("`_GTK_THEME_VARIANT` contained null byte");
         self.xconn.change_property(
@@ -605,6 +666,7 @@ impl UnownedWindow {
 
     #[inline]
     pub fn set_theme(&self, theme: Option<Theme>) {
+        self.shared_state_lock().theme = theme;
         self.set_theme_inner(theme)
             .expect("Failed to change window theme")
             .ignore_error();
@@ -672,6 +734,11 @@ impl UnownedWindow {
             Visibility::Yes => (),
         }
 
+        let fullscreen = match fullscreen {
+            Some(Fullscreen::BorderlessSpan(monitors)) => Some(self.fullscreen_span(monitors)),
+            fullscreen => fullscreen,
+        };
+
         let old_fullscreen = shared_state_lock.fullscreen.clone();
         if old_fullscreen == fullscreen {
             return Ok(None);
@@ -683,18 +750,17 @@ impl UnownedWindow {
             // fullscreen, so we can restore it upon exit, as XRandR does not
             // provide a mechanism to set this per app-session or restore this
             // to the desktop video mode as macOS and Windows do
-            (&None, &Some(Fullscreen::Exclusive(PlatformVideoMode::X(ref video_mode))))
-            | (
-                &Some(Fullscreen::Borderless(_)),
-                &Some(Fullscreen::Exclusive(PlatformVideoMode::X(ref video_mode))),
-            ) => {
+            (old, &Some(Fullscreen::Exclusive(PlatformVideoMode::X(ref video_mode))))
+                if !matches!(old, Some(Fullscreen::Exclusive(_))) =>
+            {
                 let monitor = video_mode.monitor.as_ref().unwrap();
                 shared_state_lock.desktop_video_mode =
                     Some((monitor.id, self.xconn.get_crtc_mode(monitor.id)));
             }
             // Restore desktop video mode upon exiting exclusive fullscreen
-            (&Some(Fullscreen::Exclusive(_)), &None)
-            | (&Some(Fullscreen::Exclusive(_)), &Some(Fullscreen::Borderless(_))) => {
+            (&Some(Fullscreen::Exclusive(_)), new)
+                if !matches!(new, Some(Fullscreen::Exclusive(_))) =>
+            {
                 let (monitor_id, mode_id) = shared_state_lock.desktop_video_mode.take().unwrap();
                 self.xconn
                     .set_crtc_config(monitor_id, mode_id)
@@ -705,6 +771,16 @@ impl UnownedWindow {
 
         drop(shared_state_lock);
 
+        // Go back to a single monitor when the window stops spanning.
+        if matches!(old_fullscreen, Some(Fullscreen::BorderlessSpan(_)))
+            && !matches!(fullscreen, Some(Fullscreen::BorderlessSpan(_)))
+        {
+            let monitor = self.current_monitor();
+            if let Some(index) = self.xconn.xinerama_index(&monitor) {
+                self.set_fullscreen_monitors([index; 4])?.ignore_error();
+            }
+        }
+
         match fullscreen {
             None => {
                 let flusher = self.set_fullscreen_hint(false);
@@ -725,6 +801,40 @@ impl UnownedWindow {
                         (None, monitor)
                     }
                     Fullscreen::Borderless(None) => (None, self.current_monitor()),
+                    Fullscreen::BorderlessSpan(ref monitors) => {
+                        let monitors = x11_monitors(monitors);
+                        let index = |monitor: &X11MonitorHandle| {
+                            self.xconn.xinerama_index(monitor).unwrap_or_default()
+                        };
+                        let top = monitors.iter().min_by_key(|monitor| monitor.position().y);
+                        let bottom = monitors.iter().max_by_key(|monitor| {
+                            monitor.position().y + monitor.size().height as i32
+                        });
+                        let left = monitors.iter().min_by_key(|monitor| monitor.position().x);
+                        let right = monitors.iter().max_by_key(|monitor| {
+                            monitor.position().x + monitor.size().width as i32
+                        });
+                        if let (Some(top), Some(bottom), Some(left), Some(right)) =
+                            (top, bottom, left, right)
+                        {
+                            self.set_fullscreen_monitors([
+                                index(top),
+                                index(bottom),
+                                index(left),
+                                index(right),
+                            ])?
+                            .ignore_error();
+                        }
+
+                        // Place the window on the top left monitor.
+                        let origin = monitors
+                            .iter()
+                            .min_by_key(|monitor| (monitor.position().y, monitor.position().x));
+                        (
+                            None,
+                            origin.cloned().unwrap_or_else(|| self.current_monitor()),
+                        )
+                    }
                     #[cfg(wayland_platform)]
                     _ => unreachable!(),
                 };
@@ -775,6 +885,35 @@ impl UnownedWindow {
         }
     }
 
+    /// Resolve spanning `monitors`, falling back to a single monitor when the window manager
+    /// can't span.
+    fn fullscreen_span(&self, monitors: Vec<PlatformMonitorHandle>) -> Fullscreen {
+        let atoms = self.xconn.atoms();
+        if x11_monitors(&monitors).len() > 1
+            && util::hint_is_supported(atoms[_NET_WM_FULLSCREEN_MONITORS])
+        {
+            return Fullscreen::BorderlessSpan(monitors);
+        }
+
+        if monitors.len() > 1 {
+            warn!("`_NET_WM_FULLSCREEN_MONITORS` is not supported by the window manager, using the first monitor");
+        }
+        Fullscreen::Borderless(monitors.into_iter().next())
+    }
+
+    /// Set the monitors whose edges are used for the top, bottom, left and right edges of the
+    /// fullscreen window.
+    fn set_fullscreen_monitors(&self, monitors: [u32; 4]) -> Result<VoidCookie<'_>, X11Error> {
+        let atoms = self.xconn.atoms();
+        self.xconn.send_client_msg(
+            self.xwindow,
+            self.root,
+            atoms[_NET_WM_FULLSCREEN_MONITORS],
+            Some(xproto::EventMask::SUBSTRUCTURE_REDIRECT | xproto::EventMask::SUBSTRUCTURE_NOTIFY),
+            [monitors[0], monitors[1], monitors[2], monitors[3], 1],
+        )
+    }
+
     #[inline]
     pub(crate) fn fullscreen(&self) -> Option<Fullscreen> {
         let shared_state = self.shared_state_lock();
@@ -913,6 +1052,82 @@ impl UnownedWindow {
         }
     }
 
+    fn net_wm_state(&self) -> Vec<xproto::Atom> {
+        let atoms = self.xconn.atoms();
+        self.xconn
+            .get_property(
+                self.xwindow,
+                atoms[_NET_WM_STATE],
+                xproto::Atom::from(xproto::AtomEnum::ATOM),
+            )
+            .unwrap_or_default()
+    }
+
+    fn tiled_edges_inner(&self, net_wm_state: &[xproto::Atom]) -> TiledEdges {
+        // The edge constraints used by GTK and set by mutter.
+        const TOP_TILED: u32 = 1 << 0;
+        const RIGHT_TILED: u32 = 1 << 2;
+        const BOTTOM_TILED: u32 = 1 << 4;
+        const LEFT_TILED: u32 = 1 << 6;
+
+        let atoms = self.xconn.atoms();
+        let constraints = self
+            .xconn
+            .get_property::<util::Cardinal>(
+                self.xwindow,
+                atoms[_GTK_EDGE_CONSTRAINTS],
+                xproto::Atom::from(xproto::AtomEnum::CARDINAL),
+            )
+            .ok()
+            .and_then(|constraints| constraints.first().copied());
+
+        let mut tiled_edges = TiledEdges::empty();
+        if let Some(constraints) = constraints {
+            tiled_edges.set(TiledEdges::TOP, constraints & TOP_TILED != 0);
+            tiled_edges.set(TiledEdges::RIGHT, constraints & RIGHT_TILED != 0);
+            tiled_edges.set(TiledEdges::BOTTOM, constraints & BOTTOM_TILED != 0);
+            tiled_edges.set(TiledEdges::LEFT, constraints & LEFT_TILED != 0);
+        } else {
+            // Guess from the partial maximization, which is used by the window managers to
+            // snap the window to the screen edges.
+            let horz = net_wm_state.contains(&atoms[_NET_WM_STATE_MAXIMIZED_HORZ]);
+            let vert = net_wm_state.contains(&atoms[_NET_WM_STATE_MAXIMIZED_VERT]);
+            if horz != vert {
+                tiled_edges.set(TiledEdges::LEFT | TiledEdges::RIGHT, horz);
+                tiled_edges.set(TiledEdges::TOP | TiledEdges::BOTTOM, vert);
+            }
+        }
+
+        tiled_edges
+    }
+
+    #[inline]
+    pub fn tiled_edges(&self) -> TiledEdges {
+        self.tiled_edges_inner(&self.net_wm_state())
+    }
+
+    /// Reload the window state, returning the event to deliver if it changed.
+    pub(crate) fn update_window_state(&self) -> Option<WindowEvent<'static>> {
+        let atoms = self.xconn.atoms();
+        let net_wm_state = self.net_wm_state();
+        let event = WindowEvent::WindowStateChanged {
+            maximized: net_wm_state.contains(&atoms[_NET_WM_STATE_MAXIMIZED_HORZ])
+                && net_wm_state.contains(&atoms[_NET_WM_STATE_MAXIMIZED_VERT]),
+            fullscreen: net_wm_state.contains(&atoms[_NET_WM_STATE_FULLSCREEN]),
+            tiled_edges: self.tiled_edges_inner(&net_wm_state),
+            suspended: net_wm_state.contains(&atoms[_NET_WM_STATE_HIDDEN]),
+            activated: net_wm_state.contains(&atoms[_NET_WM_STATE_FOCUSED]),
+        };
+
+        let mut shared_state_lock = self.shared_state_lock();
+        if shared_state_lock.window_state.as_ref() == Some(&event) {
+            return None;
+        }
+
+        shared_state_lock.window_state = Some(event.clone());
+        Some(event)
+    }
+
     fn set_maximized_inner(&self, maximized: bool) -> Result<VoidCookie<'_>, X11Error> {
         let atoms = self.xconn.atoms();
         let horz_atom = atoms[_NET_WM_STATE_MAXIMIZED_HORZ];
@@ -1197,6 +1412,23 @@ impl UnownedWindow {
         self.inner_size_physical().into()
     }
 
+    #[inline]
+    pub fn restored_outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
+        let restored_position = self.shared_state_lock().restored_position;
+        match restored_position {
+            Some(position) => Ok(position.into()),
+            None => self.outer_position(),
+        }
+    }
+
+    #[inline]
+    pub fn restored_inner_size(&self) -> PhysicalSize<u32> {
+        let restored_size = self.shared_state_lock().restored_size;
+        restored_size
+            .map(Into::into)
+            .unwrap_or_else(|| self.inner_size())
+    }
+
     #[inline]
     pub fn outer_size(&self) -> PhysicalSize<u32> {
         let extents = self.shared_state_lock().frame_extents.clone();
@@ -1415,7 +1647,7 @@ impl UnownedWindow {
     pub fn set_cursor_icon(&self, cursor: CursorIcon) {
         let old_cursor = replace(&mut *self.cursor.lock().unwrap(), cursor);
         #[allow(clippy::mutex_atomic)]
-        if cursor != old_cursor && *self.cursor_visible.lock().unwrap() {
+        if cursor != old_cursor && *self.cursor_visible.lock().unwrap() && !self.cursor_locked() {
             self.xconn.set_cursor_icon(self.xwindow, Some(cursor));
         }
     }
@@ -1433,12 +1665,15 @@ impl UnownedWindow {
             .xcb_connection()
             .ungrab_pointer(x11rb::CURRENT_TIME)
             .expect_then_ignore_error("Failed to call `xcb_ungrab_pointer`");
+        if *grabbed_lock == CursorGrabMode::Locked {
+            self.unlock_cursor();
+        }
 
         let result = match mode {
             CursorGrabMode::None => self.xconn.flush_requests().map_err(|err| {
                 ExternalError::Os(os_error!(OsError::XError(X11Error::Xlib(err).into())))
             }),
-            CursorGrabMode::Confined => {
+            CursorGrabMode::Confined | CursorGrabMode::Locked => {
                 let result = {
                     self.xconn
                         .xcb_connection()
@@ -1486,18 +1721,113 @@ impl UnownedWindow {
                 }
                 .map_err(|err| ExternalError::Os(os_error!(OsError::XMisc(err))))
             }
-            CursorGrabMode::Locked => {
-                return Err(ExternalError::NotSupported(NotSupportedError::new()));
-            }
+        };
+
+        let result = match result {
+            Ok(()) if mode == CursorGrabMode::Locked => self.lock_cursor(),
+            result => result,
         };
 
         if result.is_ok() {
             *grabbed_lock = mode;
+        } else if mode != CursorGrabMode::None {
+            self.xconn
+                .xcb_connection()
+                .ungrab_pointer(x11rb::CURRENT_TIME)
+                .expect_then_ignore_error("Failed to call `xcb_ungrab_pointer`");
+            *grabbed_lock = CursorGrabMode::None;
         }
 
         result
     }
 
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        seat_id: SeatId,
+        cursor: CursorIcon,
+    ) -> Result<(), ExternalError> {
+        self.xconn
+            .set_device_cursor_icon(self.xwindow, seat_id.0 as xinput::DeviceId, Some(cursor))
+            .map_err(|err| ExternalError::Os(os_error!(OsError::XError(err.into()))))
+    }
+
+    /// Only the client pointer can be confined, with the core grab, since the grabs of XInput2
+    /// don't confine the pointer.
+    pub fn set_seat_cursor_grab(
+        &self,
+        seat_id: SeatId,
+        mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        if self.client_pointer()? as u32 == seat_id.0 {
+            self.set_cursor_grab(mode)
+        } else {
+            Err(ExternalError::NotSupported(NotSupportedError::new()))
+        }
+    }
+
+    /// The master pointer used by the core requests on the window, like the grabs and warps.
+    fn client_pointer(&self) -> Result<xinput::DeviceId, ExternalError> {
+        let reply = self
+            .xconn
+            .xcb_connection()
+            .xinput_xi_get_client_pointer(self.xwindow)
+            .map_err(|err| {
+                ExternalError::Os(os_error!(OsError::XError(X11Error::from(err).into())))
+            })?
+            .reply()
+            .map_err(|err| {
+                ExternalError::Os(os_error!(OsError::XError(X11Error::from(err).into())))
+            })?;
+        Ok(reply.deviceid)
+    }
+
+    /// Hide the pointer and keep it at the center of the window, the pointer being already confined.
+    ///
+    /// The event processor warps the pointer back after each motion.
+    fn lock_cursor(&self) -> Result<(), ExternalError> {
+        let client_pointer = self.client_pointer()?;
+        self.shared_state_lock().locked_pointer = Some(client_pointer);
+        self.xconn.set_cursor_icon(self.xwindow, None);
+        self.center_locked_cursor()
+    }
+
+    /// Warp the locked pointer to the center of the window.
+    fn center_locked_cursor(&self) -> Result<(), ExternalError> {
+        let (width, height) = self.inner_size_physical();
+        let position = ((width / 2) as i32, (height / 2) as i32);
+        self.shared_state_lock().locked_cursor_pos = Some((position.0 as f64, position.1 as f64));
+        self.set_cursor_position_physical(position.0, position.1)
+    }
+
+    /// Keep the locked pointer at the center of the window once it's resized.
+    pub(crate) fn recenter_locked_cursor(&self) {
+        if !self.cursor_locked() {
+            return;
+        }
+        if let Err(err) = self.center_locked_cursor() {
+            warn!("Failed to warp the locked pointer to the center of the window: {err}");
+        }
+    }
+
+    /// Stop warping the pointer back and restore its visibility.
+    fn unlock_cursor(&self) {
+        let mut shared_state_lock = self.shared_state_lock();
+        shared_state_lock.locked_cursor_pos = None;
+        shared_state_lock.locked_pointer = None;
+        drop(shared_state_lock);
+        #[allow(clippy::mutex_atomic)]
+        if *self.cursor_visible.lock().unwrap() {
+            self.xconn
+                .set_cursor_icon(self.xwindow, Some(*self.cursor.lock().unwrap()));
+        }
+    }
+
+    #[inline]
+    fn cursor_locked(&self) -> bool {
+        self.shared_state_lock().locked_cursor_pos.is_some()
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, visible: bool) {
         #[allow(clippy::mutex_atomic)]
@@ -1512,7 +1842,10 @@ impl UnownedWindow {
         };
         *visible_lock = visible;
         drop(visible_lock);
-        self.xconn.set_cursor_icon(self.xwindow, cursor);
+        // The pointer stays hidden while it is locked, and gets its visibility back when unlocked.
+        if !self.cursor_locked() {
+            self.xconn.set_cursor_icon(self.xwindow, cursor);
+        }
     }
 
     #[inline]
@@ -1564,18 +1897,8 @@ impl UnownedWindow {
         })
     }
 
-    /// Initiates a drag operation while the left mouse button is pressed.
-    fn drag_initiate(&self, action: isize) -> Result<(), ExternalError> {
-        let pointer = self
-            .xconn
-            .query_pointer(self.xwindow, util::VIRTUAL_CORE_POINTER)
-            .map_err(|err| ExternalError::Os(os_error!(OsError::XError(err.into()))))?;
-
-        let window = self.inner_position().map_err(ExternalError::NotSupported)?;
-
-        let atoms = self.xconn.atoms();
-        let message = atoms[_NET_WM_MOVERESIZE];
-
+    /// Release the pointer grab, so the window manager could take it.
+    fn ungrab_pointer(&self) -> Result<MutexGuard<'_, CursorGrabMode>, ExternalError> {
         // we can't use `set_cursor_grab(false)` here because it doesn't run `XUngrabPointer`
         // if the cursor isn't currently grabbed
         let mut grabbed_lock = self.cursor_grabbed_mode.lock().unwrap();
@@ -1586,12 +1909,71 @@ impl UnownedWindow {
                 ExternalError::Os(os_error!(OsError::XError(X11Error::from(err).into())))
             })?
             .ignore_error();
+        if *grabbed_lock == CursorGrabMode::Locked {
+            self.unlock_cursor();
+        }
         self.xconn.flush_requests().map_err(|err| {
             ExternalError::Os(os_error!(OsError::XError(X11Error::Xlib(err).into())))
         })?;
         *grabbed_lock = CursorGrabMode::None;
 
+        Ok(grabbed_lock)
+    }
+
+    /// Shows the window menu at the given position relative to the window.
+    pub fn show_window_menu(&self, position: Position) {
+        let (x, y): (i32, i32) = position.to_physical::<i32>(self.scale_factor()).into();
+        let window = match self.inner_position() {
+            Ok(window) => window,
+            Err(_) => return,
+        };
+
+        let _grabbed_lock = match self.ungrab_pointer() {
+            Ok(grabbed_lock) => grabbed_lock,
+            Err(err) => {
+                warn!("Failed to release the pointer grab for the window menu: {err}");
+                return;
+            }
+        };
+
+        let atoms = self.xconn.atoms();
+        self.xconn
+            .send_client_msg(
+                self.xwindow,
+                self.root,
+                atoms[_GTK_SHOW_WINDOW_MENU],
+                Some(
+                    xproto::EventMask::SUBSTRUCTURE_REDIRECT
+                        | xproto::EventMask::SUBSTRUCTURE_NOTIFY,
+                ),
+                [
+                    util::VIRTUAL_CORE_POINTER as u32,
+                    (window.x + x) as u32,
+                    (window.y + y) as u32,
+                    0,
+                    0,
+                ],
+            )
+            .expect_then_ignore_error("Failed to show the window menu");
+        if let Err(err) = self.xconn.flush_requests() {
+            warn!("Failed to show the window menu: {err}");
+        }
+    }
+
+    /// Initiates a drag operation while the left mouse button is pressed.
+    fn drag_initiate(&self, action: isize) -> Result<(), ExternalError> {
+        let pointer = self
+            .xconn
+            .query_pointer(self.xwindow, util::VIRTUAL_CORE_POINTER)
+            .map_err(|err| ExternalError::Os(os_error!(OsError::XError(err.into()))))?;
+
+        let window = self.inner_position().map_err(ExternalError::NotSupported)?;
+
+        let atoms = self.xconn.atoms();
+        let message = atoms[_NET_WM_MOVERESIZE];
+
         // we keep the lock until we are done
+        let _grabbed_lock = self.ungrab_pointer()?;
         self.xconn
             .send_client_msg(
                 self.xwindow,
@@ -1749,12 +2131,14 @@ impl UnownedWindow {
 
     #[inline]
     pub fn theme(&self) -> Option<Theme> {
-        None
+        self.shared_state_lock()
+            .theme
+            .or_else(|| self.xconn.system_theme())
     }
 
     #[inline]
     pub fn has_focus(&self) -> bool {
-        self.shared_state_lock().has_focus
+        self.shared_state_lock().focused_seats.has_focus()
     }
 
     pub fn title(&self) -> String {
@@ -1783,3 +2167,14 @@ fn cast_size_to_hint(size: Size, scale_factor: f64) -> (i32, i32) {
         Size::Logical(size) => size.to_physical::<i32>(scale_factor).into(),
     }
 }
+
+fn x11_monitors(monitors: &[PlatformMonitorHandle]) -> Vec<X11MonitorHandle> {
+    monitors
+        .iter()
+        .filter_map(|monitor| match monitor {
+            PlatformMonitorHandle::X(monitor) => Some(monitor.clone()),
+            #[cfg(wayland_platform)]
+            _ => None,
+        })
+        .collect()
+}
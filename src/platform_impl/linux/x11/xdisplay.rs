@@ -8,9 +8,10 @@ use std::{
     },
 };
 
-use crate::window::CursorIcon;
+use crate::monitor::GammaRamp;
+use crate::window::{CursorIcon, Theme};
 
-use super::{atoms::Atoms, ffi};
+use super::{atoms::Atoms, ffi, util::xsettings::XSettings};
 use x11rb::{connection::Connection, protocol::xproto, xcb_ffi::XCBConnection};
 
 /// A connection to an X server.
@@ -40,6 +41,12 @@ pub(crate) struct XConnection {
 
     pub latest_error: Mutex<Option<XError>>,
     pub cursor_cache: Mutex<HashMap<Option<CursorIcon>, ffi::Cursor>>,
+    /// The gamma ramps of the CRTCs before they were changed, restored on exit.
+    pub original_gamma: Mutex<HashMap<ffi::RRCrtc, GammaRamp>>,
+    /// The settings shared by the XSETTINGS manager.
+    pub xsettings: Mutex<XSettings>,
+    /// The color scheme preferred by the desktop, as reported by the XDG desktop portal.
+    pub portal_theme: Mutex<Option<Theme>>,
 }
 
 unsafe impl Send for XConnection {}
@@ -104,6 +111,9 @@ impl XConnection {
             timestamp: AtomicU32::new(0),
             latest_error: Mutex::new(None),
             cursor_cache: Default::default(),
+            original_gamma: Default::default(),
+            xsettings: Default::default(),
+            portal_theme: Default::default(),
         })
     }
 
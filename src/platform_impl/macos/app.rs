@@ -68,6 +68,7 @@ fn maybe_dispatch_device_event(event: &NSEvent) {
             if delta_x != 0.0 || delta_y != 0.0 {
                 queue_device_event(DeviceEvent::MouseMotion {
                     delta: (delta_x, delta_y),
+                    unaccelerated_delta: None,
                 });
             }
         }
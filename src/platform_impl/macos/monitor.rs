@@ -13,6 +13,7 @@ use objc2::rc::{Id, Shared};
 use super::appkit::NSScreen;
 use super::ffi;
 use crate::dpi::{PhysicalPosition, PhysicalSize};
+use crate::monitor::{MonitorTransform, SubpixelOrder};
 
 #[derive(Clone)]
 pub struct VideoMode {
@@ -207,6 +208,35 @@ impl MonitorHandle {
         )
     }
 
+    #[inline]
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        (self.position(), self.size())
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    pub fn manufacturer(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        None
+    }
+
+    pub fn transform(&self) -> MonitorTransform {
+        MonitorTransform::Normal
+    }
+
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        SubpixelOrder::Unknown
+    }
+
     pub fn scale_factor(&self) -> f64 {
         match self.ns_screen() {
             Some(screen) => screen.backingScaleFactor() as f64,
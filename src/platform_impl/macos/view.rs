@@ -24,7 +24,7 @@ use crate::{
     dpi::{LogicalPosition, LogicalSize},
     event::{
         DeviceEvent, ElementState, Event, Ime, Modifiers, MouseButton, MouseScrollDelta,
-        TouchPhase, WindowEvent,
+        ScrollSource, TouchPhase, WindowEvent,
     },
     keyboard::{Key, KeyCode, KeyLocation, ModifiersState},
     platform::macos::{OptionAsAlt, WindowExtMacOS},
@@ -334,7 +334,10 @@ declare_class!(
             // Notify IME is active if application still doesn't know it.
             if self.state.ime_state.get() == ImeState::Disabled {
                 *self.state.input_source.borrow_mut() = self.current_input_source();
-                self.queue_event(WindowEvent::Ime(Ime::Enabled));
+                self.queue_event(WindowEvent::Ime {
+                    seat_id: None,
+                    event: Ime::Enabled,
+                });
             }
 
             if self.hasMarkedText() {
@@ -353,7 +356,10 @@ declare_class!(
             };
 
             // Send WindowEvent for updating marked text
-            self.queue_event(WindowEvent::Ime(Ime::Preedit(preedit_string, cursor_range)));
+            self.queue_event(WindowEvent::Ime {
+                seat_id: None,
+                event: Ime::Preedit(preedit_string, cursor_range),
+            });
         }
 
         #[sel(unmarkText)]
@@ -364,7 +370,10 @@ declare_class!(
             let input_context = self.inputContext().expect("input context");
             input_context.discardMarkedText();
 
-            self.queue_event(WindowEvent::Ime(Ime::Preedit(String::new(), None)));
+            self.queue_event(WindowEvent::Ime {
+                seat_id: None,
+                event: Ime::Preedit(String::new(), None),
+            });
             if self.is_ime_enabled() {
                 // Leave the Preedit self.state
                 self.state.ime_state.set(ImeState::Ground);
@@ -431,8 +440,14 @@ declare_class!(
 
             // Commit only if we have marked text.
             if self.hasMarkedText() && self.is_ime_enabled() && !is_control {
-                self.queue_event(WindowEvent::Ime(Ime::Preedit(String::new(), None)));
-                self.queue_event(WindowEvent::Ime(Ime::Commit(string)));
+                self.queue_event(WindowEvent::Ime {
+                    seat_id: None,
+                    event: Ime::Preedit(String::new(), None),
+                });
+                self.queue_event(WindowEvent::Ime {
+                    seat_id: None,
+                    event: Ime::Commit(string),
+                });
                 self.state.ime_state.set(ImeState::Commited);
             }
         }
@@ -469,7 +484,10 @@ declare_class!(
                     *prev_input_source = current_input_source;
                     drop(prev_input_source);
                     self.state.ime_state.set(ImeState::Disabled);
-                    self.queue_event(WindowEvent::Ime(Ime::Disabled));
+                    self.queue_event(WindowEvent::Ime {
+                        seat_id: None,
+                        event: Ime::Disabled,
+                    });
                 }
             }
 
@@ -709,10 +727,17 @@ declare_class!(
             self.update_modifiers(event, false);
 
             self.queue_device_event(DeviceEvent::MouseWheel { delta });
+            let source = if event.hasPreciseScrollingDeltas() {
+                ScrollSource::Finger
+            } else {
+                ScrollSource::Wheel
+            };
             self.queue_event(WindowEvent::MouseWheel {
                 device_id: DEVICE_ID,
                 delta,
                 phase,
+                source,
+                value120: None,
             });
         }
 
@@ -881,7 +906,10 @@ impl WinitView {
 
         if self.state.ime_state.get() != ImeState::Disabled {
             self.state.ime_state.set(ImeState::Disabled);
-            self.queue_event(WindowEvent::Ime(Ime::Disabled));
+            self.queue_event(WindowEvent::Ime {
+                seat_id: None,
+                event: Ime::Disabled,
+            });
         }
     }
 
@@ -16,7 +16,7 @@ use crate::{
         LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size, Size::Logical,
     },
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
-    event::WindowEvent,
+    event::{SeatId, WindowEvent},
     icon::Icon,
     platform::macos::{OptionAsAlt, WindowExtMacOS},
     platform_impl::platform::{
@@ -30,8 +30,8 @@ use crate::{
         Fullscreen, OsError,
     },
     window::{
-        CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-        WindowAttributes, WindowButtons, WindowId as RootWindowId, WindowLevel,
+        CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, TiledEdges,
+        UserAttentionType, WindowAttributes, WindowButtons, WindowId as RootWindowId, WindowLevel,
     },
 };
 use core_graphics::display::{CGDisplay, CGPoint};
@@ -803,6 +803,24 @@ impl WinitWindow {
             .map_err(|status| ExternalError::Os(os_error!(OsError::CGError(status))))
     }
 
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        _seat_id: SeatId,
+        _cursor: CursorIcon,
+    ) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
+    #[inline]
+    pub fn set_seat_cursor_grab(
+        &self,
+        _seat_id: SeatId,
+        _mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, visible: bool) {
         let view = self.view();
@@ -847,6 +865,9 @@ impl WinitWindow {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    #[inline]
+    pub fn show_window_menu(&self, _position: Position) {}
+
     #[inline]
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
         util::set_ignore_mouse_events_sync(self, !hittest);
@@ -943,6 +964,11 @@ impl WinitWindow {
         self.is_zoomed()
     }
 
+    #[inline]
+    pub fn tiled_edges(&self) -> TiledEdges {
+        TiledEdges::empty()
+    }
+
     #[inline]
     pub(crate) fn set_fullscreen(&self, fullscreen: Option<Fullscreen>) {
         let mut shared_state_lock = self.lock_shared_state("set_fullscreen");
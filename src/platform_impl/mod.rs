@@ -30,6 +30,8 @@ pub use self::platform::*;
 pub(crate) enum Fullscreen {
     Exclusive(VideoMode),
     Borderless(Option<MonitorHandle>),
+    #[cfg(x11_platform)]
+    BorderlessSpan(Vec<MonitorHandle>),
 }
 
 impl From<RootFullscreen> for Fullscreen {
@@ -38,6 +40,15 @@ impl From<RootFullscreen> for Fullscreen {
             RootFullscreen::Exclusive(mode) => Self::Exclusive(mode.video_mode),
             RootFullscreen::Borderless(Some(handle)) => Self::Borderless(Some(handle.inner)),
             RootFullscreen::Borderless(None) => Self::Borderless(None),
+            #[cfg(x11_platform)]
+            RootFullscreen::BorderlessSpan(monitors) => {
+                Self::BorderlessSpan(monitors.into_iter().map(|handle| handle.inner).collect())
+            }
+            #[cfg(not(x11_platform))]
+            RootFullscreen::BorderlessSpan(monitors) => {
+                warn!("`Fullscreen::BorderlessSpan` is only supported on X11, using the first monitor");
+                Self::Borderless(monitors.into_iter().next().map(|handle| handle.inner))
+            }
         }
     }
 }
@@ -50,6 +61,13 @@ impl From<Fullscreen> for RootFullscreen {
                 Self::Borderless(Some(RootMonitorHandle { inner }))
             }
             Fullscreen::Borderless(None) => Self::Borderless(None),
+            #[cfg(x11_platform)]
+            Fullscreen::BorderlessSpan(monitors) => Self::BorderlessSpan(
+                monitors
+                    .into_iter()
+                    .map(|inner| RootMonitorHandle { inner })
+                    .collect(),
+            ),
         }
     }
 }
@@ -358,11 +358,17 @@ impl<T: 'static> EventLoop<T> {
             EventOption::TextInput(TextInputEvent { character }) => {
                 event_handler(event::Event::WindowEvent {
                     window_id: RootWindowId(window_id),
-                    event: event::WindowEvent::Ime(Ime::Preedit("".into(), None)),
+                    event: event::WindowEvent::Ime {
+                        seat_id: None,
+                        event: Ime::Preedit("".into(), None),
+                    },
                 });
                 event_handler(event::Event::WindowEvent {
                     window_id: RootWindowId(window_id),
-                    event: event::WindowEvent::Ime(Ime::Commit(character.into())),
+                    event: event::WindowEvent::Ime {
+                        seat_id: None,
+                        event: Ime::Commit(character.into()),
+                    },
                 });
             }
             EventOption::Mouse(MouseEvent { x, y }) => {
@@ -397,6 +403,8 @@ impl<T: 'static> EventLoop<T> {
                         device_id: event::DeviceId(DeviceId),
                         delta: event::MouseScrollDelta::LineDelta(x as f32, y as f32),
                         phase: event::TouchPhase::Moved,
+                        source: event::ScrollSource::Unknown,
+                        value120: None,
                     },
                 });
             }
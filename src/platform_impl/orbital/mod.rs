@@ -3,6 +3,7 @@
 use std::str;
 
 use crate::dpi::{PhysicalPosition, PhysicalSize};
+use crate::monitor::{MonitorTransform, SubpixelOrder};
 
 pub use self::event_loop::{EventLoop, EventLoopProxy, EventLoopWindowTarget};
 mod event_loop;
@@ -204,6 +205,34 @@ impl MonitorHandle {
         (0, 0).into()
     }
 
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        (self.position(), self.size())
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    pub fn manufacturer(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        None
+    }
+
+    pub fn transform(&self) -> MonitorTransform {
+        MonitorTransform::Normal
+    }
+
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        SubpixelOrder::Unknown
+    }
+
     pub fn scale_factor(&self) -> f64 {
         1.0 // TODO
     }
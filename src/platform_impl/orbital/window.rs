@@ -10,6 +10,7 @@ use raw_window_handle::{
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error,
+    event::SeatId,
     platform_impl::Fullscreen,
     window,
     window::ImePurpose,
@@ -296,6 +297,11 @@ impl Window {
         false
     }
 
+    #[inline]
+    pub fn tiled_edges(&self) -> window::TiledEdges {
+        window::TiledEdges::empty()
+    }
+
     #[inline]
     pub(crate) fn set_fullscreen(&self, _monitor: Option<Fullscreen>) {}
 
@@ -356,6 +362,28 @@ impl Window {
         ))
     }
 
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        _: SeatId,
+        _: window::CursorIcon,
+    ) -> Result<(), error::ExternalError> {
+        Err(error::ExternalError::NotSupported(
+            error::NotSupportedError::new(),
+        ))
+    }
+
+    #[inline]
+    pub fn set_seat_cursor_grab(
+        &self,
+        _: SeatId,
+        _: window::CursorGrabMode,
+    ) -> Result<(), error::ExternalError> {
+        Err(error::ExternalError::NotSupported(
+            error::NotSupportedError::new(),
+        ))
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, _: bool) {}
 
@@ -376,6 +404,9 @@ impl Window {
         ))
     }
 
+    #[inline]
+    pub fn show_window_menu(&self, _position: Position) {}
+
     #[inline]
     pub fn set_cursor_hittest(&self, _hittest: bool) -> Result<(), error::ExternalError> {
         Err(error::ExternalError::NotSupported(
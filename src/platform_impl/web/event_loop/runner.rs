@@ -296,6 +296,7 @@ impl<T: 'static> Shared<T> {
                             device_id,
                             event: DeviceEvent::MouseMotion {
                                 delta: (delta.x, delta.y),
+                                unaccelerated_delta: None,
                             },
                         }))
                 }));
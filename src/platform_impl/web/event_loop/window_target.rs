@@ -17,8 +17,8 @@ use super::{
     window::WindowId,
 };
 use crate::event::{
-    DeviceEvent, DeviceId as RootDeviceId, ElementState, Event, KeyEvent, RawKeyEvent, Touch,
-    TouchPhase, WindowEvent,
+    DeviceEvent, DeviceId as RootDeviceId, ElementState, Event, KeyEvent, RawKeyEvent,
+    ScrollSource, Touch, TouchPhase, WindowEvent,
 };
 use crate::event_loop::DeviceEvents;
 use crate::keyboard::ModifiersState;
@@ -337,6 +337,7 @@ impl<T> EventLoopWindowTarget<T> {
                                         device_id,
                                         event: DeviceEvent::MouseMotion {
                                             delta: (delta.x, delta.y),
+                                            unaccelerated_delta: None,
                                         },
                                     },
                                 ))
@@ -379,6 +380,7 @@ impl<T> EventLoopWindowTarget<T> {
                                 device_id: RootDeviceId(DeviceId(device_id)),
                                 phase: TouchPhase::Moved,
                                 force: Some(force),
+                                contact: None,
                                 location,
                             }),
                         },
@@ -526,6 +528,7 @@ impl<T> EventLoopWindowTarget<T> {
                                 device_id: RootDeviceId(DeviceId(device_id)),
                                 phase: TouchPhase::Started,
                                 force: Some(force),
+                                contact: None,
                                 location,
                             }),
                         },
@@ -622,6 +625,7 @@ impl<T> EventLoopWindowTarget<T> {
                                 device_id: RootDeviceId(DeviceId(device_id)),
                                 phase: TouchPhase::Ended,
                                 force: Some(force),
+                                contact: None,
                                 location,
                             }),
                         },
@@ -656,6 +660,8 @@ impl<T> EventLoopWindowTarget<T> {
                             device_id: RootDeviceId(DeviceId(pointer_id)),
                             delta,
                             phase: TouchPhase::Moved,
+                            source: ScrollSource::Unknown,
+                            value120: None,
                         },
                     }),
                 ));
@@ -672,6 +678,7 @@ impl<T> EventLoopWindowTarget<T> {
                     device_id: RootDeviceId(DeviceId(device_id)),
                     phase: TouchPhase::Cancelled,
                     force: Some(force),
+                    contact: None,
                     location,
                 }),
             });
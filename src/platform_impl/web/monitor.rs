@@ -1,4 +1,5 @@
 use crate::dpi::{PhysicalPosition, PhysicalSize};
+use crate::monitor::{MonitorTransform, SubpixelOrder};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MonitorHandle;
@@ -12,6 +13,34 @@ impl MonitorHandle {
         PhysicalPosition { x: 0, y: 0 }
     }
 
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        (self.position(), self.size())
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    pub fn manufacturer(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        None
+    }
+
+    pub fn transform(&self) -> MonitorTransform {
+        MonitorTransform::Normal
+    }
+
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        SubpixelOrder::Unknown
+    }
+
     pub fn name(&self) -> Option<String> {
         None
     }
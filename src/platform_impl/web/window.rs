@@ -1,8 +1,9 @@
 use crate::dpi::{PhysicalPosition, PhysicalSize, Position, Size};
 use crate::error::{ExternalError, NotSupportedError, OsError as RootOE};
+use crate::event::SeatId;
 use crate::icon::Icon;
 use crate::window::{
-    CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
+    CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, TiledEdges, UserAttentionType,
     WindowAttributes, WindowButtons, WindowId as RootWI, WindowLevel,
 };
 
@@ -258,6 +259,24 @@ impl Window {
         })
     }
 
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        _seat_id: SeatId,
+        _cursor: CursorIcon,
+    ) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
+    #[inline]
+    pub fn set_seat_cursor_grab(
+        &self,
+        _seat_id: SeatId,
+        _mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, visible: bool) {
         self.inner.dispatch(move |inner| {
@@ -283,6 +302,9 @@ impl Window {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    #[inline]
+    pub fn show_window_menu(&self, _position: Position) {}
+
     #[inline]
     pub fn set_cursor_hittest(&self, _hittest: bool) -> Result<(), ExternalError> {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
@@ -310,6 +332,11 @@ impl Window {
         false
     }
 
+    #[inline]
+    pub fn tiled_edges(&self) -> TiledEdges {
+        TiledEdges::empty()
+    }
+
     #[inline]
     pub(crate) fn fullscreen(&self) -> Option<Fullscreen> {
         self.inner.queue(|inner| {
@@ -78,7 +78,9 @@ use windows_sys::Win32::{
 
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
-    event::{DeviceEvent, Event, Force, Ime, RawKeyEvent, Touch, TouchPhase, WindowEvent},
+    event::{
+        DeviceEvent, Event, Force, Ime, RawKeyEvent, ScrollSource, Touch, TouchPhase, WindowEvent,
+    },
     event_loop::{ControlFlow, DeviceEvents, EventLoopClosed, EventLoopWindowTarget as RootELW},
     keyboard::{KeyCode, ModifiersState},
     platform::scancode::KeyCodeExtScancode,
@@ -1275,7 +1277,10 @@ unsafe fn public_window_callback_inner<T: 'static>(
 
                 userdata.send_event(Event::WindowEvent {
                     window_id: RootWindowId(WindowId(window)),
-                    event: WindowEvent::Ime(Ime::Enabled),
+                    event: WindowEvent::Ime {
+                        seat_id: None,
+                        event: Ime::Enabled,
+                    },
                 });
             }
 
@@ -1295,7 +1300,10 @@ unsafe fn public_window_callback_inner<T: 'static>(
                 if lparam == 0 {
                     userdata.send_event(Event::WindowEvent {
                         window_id: RootWindowId(WindowId(window)),
-                        event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                        event: WindowEvent::Ime {
+                            seat_id: None,
+                            event: Ime::Preedit(String::new(), None),
+                        },
                     });
                 }
 
@@ -1307,11 +1315,17 @@ unsafe fn public_window_callback_inner<T: 'static>(
 
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                            event: WindowEvent::Ime {
+                                seat_id: None,
+                                event: Ime::Preedit(String::new(), None),
+                            },
                         });
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Commit(text)),
+                            event: WindowEvent::Ime {
+                                seat_id: None,
+                                event: Ime::Commit(text),
+                            },
                         });
                     }
                 }
@@ -1324,7 +1338,10 @@ unsafe fn public_window_callback_inner<T: 'static>(
 
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Preedit(text, cursor_range)),
+                            event: WindowEvent::Ime {
+                                seat_id: None,
+                                event: Ime::Preedit(text, cursor_range),
+                            },
                         });
                     }
                 }
@@ -1347,11 +1364,17 @@ unsafe fn public_window_callback_inner<T: 'static>(
                     if let Some(text) = ime_context.get_composed_text() {
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                            event: WindowEvent::Ime {
+                                seat_id: None,
+                                event: Ime::Preedit(String::new(), None),
+                            },
                         });
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Commit(text)),
+                            event: WindowEvent::Ime {
+                                seat_id: None,
+                                event: Ime::Commit(text),
+                            },
                         });
                     }
                 }
@@ -1360,7 +1383,10 @@ unsafe fn public_window_callback_inner<T: 'static>(
 
                 userdata.send_event(Event::WindowEvent {
                     window_id: RootWindowId(WindowId(window)),
-                    event: WindowEvent::Ime(Ime::Disabled),
+                    event: WindowEvent::Ime {
+                        seat_id: None,
+                        event: Ime::Disabled,
+                    },
                 });
             }
 
@@ -1474,9 +1500,9 @@ unsafe fn public_window_callback_inner<T: 'static>(
         WM_MOUSEWHEEL => {
             use crate::event::MouseScrollDelta::LineDelta;
 
-            let value = (wparam >> 16) as i16;
-            let value = value as i32;
-            let value = value as f32 / WHEEL_DELTA as f32;
+            let value120 = (wparam >> 16) as i16;
+            let value120 = value120 as i32;
+            let value = value120 as f32 / WHEEL_DELTA as f32;
 
             update_modifiers(window, userdata);
 
@@ -1486,6 +1512,8 @@ unsafe fn public_window_callback_inner<T: 'static>(
                     device_id: DEVICE_ID,
                     delta: LineDelta(0.0, value),
                     phase: TouchPhase::Moved,
+                    source: ScrollSource::Unknown,
+                    value120: Some((0, value120)),
                 },
             });
 
@@ -1495,9 +1523,9 @@ unsafe fn public_window_callback_inner<T: 'static>(
         WM_MOUSEHWHEEL => {
             use crate::event::MouseScrollDelta::LineDelta;
 
-            let value = (wparam >> 16) as i16;
-            let value = value as i32;
-            let value = -value as f32 / WHEEL_DELTA as f32; // NOTE: inverted! See https://github.com/rust-windowing/winit/pull/2105/
+            let value120 = (wparam >> 16) as i16;
+            let value120 = -(value120 as i32); // NOTE: inverted! See https://github.com/rust-windowing/winit/pull/2105/
+            let value = value120 as f32 / WHEEL_DELTA as f32;
 
             update_modifiers(window, userdata);
 
@@ -1507,6 +1535,8 @@ unsafe fn public_window_callback_inner<T: 'static>(
                     device_id: DEVICE_ID,
                     delta: LineDelta(value, 0.0),
                     phase: TouchPhase::Moved,
+                    source: ScrollSource::Unknown,
+                    value120: Some((value120, 0)),
                 },
             });
 
@@ -1747,6 +1777,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
                             },
                             location,
                             force: None, // WM_TOUCH doesn't support pressure information
+                            contact: None,
                             id: input.dwID as u64,
                             device_id: DEVICE_ID,
                         }),
@@ -1888,6 +1919,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
                             },
                             location,
                             force,
+                            contact: None,
                             id: pointer_info.pointerId as u64,
                             device_id: DEVICE_ID,
                         }),
@@ -2411,7 +2443,11 @@ unsafe fn handle_raw_input<T: 'static>(userdata: &ThreadMsgTargetData<T>, data:
             if x != 0.0 || y != 0.0 {
                 userdata.send_event(Event::DeviceEvent {
                     device_id,
-                    event: MouseMotion { delta: (x, y) },
+                    event: MouseMotion {
+                        delta: (x, y),
+                        // The relative motion of `WM_INPUT` is never accelerated.
+                        unaccelerated_delta: Some((x, y)),
+                    },
                 });
             }
         }
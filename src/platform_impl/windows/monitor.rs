@@ -17,7 +17,7 @@ use windows_sys::Win32::{
 use super::util::decode_wide;
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
-    monitor::VideoMode as RootVideoMode,
+    monitor::{MonitorTransform, SubpixelOrder, VideoMode as RootVideoMode},
     platform_impl::platform::{
         dpi::{dpi_to_scale_factor, get_monitor_dpi},
         util::has_flag,
@@ -216,6 +216,51 @@ impl MonitorHandle {
         }
     }
 
+    #[inline]
+    pub fn work_area(&self) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        let rc_work = get_monitor_info(self.0).unwrap().monitorInfo.rcWork;
+        (
+            PhysicalPosition {
+                x: rc_work.left,
+                y: rc_work.top,
+            },
+            PhysicalSize {
+                width: (rc_work.right - rc_work.left) as u32,
+                height: (rc_work.bottom - rc_work.top) as u32,
+            },
+        )
+    }
+
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    #[inline]
+    pub fn manufacturer(&self) -> Option<String> {
+        None
+    }
+
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    #[inline]
+    pub fn serial_number(&self) -> Option<String> {
+        None
+    }
+
+    #[inline]
+    pub fn transform(&self) -> MonitorTransform {
+        MonitorTransform::Normal
+    }
+
+    #[inline]
+    pub fn subpixel_order(&self) -> SubpixelOrder {
+        SubpixelOrder::Unknown
+    }
+
     #[inline]
     pub fn scale_factor(&self) -> f64 {
         dpi_to_scale_factor(get_monitor_dpi(self.0).unwrap_or(96))
@@ -57,6 +57,7 @@ use windows_sys::Win32::{
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
+    event::SeatId,
     icon::Icon,
     platform_impl::platform::{
         dark_mode::try_theme,
@@ -75,8 +76,8 @@ use crate::{
         Fullscreen, PlatformSpecificWindowBuilderAttributes, WindowId,
     },
     window::{
-        CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-        WindowAttributes, WindowButtons, WindowLevel,
+        CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, TiledEdges,
+        UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
     },
 };
 
@@ -370,6 +371,24 @@ impl Window {
         rx.recv().unwrap()
     }
 
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        _seat_id: SeatId,
+        _cursor: CursorIcon,
+    ) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
+    #[inline]
+    pub fn set_seat_cursor_grab(
+        &self,
+        _seat_id: SeatId,
+        _mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, visible: bool) {
         let window = self.window.clone();
@@ -460,6 +479,9 @@ impl Window {
         Ok(())
     }
 
+    #[inline]
+    pub fn show_window_menu(&self, _position: Position) {}
+
     #[inline]
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
         let window = self.window.clone();
@@ -520,6 +542,11 @@ impl Window {
         window_state.window_flags.contains(WindowFlags::MAXIMIZED)
     }
 
+    #[inline]
+    pub fn tiled_edges(&self) -> TiledEdges {
+        TiledEdges::empty()
+    }
+
     #[inline]
     pub fn fullscreen(&self) -> Option<Fullscreen> {
         let window_state = self.window_state_lock();
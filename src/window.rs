@@ -8,8 +8,9 @@ use raw_window_handle::{
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError},
+    event::SeatId,
     event_loop::EventLoopWindowTarget,
-    monitor::{MonitorHandle, VideoMode},
+    monitor::{MonitorHandle, MonitorId, VideoMode},
     platform_impl,
 };
 
@@ -109,6 +110,9 @@ pub struct WindowBuilder {
     /// The attributes to use to create the window.
     pub(crate) window: WindowAttributes,
 
+    /// The placement to restore, resolved against the monitors when building the window.
+    pub(crate) placement: Option<WindowPlacement>,
+
     // Platform-specific configuration.
     pub(crate) platform_specific: platform_impl::PlatformSpecificWindowBuilderAttributes,
 }
@@ -117,6 +121,7 @@ impl fmt::Debug for WindowBuilder {
     fn fmt(&self, fmtr: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmtr.debug_struct("WindowBuilder")
             .field("window", &self.window)
+            .field("placement", &self.placement)
             .finish()
     }
 }
@@ -380,7 +385,7 @@ impl WindowBuilder {
     /// - **Wayland:** This control only CSD. You can also use `WINIT_WAYLAND_CSD_THEME` env variable to set the theme.
     ///   Possible values for env variable are: "dark" and light".
     /// - **x11:** Build window with `_GTK_THEME_VARIANT` hint set to `dark` or `light`.
-    /// - **iOS / Android / Web / x11 / Orbital:** Ignored.
+    /// - **iOS / Android / Web / Orbital:** Ignored.
     #[inline]
     pub fn with_theme(mut self, theme: Option<Theme>) -> Self {
         self.window.preferred_theme = theme;
@@ -452,6 +457,22 @@ impl WindowBuilder {
         self
     }
 
+    /// Restores a placement previously obtained with [`Window::placement`].
+    ///
+    /// This overrides the position, size, maximized and fullscreen attributes. When building
+    /// the window, the geometry is clamped to the work area of the monitor the window was on,
+    /// or of the primary monitor if it's no longer connected, so the window never ends up
+    /// off-screen.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The position isn't restored, only the size and the states are.
+    #[inline]
+    pub fn with_placement(mut self, placement: &WindowPlacement) -> Self {
+        self.placement = Some(placement.clone());
+        self
+    }
+
     /// Builds the window.
     ///
     /// Possible causes of error include denied permission, incompatible system, and lack of memory.
@@ -465,12 +486,15 @@ impl WindowBuilder {
         self,
         window_target: &EventLoopWindowTarget<T>,
     ) -> Result<Window, OsError> {
-        platform_impl::Window::new(&window_target.p, self.window, self.platform_specific).map(
-            |window| {
-                window.request_redraw();
-                Window { window }
-            },
-        )
+        let mut window = self.window;
+        if let Some(placement) = self.placement {
+            placement.apply(window_target, &mut window);
+        }
+
+        platform_impl::Window::new(&window_target.p, window, self.platform_specific).map(|window| {
+            window.request_redraw();
+            Window { window }
+        })
     }
 }
 
@@ -511,7 +535,7 @@ impl Window {
     ///
     /// ## Platform-specific
     ///
-    /// - **X11:** This respects Xft.dpi, and can be overridden using the `WINIT_X11_SCALE_FACTOR` environment variable.
+    /// - **X11:** This respects the XSETTINGS and Xft.dpi, and can be overridden using the `WINIT_X11_SCALE_FACTOR` environment variable.
     /// - **Wayland:** Uses the wp-fractional-scale protocol if available. Falls back to integer-scale factors otherwise.
     /// - **Android:** Always returns 1.0.
     /// - **iOS:** Can only be called on the main thread. Returns the underlying `UIView`'s
@@ -763,7 +787,7 @@ impl Window {
     ///
     /// ## Platform-specific
     ///
-    /// - **iOS / Android / Web / Wayland / Windows / Orbital:** Always returns [`None`].
+    /// - **iOS / Android / Web / Windows / Orbital:** Always returns [`None`].
     #[inline]
     pub fn resize_increments(&self) -> Option<PhysicalSize<u32>> {
         self.window.resize_increments()
@@ -777,13 +801,58 @@ impl Window {
     /// ## Platform-specific
     ///
     /// - **macOS:** Increments are converted to logical size and then macOS rounds them to whole numbers.
-    /// - **Wayland / Windows:** Not implemented.
+    /// - **Wayland:** Emulated by snapping the sizes proposed by the compositor and the requested
+    ///   inner sizes to the increments, starting from the base size set with
+    ///   `WindowBuilderExtWayland::with_base_size`, and within the min and max sizes. The sizes
+    ///   are not snapped when the window is maximized, fullscreen, or tiled.
+    /// - **Windows:** Not implemented.
     /// - **iOS / Android / Web / Orbital:** Unsupported.
     #[inline]
     pub fn set_resize_increments<S: Into<Size>>(&self, increments: Option<S>) {
         self.window
             .set_resize_increments(increments.map(Into::into))
     }
+
+    /// Returns a snapshot of the geometry and state of the window, which can be stored and
+    /// restored with [`WindowBuilder::with_placement`].
+    ///
+    /// The position and size are the ones the window had the last time it wasn't maximized,
+    /// fullscreen or tiled, so the window can be unmaximized once restored.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The position isn't available.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** The position and size are the
+    ///   current ones.
+    pub fn placement(&self) -> WindowPlacement {
+        #[cfg(any(x11_platform, wayland_platform))]
+        let restored = (
+            self.window.restored_outer_position().ok(),
+            self.window.restored_inner_size(),
+        );
+        #[cfg(not(any(x11_platform, wayland_platform)))]
+        let restored = (self.outer_position().ok(), self.inner_size());
+
+        let maximized = self.is_maximized();
+        let fullscreen = self.fullscreen().is_some();
+        let current = (
+            self.outer_position().ok(),
+            self.inner_size(),
+            self.outer_size(),
+        );
+        let (position, inner_size, outer_size) =
+            restored_geometry(current, restored, maximized || fullscreen);
+
+        WindowPlacement {
+            position,
+            inner_size,
+            outer_size,
+            scale_factor: self.scale_factor(),
+            maximized,
+            fullscreen,
+            monitor: self.current_monitor().map(|monitor| monitor.stable_id()),
+        }
+    }
 }
 
 /// Misc. attribute functions.
@@ -875,7 +944,9 @@ impl Window {
     ///
     /// ## Platform-specific
     ///
-    /// - **Wayland / X11 / Orbital:** Not implemented.
+    /// - **Wayland:** Only affects the client side decorations. The bundled frame always shows
+    ///   the close button.
+    /// - **X11 / Orbital:** Not implemented.
     /// - **Web / iOS / Android:** Unsupported.
     pub fn set_enabled_buttons(&self, buttons: WindowButtons) {
         self.window.set_enabled_buttons(buttons)
@@ -885,8 +956,12 @@ impl Window {
     ///
     /// ## Platform-specific
     ///
-    /// - **Wayland / X11 / Orbital:** Not implemented. Always returns [`WindowButtons::all`].
+    /// - **Wayland:** Returns the enabled buttons the compositor supports, which could change
+    ///   at runtime, see [`WindowEvent::EnabledButtonsChanged`].
+    /// - **X11 / Orbital:** Not implemented. Always returns [`WindowButtons::all`].
     /// - **Web / iOS / Android:** Unsupported. Always returns [`WindowButtons::all`].
+    ///
+    /// [`WindowEvent::EnabledButtonsChanged`]: crate::event::WindowEvent::EnabledButtonsChanged
     pub fn enabled_buttons(&self) -> WindowButtons {
         self.window.enabled_buttons()
     }
@@ -939,6 +1014,22 @@ impl Window {
         self.window.is_maximized()
     }
 
+    /// Gets the edges of the window which are tiled by the window manager.
+    ///
+    /// The window is tiled when it's snapped to the screen edge or placed next to other windows,
+    /// thus the applications drawing their own decorations should avoid drawing rounded corners
+    /// and shadows on these edges.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Reads `_GTK_EDGE_CONSTRAINTS` when the window manager provides it, otherwise
+    ///   guessed from the partially maximized `_NET_WM_STATE`.
+    /// - **iOS / Android / Web / Windows / macOS / Orbital:** Unsupported, always empty.
+    #[inline]
+    pub fn tiled_edges(&self) -> TiledEdges {
+        self.window.tiled_edges()
+    }
+
     /// Sets the window to fullscreen or back.
     ///
     /// ## Platform-specific
@@ -956,7 +1047,10 @@ impl Window {
     ///
     ///   The dock and the menu bar are disabled in exclusive fullscreen mode.
     /// - **iOS:** Can only be called on the main thread.
-    /// - **Wayland:** Does not support exclusive fullscreen mode and will no-op a request.
+    /// - **Wayland:** [`Fullscreen::Exclusive`] is emulated by going fullscreen on the output
+    ///   of the video mode and scaling the buffers to it with `wp_viewporter`, the output mode
+    ///   itself is never changed. [`Window::inner_size`] reports the size of the video mode.
+    ///   Falls back to [`Fullscreen::Borderless`] when `wp_viewporter` is not available.
     /// - **Windows:** Screen saver is disabled in fullscreen mode.
     /// - **Android / Orbital:** Unsupported.
     /// - **Web:** Does nothing without a [transient activation], but queues the request
@@ -1161,7 +1255,8 @@ impl Window {
     /// - **macOS:** This is an app-wide setting.
     /// - **Wayland:** You can also use `WINIT_WAYLAND_CSD_THEME` env variable to set the theme.
     ///   Possible values for env variable are: "dark" and light". When unspecified, a theme is automatically selected.
-    /// - **X11:** Sets `_GTK_THEME_VARIANT` hint to `dark` or `light` and if `None` is used, it will default to  [`Theme::Dark`].
+    /// - **X11:** Sets `_GTK_THEME_VARIANT` hint to `dark` or `light` and if `None` is used, it will follow the
+    ///   system theme, defaulting to [`Theme::Dark`].
     /// - **iOS / Android / Web / Orbital:** Unsupported.
     #[inline]
     pub fn set_theme(&self, theme: Option<Theme>) {
@@ -1173,7 +1268,9 @@ impl Window {
     /// ## Platform-specific
     ///
     /// - **macOS:** This is an app-wide setting.
-    /// - **iOS / Android / Wayland / x11 / Orbital:** Unsupported.
+    /// - **X11 / Wayland:** The system theme is read from the color scheme of the XDG desktop portal
+    ///   with the `xdg-portal` feature. X11 falls back to guessing it from the GTK theme name in XSETTINGS.
+    /// - **iOS / Android / Orbital:** Unsupported.
     #[inline]
     pub fn theme(&self) -> Option<Theme> {
         self.window.theme()
@@ -1259,6 +1356,43 @@ impl Window {
         self.window.set_cursor_grab(mode)
     }
 
+    /// Modifies the cursor icon of the window for the pointer of a seat only.
+    ///
+    /// The icon takes precedence over the one set by [`Window::set_cursor_icon`] for that seat.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / Web / iOS / Android / Orbital:** Always returns an
+    ///   [`ExternalError::NotSupported`].
+    #[inline]
+    pub fn set_seat_cursor_icon(
+        &self,
+        seat_id: SeatId,
+        cursor: CursorIcon,
+    ) -> Result<(), ExternalError> {
+        self.window.set_seat_cursor_icon(seat_id, cursor)
+    }
+
+    /// Set grabbing [mode]([`CursorGrabMode`]) on the cursor of a seat only.
+    ///
+    /// The mode takes precedence over the one set by [`Window::set_cursor_grab`] for that seat,
+    /// while [`CursorGrabMode::None`] removes it so that the seat follows the window again.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Only supported for the seat of the client pointer, the other master pointers
+    ///   can't be confined by a grab.
+    /// - **Windows / macOS / Web / iOS / Android / Orbital:** Always returns an
+    ///   [`ExternalError::NotSupported`].
+    #[inline]
+    pub fn set_seat_cursor_grab(
+        &self,
+        seat_id: SeatId,
+        mode: CursorGrabMode,
+    ) -> Result<(), ExternalError> {
+        self.window.set_seat_cursor_grab(seat_id, mode)
+    }
+
     /// Modifies the cursor's visibility.
     ///
     /// If `false`, this will hide the cursor. If `true`, this will show the cursor.
@@ -1306,6 +1440,24 @@ impl Window {
         self.window.drag_resize_window(direction)
     }
 
+    /// Show the window menu of the window manager at the given position relative to the
+    /// window content.
+    ///
+    /// This is intended for the applications drawing their own title bars, which should show
+    /// this menu on right click.
+    ///
+    /// There's no guarantee that this will work unless the mouse button was pressed immediately
+    /// before this function is called.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Uses `_GTK_SHOW_WINDOW_MENU`, which is supported only by some window managers.
+    /// - **iOS / Android / Web / Windows / macOS / Orbital:** Unsupported.
+    #[inline]
+    pub fn show_window_menu(&self, position: impl Into<Position>) {
+        self.window.show_window_menu(position.into())
+    }
+
     /// Modifies whether the window catches cursor events.
     ///
     /// If `true`, the window will catch the cursor events. If `false`, events are passed through
@@ -1431,8 +1583,14 @@ pub enum CursorGrabMode {
     ///
     /// ## Platform-specific
     ///
-    /// - **X11 / Windows:** Not implemented. Always returns [`ExternalError::NotSupported`] for now.
+    /// - **X11:** The cursor is confined, hidden, and warped back to the center of the window after
+    ///   each motion. [`WindowEvent::CursorMoved`] isn't emitted while locked, use
+    ///   [`DeviceEvent::MouseMotion`] for the motion.
+    /// - **Windows:** Not implemented. Always returns [`ExternalError::NotSupported`] for now.
     /// - **iOS / Android / Orbital:** Always returns an [`ExternalError::NotSupported`].
+    ///
+    /// [`WindowEvent::CursorMoved`]: crate::event::WindowEvent::CursorMoved
+    /// [`DeviceEvent::MouseMotion`]: crate::event::DeviceEvent::MouseMotion
     Locked,
 }
 
@@ -1472,6 +1630,154 @@ pub enum Fullscreen {
 
     /// Providing `None` to `Borderless` will fullscreen on the current monitor.
     Borderless(Option<MonitorHandle>),
+
+    /// Borderless fullscreen spanning the bounding box of all the given monitors.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Requires a window manager supporting `_NET_WM_FULLSCREEN_MONITORS`.
+    /// - Falls back to [`Fullscreen::Borderless`] on the first monitor with a warning when the
+    ///   window manager or the platform can't span monitors.
+    BorderlessSpan(Vec<MonitorHandle>),
+}
+
+/// Snapshot of the geometry and state of a window.
+///
+/// Can be acquired with [`Window::placement`] and restored with
+/// [`WindowBuilder::with_placement`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindowPlacement {
+    /// The position of the top-left corner of the window, including the decorations.
+    ///
+    /// `None` when the position isn't available, like on Wayland.
+    pub position: Option<PhysicalPosition<i32>>,
+    /// The size of the client area of the window, when it isn't maximized.
+    pub inner_size: PhysicalSize<u32>,
+    /// The size of the window including the decorations, matching [`inner_size`](Self::inner_size).
+    pub outer_size: PhysicalSize<u32>,
+    /// The scale factor of the window when the snapshot was taken.
+    pub scale_factor: f64,
+    pub maximized: bool,
+    /// Whether the window was fullscreen on [`monitor`](Self::monitor).
+    pub fullscreen: bool,
+    /// The monitor the window was on.
+    pub monitor: Option<MonitorId>,
+}
+
+impl WindowPlacement {
+    fn apply<T>(&self, window_target: &EventLoopWindowTarget<T>, window: &mut WindowAttributes) {
+        let monitor = self
+            .monitor
+            .as_ref()
+            .and_then(|id| window_target.monitor_by_id(id))
+            .or_else(|| window_target.primary_monitor())
+            .or_else(|| window_target.available_monitors().next());
+
+        let (position, size) = self.resolve(
+            monitor
+                .as_ref()
+                .map(|monitor| (monitor.scale_factor(), monitor.work_area())),
+        );
+
+        window.inner_size = Some(size.into());
+        window.position = position.map(Into::into);
+        window.maximized = self.maximized;
+        window.fullscreen = self.fullscreen.then(|| Fullscreen::Borderless(monitor));
+    }
+
+    /// The position and inner size to restore on the monitor, given by its scale factor and
+    /// work area.
+    fn resolve(
+        &self,
+        monitor: Option<(f64, (PhysicalPosition<i32>, PhysicalSize<u32>))>,
+    ) -> (Option<PhysicalPosition<i32>>, PhysicalSize<u32>) {
+        // Keep the logical size, since the monitor may have a different scale factor now.
+        let scale_factor = monitor.map_or(self.scale_factor, |(scale_factor, _)| scale_factor);
+        let scale = |size: PhysicalSize<u32>| -> PhysicalSize<u32> {
+            size.to_logical::<f64>(self.scale_factor)
+                .to_physical(scale_factor)
+        };
+        let size = scale(self.inner_size);
+        match monitor {
+            Some((_, work_area)) => {
+                clamp_to_area(self.position, size, scale(self.outer_size), work_area)
+            }
+            None => (self.position, size),
+        }
+    }
+}
+
+/// The outer position, inner size and outer size of the window once restored, from its current
+/// geometry and the one it had the last time it wasn't maximized, fullscreen or tiled.
+fn restored_geometry(
+    (current_position, current_inner_size, current_outer_size): (
+        Option<PhysicalPosition<i32>>,
+        PhysicalSize<u32>,
+        PhysicalSize<u32>,
+    ),
+    (restored_position, inner_size): (Option<PhysicalPosition<i32>>, PhysicalSize<u32>),
+    maximized_or_fullscreen: bool,
+) -> (
+    Option<PhysicalPosition<i32>>,
+    PhysicalSize<u32>,
+    PhysicalSize<u32>,
+) {
+    // The origin of a maximized or fullscreen window doesn't fit the restored size.
+    let position = if maximized_or_fullscreen {
+        restored_position
+    } else {
+        current_position
+    };
+
+    // Assume the decorations keep their size once restored.
+    let outer_size = PhysicalSize::new(
+        inner_size.width
+            + current_outer_size
+                .width
+                .saturating_sub(current_inner_size.width),
+        inner_size.height
+            + current_outer_size
+                .height
+                .saturating_sub(current_inner_size.height),
+    );
+
+    (position, inner_size, outer_size)
+}
+
+/// Shrink the window to fit in the area, then move it inside of it, keeping room for the
+/// decorations.
+fn clamp_to_area(
+    position: Option<PhysicalPosition<i32>>,
+    inner_size: PhysicalSize<u32>,
+    outer_size: PhysicalSize<u32>,
+    (area_position, area_size): (PhysicalPosition<i32>, PhysicalSize<u32>),
+) -> (Option<PhysicalPosition<i32>>, PhysicalSize<u32>) {
+    let clamp = |position: i32, inner: u32, outer: u32, start: i32, length: u32| {
+        let decorations = outer.saturating_sub(inner);
+        let inner = inner.clamp(1, length.saturating_sub(decorations).max(1));
+        let end = start.saturating_add(length.saturating_sub(inner + decorations) as i32);
+        (position.clamp(start, end), inner)
+    };
+
+    let (x, width) = clamp(
+        position.map_or(area_position.x, |position| position.x),
+        inner_size.width,
+        outer_size.width,
+        area_position.x,
+        area_size.width,
+    );
+    let (y, height) = clamp(
+        position.map_or(area_position.y, |position| position.y),
+        inner_size.height,
+        outer_size.height,
+        area_position.y,
+        area_size.height,
+    );
+    (
+        position.map(|_| PhysicalPosition::new(x, y)),
+        PhysicalSize::new(width, height),
+    )
 }
 
 /// The theme variant to use.
@@ -1516,6 +1822,17 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// The edges of the window which are tiled, see [`Window::tiled_edges`].
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct TiledEdges: u32 {
+        const LEFT = 1 << 0;
+        const RIGHT = 1 << 1;
+        const TOP = 1 << 2;
+        const BOTTOM = 1 << 3;
+    }
+}
+
 /// A window level groups windows with respect to their z-position.
 ///
 /// The relative ordering between windows in different window levels is fixed.
@@ -1579,3 +1896,97 @@ impl ActivationToken {
         Self { _token }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: (PhysicalPosition<i32>, PhysicalSize<u32>) =
+        (PhysicalPosition::new(0, 32), PhysicalSize::new(1920, 1048));
+
+    #[test]
+    fn placement_is_moved_inside_work_area() {
+        // The decorations would go past the bottom right corner.
+        let (position, size) = clamp_to_area(
+            Some(PhysicalPosition::new(1200, 400)),
+            PhysicalSize::new(800, 600),
+            PhysicalSize::new(810, 640),
+            AREA,
+        );
+        assert_eq!(position, Some(PhysicalPosition::new(1110, 400)));
+        assert_eq!(size, PhysicalSize::new(800, 600));
+
+        let (position, _) = clamp_to_area(
+            Some(PhysicalPosition::new(100, 900)),
+            PhysicalSize::new(800, 600),
+            PhysicalSize::new(810, 640),
+            AREA,
+        );
+        assert_eq!(position, Some(PhysicalPosition::new(100, 440)));
+
+        // Above the panel.
+        let (position, _) = clamp_to_area(
+            Some(PhysicalPosition::new(-50, 0)),
+            PhysicalSize::new(800, 600),
+            PhysicalSize::new(810, 640),
+            AREA,
+        );
+        assert_eq!(position, Some(PhysicalPosition::new(0, 32)));
+    }
+
+    #[test]
+    fn placement_is_shrunk_to_work_area() {
+        // The decorations still fit once shrunk.
+        let (position, size) = clamp_to_area(
+            Some(PhysicalPosition::new(300, 300)),
+            PhysicalSize::new(2000, 1100),
+            PhysicalSize::new(2010, 1140),
+            AREA,
+        );
+        assert_eq!(position, Some(PhysicalPosition::new(0, 32)));
+        assert_eq!(size, PhysicalSize::new(1910, 1008));
+
+        // Without position, like on Wayland.
+        let (position, size) = clamp_to_area(
+            None,
+            PhysicalSize::new(2000, 600),
+            PhysicalSize::new(2000, 600),
+            AREA,
+        );
+        assert_eq!(position, None);
+        assert_eq!(size, PhysicalSize::new(1920, 600));
+    }
+
+    #[test]
+    fn maximized_placement_round_trips() {
+        // Maximized under the panel, after being at (200, 150) with decorations of 10x40.
+        let current = (
+            Some(PhysicalPosition::new(0, 32)),
+            PhysicalSize::new(1910, 1008),
+            PhysicalSize::new(1920, 1048),
+        );
+        let restored = (
+            Some(PhysicalPosition::new(200, 150)),
+            PhysicalSize::new(800, 600),
+        );
+        let (position, inner_size, outer_size) = restored_geometry(current, restored, true);
+        let placement = WindowPlacement {
+            position,
+            inner_size,
+            outer_size,
+            scale_factor: 1.,
+            maximized: true,
+            fullscreen: false,
+            monitor: None,
+        };
+        assert_eq!(placement.outer_size, PhysicalSize::new(810, 640));
+
+        let (position, size) = placement.resolve(Some((1., AREA)));
+        assert_eq!(position, Some(PhysicalPosition::new(200, 150)));
+        assert_eq!(size, PhysicalSize::new(800, 600));
+
+        // Restored on a monitor with twice the scale factor.
+        let (_, size) = placement.resolve(Some((2., (AREA.0, PhysicalSize::new(3840, 2096)))));
+        assert_eq!(size, PhysicalSize::new(1600, 1200));
+    }
+}
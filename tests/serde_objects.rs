@@ -3,9 +3,13 @@
 use serde::{Deserialize, Serialize};
 use winit::{
     dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize},
-    event::{ElementState, MouseButton, MouseScrollDelta, TouchPhase},
-    keyboard::{Key, KeyCode, KeyLocation, ModifiersState},
-    window::CursorIcon,
+    event::{
+        DeviceKind, ElementState, GamepadAxis, GamepadButton, MouseButton, MouseScrollDelta,
+        PenAxes, PenEvent, PenTool, PenToolKind, ScrollSource, TouchContact, TouchPhase,
+    },
+    keyboard::{Key, KeyCode, KeyLocation, LayoutInfo, ModifiersState},
+    monitor::{MonitorId, MonitorTransform, SubpixelOrder},
+    window::{CursorIcon, WindowPlacement},
 };
 
 #[allow(dead_code)]
@@ -14,18 +18,36 @@ fn needs_serde<S: Serialize + Deserialize<'static>>() {}
 #[test]
 fn window_serde() {
     needs_serde::<CursorIcon>();
+    needs_serde::<WindowPlacement>();
 }
 
 #[test]
 fn events_serde() {
     needs_serde::<TouchPhase>();
+    needs_serde::<TouchContact>();
     needs_serde::<ElementState>();
     needs_serde::<MouseButton>();
     needs_serde::<MouseScrollDelta>();
+    needs_serde::<ScrollSource>();
+    needs_serde::<DeviceKind>();
+    needs_serde::<GamepadButton>();
+    needs_serde::<GamepadAxis>();
+    needs_serde::<PenEvent>();
+    needs_serde::<PenTool>();
+    needs_serde::<PenToolKind>();
+    needs_serde::<PenAxes>();
     needs_serde::<Key>();
     needs_serde::<KeyCode>();
     needs_serde::<KeyLocation>();
     needs_serde::<ModifiersState>();
+    needs_serde::<LayoutInfo>();
+}
+
+#[test]
+fn monitor_serde() {
+    needs_serde::<MonitorId>();
+    needs_serde::<MonitorTransform>();
+    needs_serde::<SubpixelOrder>();
 }
 
 #[test]